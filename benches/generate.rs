@@ -0,0 +1,63 @@
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use rudocx::elements::{Document, HexColor, Paragraph, ParagraphChild, Run, RunProperties};
+use rudocx::xml::generate;
+use std::hint::black_box;
+
+/// Build a document of `paragraphs` paragraphs with `runs_per_paragraph` runs each, cycling through plain, bold,
+/// colored and sized runs so that both the text and the run properties paths are exercised.
+fn large_document(paragraphs: usize, runs_per_paragraph: usize) -> Document {
+    let run = |index: usize| {
+        let properties = match index % 4 {
+            0 => RunProperties::default(),
+            1 => RunProperties {
+                bold: true,
+                ..Default::default()
+            },
+            2 => RunProperties {
+                color: Some(HexColor::new("FF0000")),
+                spacing: Some(15),
+                ..Default::default()
+            },
+            _ => RunProperties {
+                size: Some(24),
+                position: Some(-4),
+                ..Default::default()
+            },
+        };
+        Run {
+            properties,
+            text: format!("Run {index} with some text & a <tag> "),
+            ..Default::default()
+        }
+    };
+
+    Document {
+        paragraphs: (0..paragraphs)
+            .map(|p| Paragraph {
+                children: (0..runs_per_paragraph)
+                    .map(|r| ParagraphChild::Run(run(p * runs_per_paragraph + r)))
+                    .collect(),
+                ..Default::default()
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+fn bench_generate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("generate");
+    for (paragraphs, runs_per_paragraph) in [(100, 10), (1_000, 10), (2_000, 25)] {
+        let document = large_document(paragraphs, runs_per_paragraph);
+        let runs = paragraphs * runs_per_paragraph;
+        group.throughput(Throughput::Elements(runs as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(runs),
+            &document,
+            |b, document| b.iter(|| generate(black_box(document)).unwrap()),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_generate);
+criterion_main!(benches);
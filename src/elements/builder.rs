@@ -0,0 +1,128 @@
+// --- Document builder ---
+
+use crate::elements::{
+    Document, Paragraph, ParagraphChild, ParagraphProperties, Run, RunProperties,
+};
+
+/// Assembles a [Document] paragraph by paragraph, applying the same default formatting to all of them.
+/// Created by [Document::builder].
+///
+/// ```
+/// use rudocx::elements::{Document, RunProperties};
+///
+/// let document = Document::builder()
+///     .run_defaults(RunProperties {
+///         size: Some(24),
+///         ..Default::default()
+///     })
+///     .paragraph("Monthly report")
+///     .build();
+/// assert_eq!(document.runs().next().unwrap().properties.size, Some(24));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DocumentBuilder {
+    document: Document,
+    run_defaults: RunProperties,
+    paragraph_defaults: ParagraphProperties,
+}
+
+impl Document {
+    /// Start building a document with shared defaults. See [DocumentBuilder].
+    pub fn builder() -> DocumentBuilder {
+        DocumentBuilder::default()
+    }
+}
+
+impl DocumentBuilder {
+    /// Set the `RunProperties` of the text of the paragraphs added afterwards.
+    pub fn run_defaults(mut self, properties: RunProperties) -> Self {
+        self.run_defaults = properties;
+        self
+    }
+
+    /// Set the `ParagraphProperties` of the paragraphs added afterwards.
+    pub fn paragraph_defaults(mut self, properties: ParagraphProperties) -> Self {
+        self.paragraph_defaults = properties;
+        self
+    }
+
+    /// Add a paragraph with a single run of `text`, formatted with the defaults.
+    pub fn paragraph(self, text: &str) -> Self {
+        let properties = self.run_defaults.clone();
+        self.push(text, properties)
+    }
+
+    /// Add a paragraph with a single run of `text`, formatted with `properties`. The properties left unset are
+    /// taken from the defaults, see [RunProperties::merge].
+    pub fn styled_paragraph(self, text: &str, properties: RunProperties) -> Self {
+        let properties = properties.merge(&self.run_defaults);
+        self.push(text, properties)
+    }
+
+    /// Get the assembled document. See [Document::fix_space_preserve] for the handling of surrounding whitespace.
+    pub fn build(mut self) -> Document {
+        self.document.fix_space_preserve();
+        self.document
+    }
+
+    fn push(mut self, text: &str, properties: RunProperties) -> Self {
+        self.document.paragraphs.push(Paragraph {
+            properties: self.paragraph_defaults.clone(),
+            children: vec![ParagraphChild::Run(Run {
+                properties,
+                text: text.to_string(),
+                ..Default::default()
+            })],
+        });
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_defaults() {
+        let document = Document::builder()
+            .run_defaults(RunProperties {
+                size: Some(24),
+                ..Default::default()
+            })
+            .paragraph_defaults(ParagraphProperties {
+                outline_level: Some(9),
+                ..Default::default()
+            })
+            .paragraph("First")
+            .styled_paragraph(
+                "Second",
+                RunProperties {
+                    bold: true,
+                    ..Default::default()
+                },
+            )
+            .styled_paragraph(
+                "Title",
+                RunProperties {
+                    size: Some(32),
+                    ..Default::default()
+                },
+            )
+            .build();
+
+        let runs = document.runs().collect::<Vec<&Run>>();
+        assert_eq!(
+            runs.iter().map(|r| r.properties.size).collect::<Vec<_>>(),
+            [Some(24), Some(24), Some(32)]
+        );
+        assert!(!runs[0].properties.bold);
+        assert!(runs[1].properties.bold);
+        assert!(
+            document
+                .paragraphs
+                .iter()
+                .all(|p| p.properties.outline_level == Some(9))
+        );
+        assert_eq!(document.text(), "First\nSecond\nTitle");
+    }
+}
@@ -0,0 +1,31 @@
+use crate::elements::Paragraph;
+
+/// Represents a review comment of the document [`w:comment`](). Comments are saved in the `word/comments.xml` part.
+///
+/// The commented content is delimited in the body by [ParagraphChild::CommentRangeStart](crate::elements::ParagraphChild::CommentRangeStart)
+/// and [ParagraphChild::CommentRangeEnd](crate::elements::ParagraphChild::CommentRangeEnd), and the comment mark is shown
+/// where a run with [Run::comment_reference](crate::elements::Run::comment_reference) is placed.
+///
+/// ### Fields
+/// > - **id:** `u32` - Identifier shared by the comment, its range and its reference. [`w:id`]()
+/// > - **author:** `String` - Name of the author of the comment. [`w:author`]()
+/// > - **date:** `Option<String>` - Date and time of the comment, in ISO 8601 format (e.g. `2024-01-31T10:00:00Z`). [`w:date`]()
+/// > - **paragraphs:** `Vec<Paragraph>` - The content of the comment.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Comment {
+    pub id: u32,
+    pub author: String,
+    pub date: Option<String>,
+    pub paragraphs: Vec<Paragraph>,
+}
+
+impl Comment {
+    pub fn new(id: u32, author: &str, paragraphs: Vec<Paragraph>) -> Self {
+        Self {
+            id,
+            author: author.to_string(),
+            date: None,
+            paragraphs,
+        }
+    }
+}
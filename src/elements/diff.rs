@@ -0,0 +1,116 @@
+use crate::elements::{Document, Paragraph, ParagraphProperties, Run, RunProperties};
+
+/// A single difference between two versions of a `Document`, as returned by [Document::diff](crate::elements::Document::diff).
+///
+/// Paragraphs and runs are compared by position, so inserting a paragraph reports every following paragraph as changed.
+/// Runs are numbered as in [Paragraph::runs](crate::elements::Paragraph::runs), including the ones inside hyperlinks and fields.
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+#[non_exhaustive]
+pub enum DocDiff {
+    /// A paragraph exists only in the new version
+    ParagraphAdded { index: usize },
+    /// A paragraph exists only in the old version
+    ParagraphRemoved { index: usize },
+    /// The `ParagraphProperties` of a paragraph changed
+    ParagraphPropertiesChanged {
+        paragraph: usize,
+        before: ParagraphProperties,
+        after: ParagraphProperties,
+    },
+    /// The structure of a paragraph changed (e.g. runs were added, removed or wrapped in a hyperlink)
+    ParagraphContentChanged { paragraph: usize },
+    /// The text of a run changed
+    RunTextChanged {
+        paragraph: usize,
+        run: usize,
+        before: String,
+        after: String,
+    },
+    /// The `RunProperties` of a run changed
+    RunPropertiesChanged {
+        paragraph: usize,
+        run: usize,
+        before: RunProperties,
+        after: RunProperties,
+    },
+    /// Relationships were added, removed or retargeted
+    RelationshipsChanged,
+    /// The `SectionProperties` of the document changed
+    SectionPropertiesChanged,
+    /// Comments were added, removed or edited
+    CommentsChanged,
+    /// Footnotes were added, removed or edited
+    FootnotesChanged,
+}
+
+pub(crate) fn diff_documents(before: &Document, after: &Document) -> Vec<DocDiff> {
+    let mut diff = Vec::new();
+
+    for index in 0..before.paragraphs.len().max(after.paragraphs.len()) {
+        match (before.paragraphs.get(index), after.paragraphs.get(index)) {
+            (Some(b), Some(a)) => diff_paragraphs(index, b, a, &mut diff),
+            (Some(_), None) => diff.push(DocDiff::ParagraphRemoved { index }),
+            (None, Some(_)) => diff.push(DocDiff::ParagraphAdded { index }),
+            (None, None) => (),
+        }
+    }
+
+    if before.relationship_manager != after.relationship_manager {
+        diff.push(DocDiff::RelationshipsChanged);
+    }
+    if before.section_properties != after.section_properties {
+        diff.push(DocDiff::SectionPropertiesChanged);
+    }
+    if before.comments != after.comments {
+        diff.push(DocDiff::CommentsChanged);
+    }
+    if before.footnotes != after.footnotes {
+        diff.push(DocDiff::FootnotesChanged);
+    }
+    diff
+}
+
+fn diff_paragraphs(index: usize, before: &Paragraph, after: &Paragraph, diff: &mut Vec<DocDiff>) {
+    if before.properties != after.properties {
+        diff.push(DocDiff::ParagraphPropertiesChanged {
+            paragraph: index,
+            before: before.properties.clone(),
+            after: after.properties.clone(),
+        });
+    }
+
+    let before_runs = before.runs().collect::<Vec<&Run>>();
+    let after_runs = after.runs().collect::<Vec<&Run>>();
+    // Same runs in a different structure (e.g. a run turned into a hyperlink)
+    let same_structure = before.children.len() == after.children.len()
+        && before_runs.len() == after_runs.len()
+        && before
+            .children
+            .iter()
+            .zip(&after.children)
+            .all(|(b, a)| std::mem::discriminant(b) == std::mem::discriminant(a));
+    if !same_structure {
+        diff.push(DocDiff::ParagraphContentChanged { paragraph: index });
+        return;
+    }
+
+    for (run, (b, a)) in before_runs.into_iter().zip(after_runs).enumerate() {
+        if b.text != a.text {
+            diff.push(DocDiff::RunTextChanged {
+                paragraph: index,
+                run,
+                before: b.text.clone(),
+                after: a.text.clone(),
+            });
+        }
+        if b.properties != a.properties {
+            diff.push(DocDiff::RunPropertiesChanged {
+                paragraph: index,
+                run,
+                before: b.properties.clone(),
+                after: a.properties.clone(),
+            });
+        }
+    }
+}
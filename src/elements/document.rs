@@ -1,8 +1,1337 @@
-use crate::elements::Paragraph;
-use crate::rels::RelationshipManager;
+use crate::elements::diff::diff_documents;
+use crate::elements::{
+    check_outline_level, Comment, DocDiff, Drawing, Footnote, HexColor, Hyperlink, Image,
+    Paragraph, ParagraphChild, ParagraphIndentation, Run, SectionProperties, Style,
+};
+use crate::errors::ValidationError;
+use crate::rels::{RelationshipKind, RelationshipManager};
+use std::collections::{HashMap, HashSet};
+use unicode_segmentation::UnicodeSegmentation;
 
+/// Representation of a docx document.
+///
+/// ### Fields
+/// > - **paragraphs:** `Vec<Paragraph>` - Paragraphs of the body, in order. [`w:body/w:p`]()
+/// > - **relationship_manager:** `RelationshipManager` - Relationships of the hyperlinks and drawings of every part, saved in
+/// >   `word/_rels/document.xml.rels` and in the `.rels` part of each other part using them. [`Relationship`]()
+/// > - **section_properties:** `SectionProperties` - Properties of the body section, with its headers and footers. [`w:body/w:sectPr`]()
+/// > - **comments:** `Vec<Comment>` - Comments the comment ranges and references of the paragraphs point to, saved in
+/// >   `word/comments.xml`. [`w:comments/w:comment`]()
+/// > - **footnotes:** `Vec<Footnote>` - Footnotes the footnote references of the runs point to, saved in `word/footnotes.xml`.
+/// >   [`w:footnotes/w:footnote`]()
+/// > - **images:** `Vec<Image>` - Images the drawings and pictures show, saved in `word/media`. [`a:blip r:embed`]()
+/// > - **has_tables:** `bool` - Whether the body, headers or footers have tables. Only filled when reading, tables are
+/// >   not part of the model yet and their content is skipped. [`w:tbl`]()
+/// > - **has_tracked_changes:** `bool` - Whether the body, headers or footers have tracked changes. Only filled when
+/// >   reading, the inserted content is read as regular content and the deleted content is dropped. [`w:ins`, `w:del`]()
+/// > - **default_font:** `Option<String>` - Font of the text that sets none, instead of the default of the consumer (often Calibri).
+/// >   `None` is unused. [`w:docDefaults/w:rPrDefault/w:rPr/w:rFonts`]()
+/// > - **default_size:** `Option<u32>` - Font size of the text that sets none, in half points (e.g. `24` == `12 pt.`). `None` is unused.
+/// >   [`w:docDefaults/w:rPrDefault/w:rPr/w:sz`]()
+/// > - **styles:** `Vec<Style>` - Style definitions the `style` of the paragraph and run properties refer to. [`w:styles/w:style`]()
+///
+/// The default font and size and the styles are saved in `word/styles.xml`, which is written whenever one of them is set.
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct Document {
     pub paragraphs: Vec<Paragraph>,
     pub relationship_manager: RelationshipManager,
+    pub section_properties: SectionProperties,
+    pub comments: Vec<Comment>,
+    pub footnotes: Vec<Footnote>,
+    pub images: Vec<Image>,
+    pub has_tables: bool,
+    pub has_tracked_changes: bool,
+    pub default_font: Option<String>,
+    pub default_size: Option<u32>,
+    pub styles: Vec<Style>,
+}
+
+impl Document {
+    /// Create a document from plain text. Every line becomes a `Paragraph` with a single default
+    /// `Run`, empty lines become empty paragraphs.
+    pub fn from_text(text: &str) -> Self {
+        let mut document = Self {
+            paragraphs: text
+                .split('\n')
+                .map(|line| Paragraph {
+                    children: match line {
+                        "" => Vec::new(),
+                        line => vec![ParagraphChild::Run(Run::from(line.to_string()))],
+                    },
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        document.fix_space_preserve();
+        document
+    }
+
+    /// Get the plain text of the document, one line per paragraph.
+    pub fn text(&self) -> String {
+        self.paragraphs
+            .iter()
+            .map(Paragraph::text)
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Check the structural invariants of the document body, collecting every problem found instead of stopping at the first one.
+    ///
+    /// It is meant to be used before saving documents built or modified through direct field access, which bypasses
+    /// the checks of constructors and setters.
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        let invalid_hex = |paragraph, run, color: &HexColor| ValidationError::InvalidHexColor {
+            paragraph,
+            run,
+            value: color.value(),
+        };
+
+        for (index, paragraph) in self.paragraphs.iter().enumerate() {
+            let properties = &paragraph.properties;
+            if let Some(level) = properties.outline_level
+                && let Err(error) = check_outline_level(level)
+            {
+                errors.push(ValidationError::InvalidParagraphProperties {
+                    paragraph: index,
+                    error,
+                });
+            }
+            if let Some(i) = &properties.indentation
+                && let Err(error) =
+                    ParagraphIndentation::new(i.left, i.right, i.first_line, i.hanging)
+            {
+                errors.push(ValidationError::InvalidParagraphProperties {
+                    paragraph: index,
+                    error,
+                });
+            }
+            if let Some(shading) = &properties.shading {
+                for color in [&shading.color, &shading.fill].into_iter().flatten() {
+                    if !color.is_valid() {
+                        errors.push(invalid_hex(index, None, color));
+                    }
+                }
+            }
+
+            for child in &paragraph.children {
+                if let ParagraphChild::Hyperlink(Hyperlink { id: Some(id), .. }) = child
+                    && !self
+                        .relationship_manager
+                        .get(id)
+                        .is_some_and(|r| r.kind == RelationshipKind::Hyperlink)
+                {
+                    errors.push(ValidationError::DanglingHyperlink {
+                        paragraph: index,
+                        id: id.clone(),
+                    });
+                }
+            }
+
+            for (run, r) in paragraph.runs().enumerate() {
+                if let Some(color) = &r.properties.color
+                    && !color.is_valid()
+                {
+                    errors.push(invalid_hex(index, Some(run), color));
+                }
+                if let Err(error) = r.properties.validate() {
+                    errors.push(ValidationError::InvalidRunProperties {
+                        paragraph: index,
+                        run,
+                        error,
+                    });
+                }
+            }
+        }
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors),
+        }
+    }
+
+    /// Compare the document against a newer version of it. See [DocDiff] for the details of the comparison.
+    pub fn diff(&self, after: &Document) -> Vec<DocDiff> {
+        diff_documents(self, after)
+    }
+
+    /// Whether both documents have the same content, regardless of the ids of their relationships. Hyperlinks and
+    /// pictures are compared by target, so a document equals its copy with renumbered relationships.
+    pub fn semantically_eq(&self, other: &Document) -> bool {
+        self.relationship_manager
+            .targets_eq(&other.relationship_manager)
+            && self.with_resolved_ids() == other.with_resolved_ids()
+    }
+
+    // Copy of the document with the relationship ids replaced by their targets
+    fn with_resolved_ids(&self) -> Document {
+        let mut document = self.clone();
+        let manager = std::mem::take(&mut document.relationship_manager);
+        let resolve = |id: &mut String| {
+            if let Some(r) = manager.get(id) {
+                id.clone_from(&r.target);
+            }
+        };
+
+        for paragraph in document.all_paragraphs_mut() {
+            for child in &mut paragraph.children {
+                match child {
+                    ParagraphChild::Hyperlink(Hyperlink { id: Some(id), .. }) => resolve(id),
+                    ParagraphChild::Drawing(d) => resolve(&mut d.image_id),
+                    _ => (),
+                }
+            }
+            for run in paragraph.runs_mut() {
+                if let Some(id) = &mut run.picture_id {
+                    resolve(id);
+                }
+            }
+        }
+        for image in &mut document.images {
+            resolve(&mut image.id);
+        }
+        document
+    }
+
+    /// Run `f` over the document and return its result along with the changes it made.
+    ///
+    /// The document is snapshotted before running `f`, so this is as expensive as cloning it.
+    pub fn apply<F: FnOnce(&mut Document) -> R, R>(&mut self, f: F) -> (R, Vec<DocDiff>) {
+        let before = self.clone();
+        let result = f(self);
+        let diff = before.diff(self);
+        (result, diff)
+    }
+
+    /// Iterate over every run of the document body, including the ones inside hyperlinks and fields.
+    pub fn runs(&self) -> impl Iterator<Item = &Run> {
+        self.paragraphs.iter().flat_map(Paragraph::runs)
+    }
+
+    /// Mutable version of [runs](crate::elements::Document::runs).
+    pub fn runs_mut(&mut self) -> impl Iterator<Item = &mut Run> {
+        self.paragraphs.iter_mut().flat_map(Paragraph::runs_mut)
+    }
+
+    /// Iterate over every hyperlink of the document body, paired with the target of its relationship. The target is
+    /// `None` for links to a bookmark and for relationships missing from the relationship manager.
+    pub fn hyperlinks(&self) -> impl Iterator<Item = (&Hyperlink, Option<&str>)> {
+        self.paragraphs
+            .iter()
+            .flat_map(|p| &p.children)
+            .filter_map(|c| match c {
+                ParagraphChild::Hyperlink(h) => Some(h),
+                _ => None,
+            })
+            .map(|h| {
+                let target =
+                    h.id.as_ref()
+                        .and_then(|id| self.relationship_manager.get(id))
+                        .map(|r| r.target.as_str());
+                (h, target)
+            })
+    }
+
+    /// Count the words of the document, splitting the text on Unicode whitespace. Words split across runs
+    /// of the same paragraph are counted once.
+    pub fn word_count(&self) -> usize {
+        self.paragraphs
+            .iter()
+            .map(|p| {
+                p.runs()
+                    .map(|r| r.text.as_str())
+                    .collect::<String>()
+                    .split_whitespace()
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Count the characters (`char`s) of every run of the document.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_whitespace` - If `false`, whitespace characters are not counted.
+    pub fn char_count(&self, include_whitespace: bool) -> usize {
+        self.runs()
+            .flat_map(|r| r.text.chars())
+            .filter(|c| include_whitespace || !c.is_whitespace())
+            .count()
+    }
+
+    /// Count the occurrences of every word of the document, using Unicode word boundaries. Only the
+    /// displayed text is taken into account (e.g. hyperlink URLs are not).
+    ///
+    /// # Arguments
+    ///
+    /// * `case_insensitive` - If `true`, words are lowercased before being counted, so `The` and `the` are the same word.
+    pub fn word_frequency(&self, case_insensitive: bool) -> HashMap<String, usize> {
+        let mut frequency = HashMap::new();
+        for paragraph in &self.paragraphs {
+            // Words can be split across runs with different formatting
+            let text = paragraph
+                .runs()
+                .map(|r| r.text.as_str())
+                .collect::<String>();
+            for word in text.unicode_words() {
+                let word = match case_insensitive {
+                    true => word.to_lowercase(),
+                    false => word.to_string(),
+                };
+                *frequency.entry(word).or_insert(0) += 1;
+            }
+        }
+        frequency
+    }
+
+    /// Roughly estimate the number of pages of the document. It is a heuristic, not a layout: every paragraph is assumed
+    /// to take as many lines as its text needs at an average character width, on a Letter page with 1 inch margins and
+    /// single line spacing. Pictures, page breaks and fonts other than the size are ignored. Returns at least `1`.
+    ///
+    /// The page is always Letter, whatever the `w:pgSz` and `w:pgMar` of the section: they are only kept as raw XML, and
+    /// the estimate is too rough for the page size to matter much.
+    pub fn estimate_page_count(&self) -> usize {
+        // Text area of the page, in twips
+        const TEXT_WIDTH: u64 = 12240 - 2 * 1440;
+        const TEXT_HEIGHT: u64 = 15840 - 2 * 1440;
+        const DEFAULT_SIZE: u32 = 22;
+
+        let height: u64 = self
+            .paragraphs
+            .iter()
+            .map(|paragraph| {
+                // Half points to twips, with the average character half as wide as the font size and lines 20% taller
+                let size = paragraph
+                    .runs()
+                    .filter_map(|r| r.properties.size)
+                    .max()
+                    .unwrap_or(DEFAULT_SIZE) as u64;
+                let (char_width, line_height) = ((size * 5).max(1), size * 12);
+
+                let indentation = paragraph.properties.indentation.as_ref().map_or(0, |i| {
+                    (i64::from(i.left.unwrap_or(0)) + i64::from(i.right.unwrap_or(0))).max(0) as u64
+                });
+                let chars_per_line = (TEXT_WIDTH.saturating_sub(indentation) / char_width).max(1);
+                let lines = (paragraph.char_len() as u64)
+                    .div_ceil(chars_per_line)
+                    .max(1);
+                lines * line_height
+            })
+            .sum();
+
+        (height.div_ceil(TEXT_HEIGHT) as usize).max(1)
+    }
+
+    /// Whether the document has pictures, either drawings or the legacy pictures found when reading.
+    pub fn contains_images(&self) -> bool {
+        !self.images.is_empty()
+            || self.all_paragraphs().any(|p| {
+                p.children
+                    .iter()
+                    .any(|c| matches!(c, ParagraphChild::Drawing(_)))
+                    || p.runs().any(|r| r.picture_id.is_some())
+            })
+    }
+
+    /// Whether the document has tables, see [has_tables](crate::elements::Document::has_tables).
+    pub fn contains_tables(&self) -> bool {
+        self.has_tables
+    }
+
+    /// Whether the document has fields (e.g. page numbers).
+    pub fn contains_fields(&self) -> bool {
+        self.all_paragraphs().any(|p| {
+            p.children
+                .iter()
+                .any(|c| matches!(c, ParagraphChild::SimpleField(_)))
+        })
+    }
+
+    /// Whether the document has tracked changes, see [has_tracked_changes](crate::elements::Document::has_tracked_changes).
+    pub fn contains_tracked_changes(&self) -> bool {
+        self.has_tracked_changes
+    }
+
+    /// Set the proofing language (e.g. `fr-FR`) of every run of the document, headers, footers, comments and footnotes included.
+    /// See [RunProperties::lang](crate::elements::RunProperties::lang).
+    pub fn set_language_all(&mut self, lang: &str) {
+        for paragraph in self.all_paragraphs_mut() {
+            for run in paragraph.runs_mut() {
+                run.properties.lang = Some(lang.to_string());
+            }
+        }
+    }
+
+    // Paragraphs of the body, headers, footers, comments and footnotes
+    pub(crate) fn all_paragraphs(&self) -> impl Iterator<Item = &Paragraph> {
+        let section = &self.section_properties;
+        let headers = [
+            &section.default_header,
+            &section.even_header,
+            &section.first_header,
+        ]
+        .into_iter()
+        .flatten()
+        .flat_map(|h| &h.paragraphs);
+        let footers = [
+            &section.default_footer,
+            &section.even_footer,
+            &section.first_footer,
+        ]
+        .into_iter()
+        .flatten()
+        .flat_map(|f| &f.paragraphs);
+        let comments = self.comments.iter().flat_map(|c| &c.paragraphs);
+        let footnotes = self.footnotes.iter().flat_map(|f| &f.paragraphs);
+
+        self.paragraphs
+            .iter()
+            .chain(headers)
+            .chain(footers)
+            .chain(comments)
+            .chain(footnotes)
+    }
+
+    // Mutable version of all_paragraphs
+    fn all_paragraphs_mut(&mut self) -> impl Iterator<Item = &mut Paragraph> {
+        let section = &mut self.section_properties;
+        let headers = [
+            &mut section.default_header,
+            &mut section.even_header,
+            &mut section.first_header,
+        ]
+        .into_iter()
+        .flatten()
+        .flat_map(|h| &mut h.paragraphs);
+        let footers = [
+            &mut section.default_footer,
+            &mut section.even_footer,
+            &mut section.first_footer,
+        ]
+        .into_iter()
+        .flatten()
+        .flat_map(|f| &mut f.paragraphs);
+        let comments = self.comments.iter_mut().flat_map(|c| &mut c.paragraphs);
+        let footnotes = self.footnotes.iter_mut().flat_map(|f| &mut f.paragraphs);
+
+        self.paragraphs
+            .iter_mut()
+            .chain(headers)
+            .chain(footers)
+            .chain(comments)
+            .chain(footnotes)
+    }
+
+    /// Remove the hyperlink and image relationships no hyperlink or drawing of the document refers to anymore, along
+    /// with the unused images. Returns the number of relationships removed.
+    ///
+    /// Relationships of other types are kept, since they may be used by parts not handled by this library.
+    pub fn prune_relationships(&mut self) -> usize {
+        let used = self
+            .all_paragraphs()
+            .flat_map(Paragraph::relationship_ids)
+            .map(String::from)
+            .collect::<HashSet<String>>();
+
+        let unused = self
+            .relationship_manager
+            .get_relationships()
+            .iter()
+            .filter(|(id, r)| {
+                matches!(
+                    r.kind,
+                    RelationshipKind::Hyperlink | RelationshipKind::Image
+                ) && !used.contains(*id)
+            })
+            .map(|(id, _)| id.clone())
+            .collect::<Vec<String>>();
+        for id in &unused {
+            self.relationship_manager.remove(id);
+        }
+        self.images.retain(|i| used.contains(&i.id));
+        unused.len()
+    }
+
+    /// Set `space_preserve` on every run whose text starts or ends with whitespace, so consumers
+    /// don't collapse it. Such runs are written preserved anyway, and read back with the flag: this
+    /// makes documents built through direct field access equal to the ones they are saved as.
+    pub fn fix_space_preserve(&mut self) {
+        for run in self.runs_mut() {
+            if Run::has_surrounding_whitespace(&run.text) {
+                run.space_preserve = true;
+            }
+        }
+    }
+
+    /// Embed an image in the document, registering its relationship, and get a [Drawing] showing it, to be placed in a paragraph.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The encoded bytes of the image file
+    /// * `content_type` - MIME type of the image (e.g. `image/png`)
+    /// * `width`, `height` - Size of the drawing in EMUs (914400 per inch)
+    pub fn add_image(
+        &mut self,
+        data: Vec<u8>,
+        content_type: &str,
+        width: u64,
+        height: u64,
+    ) -> Drawing {
+        let mut image = Image {
+            content_type: content_type.to_string(),
+            data,
+            ..Default::default()
+        };
+        // Numbered after the existing images, skipping the names still in use
+        let target = (self.images.len() + 1..)
+            .map(|n| format!("media/image{n}.{}", image.extension()))
+            .find(|t| {
+                !self
+                    .relationship_manager
+                    .get_relationships()
+                    .values()
+                    .any(|r| &r.target == t)
+            })
+            .unwrap();
+        image.id = self.relationship_manager.generate_image_rid(&target);
+        let drawing = Drawing::new(&image.id, width, height);
+        self.images.push(image);
+        drawing
+    }
+
+    /// Append the paragraphs of `other` at the end of the body. The relationships of its hyperlinks and drawings are
+    /// registered again in the relationship manager of `self`, and their ids rewritten, so they don't collide with the
+    /// existing ones. Its images are moved along. Image relationships without an image are not registered, and the ids
+    /// pointing to them are left as is.
+    ///
+    /// The comments and footnotes of `other` are moved along too, numbered after the ones of `self`, and the comment
+    /// ranges and references pointing to them are rewritten accordingly. Its headers, footers and styles are dropped.
+    pub fn append(&mut self, other: Document) {
+        let Document {
+            mut paragraphs,
+            relationship_manager,
+            mut comments,
+            mut footnotes,
+            mut images,
+            ..
+        } = other;
+        // Old id of other -> new id of self. Comment ids start at 0, footnote ids at 1 as 0 is reserved
+        let next_comment = self.comments.iter().map(|c| c.id + 1).max().unwrap_or(0);
+        let comment_ids = comments
+            .iter_mut()
+            .zip(next_comment..)
+            .map(|(c, id)| (std::mem::replace(&mut c.id, id), id))
+            .collect::<HashMap<u32, u32>>();
+        let next_footnote = self.footnotes.iter().map(|f| f.id + 1).max().unwrap_or(1);
+        let footnote_ids = footnotes
+            .iter_mut()
+            .zip(next_footnote..)
+            .map(|(f, id)| (std::mem::replace(&mut f.id, id), id))
+            .collect::<HashMap<u32, u32>>();
+        let renumber = |id: &mut u32, ids: &HashMap<u32, u32>| {
+            if let Some(new_id) = ids.get(id) {
+                *id = *new_id;
+            }
+        };
+
+        // Old id of other -> new id of self, so that children sharing a relationship keep sharing it
+        let mut ids = HashMap::new();
+        let all_paragraphs = paragraphs
+            .iter_mut()
+            .chain(comments.iter_mut().flat_map(|c| &mut c.paragraphs))
+            .chain(footnotes.iter_mut().flat_map(|f| &mut f.paragraphs));
+        for paragraph in all_paragraphs {
+            for run in paragraph.runs_mut() {
+                if let Some(id) = &mut run.comment_reference {
+                    renumber(id, &comment_ids);
+                }
+                if let Some(id) = &mut run.footnote_reference {
+                    renumber(id, &footnote_ids);
+                }
+            }
+            for child in &mut paragraph.children {
+                if let ParagraphChild::CommentRangeStart(id) | ParagraphChild::CommentRangeEnd(id) =
+                    child
+                {
+                    renumber(id, &comment_ids);
+                }
+            }
+            for (id, _) in paragraph.relationship_ids_mut() {
+                if let Some(new_id) = ids.get(id) {
+                    id.clone_from(new_id);
+                    continue;
+                }
+                let Some(relationship) = relationship_manager.get(id) else {
+                    continue;
+                };
+                let new_id = match images.iter().position(|i| &i.id == id) {
+                    Some(index) => {
+                        let image = images.remove(index);
+                        self.add_image(image.data, &image.content_type, 0, 0)
+                            .image_id
+                    }
+                    //There would be nothing to write for it on save
+                    None if relationship.kind == RelationshipKind::Image => continue,
+                    None => self.relationship_manager.generate_rid_typed(
+                        &relationship.target,
+                        relationship.kind.clone(),
+                        relationship.mode.clone(),
+                    ),
+                };
+                ids.insert(id.clone(), new_id.clone());
+                *id = new_id;
+            }
+        }
+        self.paragraphs.append(&mut paragraphs);
+        self.comments.append(&mut comments);
+        self.footnotes.append(&mut footnotes);
+    }
+
+    /// Add a style definition, replacing the one with the same id if there is one.
+    pub fn add_style(&mut self, style: Style) {
+        match self.styles.iter_mut().find(|s| s.id == style.id) {
+            Some(existing) => *existing = style,
+            None => self.styles.push(style),
+        }
+    }
+
+    /// Get the style definition with the given id.
+    pub fn style(&self, id: &str) -> Option<&Style> {
+        self.styles.iter().find(|s| s.id == id)
+    }
+
+    /// Number of paragraphs in the body of the document.
+    pub fn paragraph_count(&self) -> usize {
+        self.paragraphs.len()
+    }
+
+    /// Insert a paragraph at `index`, shifting the following ones. An `index` past the end appends the paragraph.
+    pub fn insert_paragraph(&mut self, index: usize, paragraph: Paragraph) {
+        self.paragraphs
+            .insert(index.min(self.paragraphs.len()), paragraph);
+    }
+
+    /// Remove and get the paragraph at `index`, or `None` if there is no paragraph at `index`. The relationships of
+    /// its hyperlinks are kept, see [prune_relationships](crate::elements::Document::prune_relationships).
+    pub fn remove_paragraph(&mut self, index: usize) -> Option<Paragraph> {
+        (index < self.paragraphs.len()).then(|| self.paragraphs.remove(index))
+    }
+
+    /// Keep only the paragraphs for which `predicate` returns `true`. The relationships of the hyperlinks and drawings of
+    /// the removed paragraphs are removed from the relationship manager, along with their images, unless the rest of the
+    /// document still uses them.
+    pub fn retain_paragraphs(&mut self, mut predicate: impl FnMut(&Paragraph) -> bool) {
+        let (kept, removed): (Vec<Paragraph>, Vec<Paragraph>) =
+            std::mem::take(&mut self.paragraphs)
+                .into_iter()
+                .partition(|p| predicate(p));
+        self.paragraphs = kept;
+
+        let used = self
+            .all_paragraphs()
+            .flat_map(Paragraph::relationship_ids)
+            .map(String::from)
+            .collect::<HashSet<String>>();
+        for id in removed.iter().flat_map(Paragraph::relationship_ids) {
+            if !used.contains(id) {
+                self.relationship_manager.remove(id);
+                self.images.retain(|i| i.id != id);
+            }
+        }
+    }
+
+    /// Replace every hyperlink in the document (body, headers, footers, comments and footnotes) with its display runs,
+    /// so the text is kept but is no longer clickable. The relationships of the flattened hyperlinks are removed from
+    /// the relationship manager once nothing refers to them anymore.
+    ///
+    /// # Arguments
+    ///
+    /// * `keep_url` - If `true`, a plain run with the target URL in parentheses (e.g. ` (https://example.com)`)
+    ///   is appended after the display runs of each hyperlink.
+    pub fn flatten_hyperlinks(&mut self, keep_url: bool) {
+        // Targets are resolved before any relationship is removed, as hyperlinks may share one
+        let manager = std::mem::take(&mut self.relationship_manager);
+        let mut flattened = HashSet::new();
+        for paragraph in self.all_paragraphs_mut() {
+            let children = std::mem::take(&mut paragraph.children);
+            for child in children {
+                match child {
+                    ParagraphChild::Hyperlink(hyperlink) => {
+                        let target = hyperlink.id.and_then(|id| {
+                            let target = manager.get(&id).map(|r| r.target.clone());
+                            flattened.insert(id);
+                            target
+                        });
+                        paragraph
+                            .children
+                            .extend(hyperlink.runs.into_iter().map(ParagraphChild::Run));
+                        if keep_url && let Some(url) = target {
+                            paragraph.children.push(ParagraphChild::Run(Run {
+                                text: format!(" ({url})"),
+                                space_preserve: true,
+                                ..Default::default()
+                            }));
+                        }
+                    }
+                    child => paragraph.children.push(child),
+                }
+            }
+        }
+        self.relationship_manager = manager;
+
+        let used = self
+            .all_paragraphs()
+            .flat_map(Paragraph::relationship_ids)
+            .map(String::from)
+            .collect::<HashSet<String>>();
+        for id in flattened.difference(&used) {
+            self.relationship_manager.remove(id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::{Footer, Header, HeaderFooterType, HexColor, Hyperlink, SimpleField};
+    use crate::xml::{generate, parse};
+
+    fn document_with_link() -> Document {
+        let mut document = Document::default();
+        let hyperlink = Hyperlink::new_with_text(
+            "https://example.com",
+            "Example",
+            &mut document.relationship_manager,
+        );
+        document.paragraphs.push(Paragraph {
+            children: vec![
+                ParagraphChild::Run(Run::from("Visit ".to_string())),
+                ParagraphChild::Hyperlink(hyperlink),
+                ParagraphChild::Run(Run::from(" today.".to_string())),
+            ],
+            ..Default::default()
+        });
+        document
+    }
+
+    fn texts(paragraph: &Paragraph) -> Vec<&str> {
+        paragraph
+            .children
+            .iter()
+            .map(|c| match c {
+                ParagraphChild::Run(r) => r.text.as_str(),
+                ParagraphChild::Hyperlink(_) => panic!("Hyperlink was not flattened"),
+                ParagraphChild::SimpleField(_) => panic!("Unexpected field"),
+                ParagraphChild::CommentRangeStart(_) | ParagraphChild::CommentRangeEnd(_) => {
+                    panic!("Unexpected comment range")
+                }
+                ParagraphChild::Drawing(_) => panic!("Unexpected drawing"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_flatten_hyperlinks_without_url() {
+        let mut document = document_with_link();
+        document.flatten_hyperlinks(false);
+
+        assert_eq!(
+            texts(&document.paragraphs[0]),
+            vec!["Visit ", "Example", " today."]
+        );
+        assert!(document.relationship_manager.get_links().is_empty());
+    }
+
+    #[test]
+    fn test_flatten_hyperlinks_keep_url() {
+        let mut document = document_with_link();
+        document.flatten_hyperlinks(true);
+
+        assert_eq!(
+            texts(&document.paragraphs[0]),
+            vec!["Visit ", "Example", " (https://example.com)", " today."]
+        );
+        assert!(document.relationship_manager.get_links().is_empty());
+    }
+
+    #[test]
+    fn test_flatten_hyperlinks_shared_relationship() {
+        let mut document = document_with_link();
+        let link = document.paragraphs[0]
+            .children
+            .iter()
+            .find_map(|c| match c {
+                ParagraphChild::Hyperlink(h) => Some(h.clone()),
+                _ => None,
+            })
+            .unwrap();
+        document.footnotes.push(Footnote::new(
+            1,
+            vec![Paragraph {
+                children: vec![ParagraphChild::Hyperlink(link)],
+                ..Default::default()
+            }],
+        ));
+        document.flatten_hyperlinks(true);
+
+        assert_eq!(
+            texts(&document.paragraphs[0]),
+            vec!["Visit ", "Example", " (https://example.com)", " today."]
+        );
+        assert_eq!(
+            texts(&document.footnotes[0].paragraphs[0]),
+            vec!["Example", " (https://example.com)"]
+        );
+        assert!(document.relationship_manager.get_links().is_empty());
+    }
+
+    #[test]
+    fn test_fix_space_preserve() {
+        let mut document = document_with_link();
+        document.paragraphs.push(Paragraph {
+            children: vec![
+                ParagraphChild::Run(Run::from("  indented".to_string())),
+                ParagraphChild::Run(Run::from("no padding".to_string())),
+                ParagraphChild::Run(Run::from("\t".to_string())),
+                // Only XML whitespace is collapsed by consumers
+                ParagraphChild::Run(Run::from("\u{a0}non-breaking".to_string())),
+            ],
+            ..Default::default()
+        });
+        document.fix_space_preserve();
+
+        let flags = document
+            .runs()
+            .map(|r| (r.text.as_str(), r.space_preserve))
+            .collect::<Vec<(&str, bool)>>();
+        assert_eq!(
+            flags,
+            vec![
+                ("Visit ", true),
+                ("Example", false),
+                (" today.", true),
+                ("  indented", true),
+                ("no padding", false),
+                ("\t", true),
+                ("\u{a0}non-breaking", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_text() {
+        let text = "First line\n\n  Third line";
+        let document = Document::from_text(text);
+
+        assert_eq!(document.paragraphs.len(), 3);
+        assert!(document.paragraphs[1].children.is_empty());
+        assert!(document.paragraphs[2].runs().all(|r| r.space_preserve));
+        assert_eq!(document.text(), text);
+    }
+
+    #[test]
+    fn test_word_frequency() {
+        let mut document = document_with_link();
+        document.paragraphs.push(Paragraph {
+            children: vec![
+                ParagraphChild::Run(Run::from("The cat saw the ".to_string())),
+                ParagraphChild::Run(Run::from("exam".to_string())),
+                ParagraphChild::Run(Run::from("ple.".to_string())),
+            ],
+            ..Default::default()
+        });
+
+        let frequency = document.word_frequency(false);
+        assert_eq!(frequency.get("Example"), Some(&1));
+        assert_eq!(frequency.get("example"), Some(&1));
+        assert_eq!(frequency.get("The"), Some(&1));
+        assert_eq!(frequency.get("the"), Some(&1));
+        assert_eq!(frequency.get("cat"), Some(&1));
+        assert!(!frequency.keys().any(|w| w.contains("https")));
+        assert_eq!(frequency.values().sum::<usize>(), 8);
+
+        let frequency = document.word_frequency(true);
+        assert_eq!(frequency.get("the"), Some(&2));
+        assert_eq!(frequency.get("example"), Some(&2));
+        assert_eq!(frequency.get("The"), None);
+    }
+
+    #[test]
+    fn test_word_and_char_count() {
+        let document = crate::xml::parse(
+            r#"
+            <w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+                <w:body>
+                    <w:p><w:r><w:t>This is plain text.</w:t></w:r></w:p>
+                    <w:p>
+                        <w:r><w:rPr><w:b/></w:rPr><w:t>This is bold.</w:t></w:r>
+                        <w:r><w:t xml:space="preserve"> </w:t></w:r>
+                        <w:r><w:rPr><w:i/></w:rPr><w:t>This is italic.</w:t></w:r>
+                    </w:p>
+                    <w:p><w:r><w:rPr><w:b/><w:i/></w:rPr><w:t>Bold and Italic.</w:t></w:r></w:p>
+                    <w:p>
+                        <w:hyperlink r:id="rId1">
+                            <w:r><w:rPr><w:i/></w:rPr><w:t>www.github.com/cmgsk/rudocx</w:t></w:r>
+                        </w:hyperlink>
+                        <w:r><w:t> That was hyperlink.</w:t></w:r>
+                    </w:p>
+                </w:body>
+            </w:document>
+        "#,
+        )
+        .unwrap();
+
+        assert_eq!(document.word_count(), 17);
+        assert_eq!(document.char_count(true), 111);
+        assert_eq!(document.char_count(false), 98);
+        assert_eq!(Document::default().word_count(), 0);
+    }
+
+    #[test]
+    fn test_retain_paragraphs() {
+        let mut document = document_with_link();
+        let orphan = Hyperlink::new_with_text(
+            "https://orphan.example.com",
+            "",
+            &mut document.relationship_manager,
+        );
+        let shared = Hyperlink::new_with_text(
+            "https://shared.example.com",
+            "Shared",
+            &mut document.relationship_manager,
+        );
+        document.paragraphs.push(Paragraph::default());
+        // Empty paragraph with a link to the same target as a remaining one
+        document.paragraphs.push(Paragraph {
+            children: vec![
+                ParagraphChild::Hyperlink(orphan.clone()),
+                ParagraphChild::Hyperlink(Hyperlink {
+                    runs: Vec::new(),
+                    ..shared.clone()
+                }),
+            ],
+            ..Default::default()
+        });
+        document.paragraphs.push(Paragraph {
+            children: vec![ParagraphChild::Hyperlink(shared.clone())],
+            ..Default::default()
+        });
+
+        document.retain_paragraphs(|p| p.char_len() > 0);
+
+        assert_eq!(document.paragraphs.len(), 2);
+        let links = document.relationship_manager.get_links();
+        assert_eq!(links.len(), 2);
+        assert!(links.contains_key(shared.id.as_ref().unwrap()));
+        assert!(!links.contains_key(orphan.id.as_ref().unwrap()));
+    }
+
+    #[test]
+    fn test_retain_paragraphs_removes_images() {
+        let mut document = Document::from_text("Kept");
+        let removed = document.add_image(vec![0], "image/png", 1, 1);
+        let kept = document.add_image(vec![1], "image/png", 1, 1);
+        document.paragraphs.push(Paragraph {
+            children: vec![ParagraphChild::Drawing(removed.clone())],
+            ..Default::default()
+        });
+        // Same image in the body and in a header, only the body one is removed
+        document.paragraphs.push(Paragraph {
+            children: vec![ParagraphChild::Drawing(kept.clone())],
+            ..Default::default()
+        });
+        document.section_properties.default_header = Some(Header::new(vec![Paragraph {
+            children: vec![ParagraphChild::Drawing(kept.clone())],
+            ..Default::default()
+        }]));
+
+        document.retain_paragraphs(|p| p.char_len() > 0);
+
+        assert_eq!(document.paragraphs.len(), 1);
+        let images = document.relationship_manager.get_images();
+        assert_eq!(images.len(), 1);
+        assert!(images.contains_key(&kept.image_id));
+        assert_eq!(document.images.len(), 1);
+        assert_eq!(document.images[0].id, kept.image_id);
+    }
+
+    #[test]
+    fn test_apply_reports_diff() {
+        let mut document = document_with_link();
+
+        let (changed, diff) = document.apply(|d| {
+            let run = d.runs_mut().nth(1).unwrap();
+            run.properties.color = Some(HexColor::new("FF0000"));
+            run.text.len()
+        });
+
+        assert_eq!(changed, "Example".len());
+        assert_eq!(diff.len(), 1);
+        match &diff[0] {
+            DocDiff::RunPropertiesChanged {
+                paragraph,
+                run,
+                before,
+                after,
+            } => {
+                assert_eq!((*paragraph, *run), (0, 1));
+                assert_eq!(before.color, None);
+                assert_eq!(after.color, Some(HexColor::new("FF0000")));
+            }
+            d => panic!("Unexpected diff {d:?}"),
+        }
+
+        let (_, diff) = document.apply(|d| d.paragraphs.push(Paragraph::default()));
+        assert_eq!(diff, vec![DocDiff::ParagraphAdded { index: 1 }]);
+        let (_, diff) = document.apply(|_| ());
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut document = document_with_link();
+        assert!(document.validate().is_ok());
+
+        document.paragraphs[0]
+            .children
+            .push(ParagraphChild::Hyperlink(Hyperlink {
+                id: Some(String::from("rId99")),
+                ..Default::default()
+            }));
+        match document.validate() {
+            Err(errors) => {
+                assert_eq!(errors.len(), 1);
+                assert!(matches!(
+                    &errors[0],
+                    ValidationError::DanglingHyperlink { paragraph: 0, id } if id == "rId99"
+                ));
+            }
+            Ok(()) => panic!("Dangling hyperlink was not reported"),
+        }
+
+        let run = document.runs_mut().next().unwrap();
+        run.properties.color = Some(HexColor {
+            value: String::from("red"),
+        });
+        document.paragraphs[0].properties.outline_level = Some(12);
+        assert_eq!(document.validate().unwrap_err().len(), 3);
+    }
+
+    #[test]
+    fn test_set_language_all() {
+        let mut document = document_with_link();
+        document.section_properties.default_header = Some(Header::new(vec![Paragraph {
+            children: vec![ParagraphChild::Run(Run::from("Header".to_string()))],
+            ..Default::default()
+        }]));
+        document.set_language_all("fr-FR");
+
+        assert!(document.runs().count() > 1);
+        for run in document.runs().chain(
+            document
+                .section_properties
+                .default_header
+                .as_ref()
+                .unwrap()
+                .paragraphs[0]
+                .runs(),
+        ) {
+            assert_eq!(run.properties.lang.as_deref(), Some("fr-FR"));
+        }
+
+        // Runs with surrounding whitespace are always written preserved, and read back as such
+        document.fix_space_preserve();
+        let saved = parse(&generate(&document).unwrap()).unwrap();
+        assert_eq!(saved.paragraphs, document.paragraphs);
+    }
+
+    #[test]
+    fn test_estimate_page_count() {
+        assert_eq!(Document::default().estimate_page_count(), 1);
+        assert_eq!(
+            Document::from_text("A single line").estimate_page_count(),
+            1
+        );
+
+        let line = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt.";
+        let long = Document::from_text(&vec![line; 200].join("\n"));
+        assert!(long.estimate_page_count() > 1);
+
+        let mut large = Document::from_text(&vec![line; 20].join("\n"));
+        let pages = large.estimate_page_count();
+        for run in large.runs_mut() {
+            run.properties.size = Some(72);
+        }
+        assert!(large.estimate_page_count() > pages);
+    }
+
+    #[test]
+    fn test_prune_relationships() {
+        let mut document = Document::default();
+        for target in ["https://example.com", "https://rust-lang.org"] {
+            let link = Hyperlink::new(target, &mut document.relationship_manager);
+            document.paragraphs.push(Paragraph {
+                children: vec![ParagraphChild::Hyperlink(link)],
+                ..Default::default()
+            });
+        }
+        document.add_image(vec![0], "image/png", 1, 1);
+        assert_eq!(document.relationship_manager.get_relationships().len(), 3);
+
+        document.paragraphs.remove(0);
+        assert_eq!(document.prune_relationships(), 2);
+        let links = document.relationship_manager.get_links();
+        assert_eq!(links.len(), 1);
+        assert_eq!(
+            links.get("rId2").map(String::as_str),
+            Some("https://rust-lang.org")
+        );
+        assert!(document.images.is_empty());
+        assert!(document.relationship_manager.get_images().is_empty());
+    }
+
+    #[test]
+    fn test_estimate_page_count_extreme_indentation() {
+        let mut document = Document::from_text("Indented");
+        document.paragraphs[0].properties.indentation = Some(ParagraphIndentation {
+            left: Some(i32::MAX),
+            right: Some(i32::MAX),
+            ..Default::default()
+        });
+        assert_eq!(document.estimate_page_count(), 1);
+    }
+
+    #[test]
+    fn test_contains_features() {
+        let mut document = Document::from_text("Page");
+        assert!(!document.contains_images());
+        assert!(!document.contains_fields());
+        assert!(!document.contains_tables());
+        assert!(!document.contains_tracked_changes());
+
+        let drawing = document.add_image(vec![0], "image/png", 1, 1);
+        document.paragraphs[0]
+            .children
+            .push(ParagraphChild::Drawing(drawing));
+        assert!(document.contains_images());
+
+        let mut footer = Footer::default();
+        footer.paragraphs.push(Paragraph {
+            children: vec![ParagraphChild::SimpleField(SimpleField::new(
+                "PAGE",
+                vec![],
+            ))],
+            ..Default::default()
+        });
+        document
+            .section_properties
+            .set_footer(HeaderFooterType::Default, Some(footer));
+        assert!(document.contains_fields());
+
+        let table = parse(
+            r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>
+                <w:tbl><w:tr><w:tc><w:p><w:r><w:t>Cell</w:t></w:r></w:p></w:tc></w:tr></w:tbl>
+            </w:body></w:document>"#,
+        )
+        .unwrap();
+        assert!(table.contains_tables());
+        assert!(!table.contains_tracked_changes());
+
+        let revision = parse(
+            r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>
+                <w:p><w:ins w:id="1" w:author="A"><w:r><w:t>New</w:t></w:r></w:ins></w:p>
+            </w:body></w:document>"#,
+        )
+        .unwrap();
+        assert!(revision.contains_tracked_changes());
+        assert!(!revision.contains_tables());
+    }
+
+    #[test]
+    fn test_semantically_eq() {
+        let mut document = Document::default();
+        let mut other = Document::default();
+        other
+            .relationship_manager
+            .generate_rid("https://unused.com");
+        other.relationship_manager.remove("rId1");
+        for doc in [&mut document, &mut other] {
+            let link = Hyperlink::new("https://example.com", &mut doc.relationship_manager);
+            doc.paragraphs.push(Paragraph {
+                children: vec![ParagraphChild::Hyperlink(link)],
+                ..Default::default()
+            });
+        }
+
+        assert_ne!(document, other);
+        assert!(document.semantically_eq(&other));
+
+        let link = Hyperlink::new("https://rust-lang.org", &mut other.relationship_manager);
+        other.paragraphs[0].children = vec![ParagraphChild::Hyperlink(link)];
+        assert!(!document.semantically_eq(&other));
+    }
+
+    #[test]
+    fn test_append() {
+        let mut document = document_with_link();
+        let mut other = Document::default();
+        let hyperlink = Hyperlink::new("https://example.org", &mut other.relationship_manager);
+        other.paragraphs.push(Paragraph {
+            children: vec![ParagraphChild::Hyperlink(hyperlink)],
+            ..Default::default()
+        });
+
+        document.append(other);
+        assert_eq!(document.paragraphs.len(), 2);
+        let targets = document
+            .paragraphs
+            .iter()
+            .flat_map(|p| &p.children)
+            .filter_map(|c| match c {
+                ParagraphChild::Hyperlink(h) => h.id.as_deref(),
+                _ => None,
+            })
+            .map(|id| {
+                (
+                    id,
+                    document
+                        .relationship_manager
+                        .get(id)
+                        .unwrap()
+                        .target
+                        .as_str(),
+                )
+            })
+            .collect::<Vec<(&str, &str)>>();
+        assert_eq!(
+            targets,
+            [
+                ("rId1", "https://example.com"),
+                ("rId2", "https://example.org")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_append_skips_images_without_data() {
+        let mut document = Document::from_text("First");
+        let mut other = Document::default();
+        other
+            .relationship_manager
+            .add_image_relationship(String::from("rId7"), String::from("media/missing.png"));
+        other.paragraphs.push(Paragraph {
+            children: vec![ParagraphChild::Drawing(Drawing::new("rId7", 1, 1))],
+            ..Default::default()
+        });
+
+        document.append(other);
+        assert!(document.relationship_manager.get_images().is_empty());
+        assert!(document.images.is_empty());
+        assert_eq!(
+            document.paragraphs[1].children,
+            [ParagraphChild::Drawing(Drawing::new("rId7", 1, 1))]
+        );
+    }
+
+    #[test]
+    fn test_append_renumbers_comments_and_footnotes() {
+        let commented = |text: &str| {
+            let mut document = Document::default();
+            document.paragraphs.push(Paragraph {
+                children: vec![
+                    ParagraphChild::CommentRangeStart(0),
+                    ParagraphChild::Run(Run::from(text)),
+                    ParagraphChild::CommentRangeEnd(0),
+                    ParagraphChild::Run(Run {
+                        comment_reference: Some(0),
+                        footnote_reference: Some(1),
+                        ..Default::default()
+                    }),
+                ],
+                ..Default::default()
+            });
+            let note = Paragraph {
+                children: vec![ParagraphChild::Run(Run::from(text))],
+                ..Default::default()
+            };
+            document
+                .comments
+                .push(Comment::new(0, "A", vec![note.clone()]));
+            document.footnotes.push(Footnote::new(1, vec![note]));
+            document
+        };
+        let mut document = commented("first");
+        document.append(commented("second"));
+
+        assert_eq!(
+            document
+                .comments
+                .iter()
+                .map(|c| (c.id, c.paragraphs[0].text()))
+                .collect::<Vec<_>>(),
+            [(0, String::from("first")), (1, String::from("second"))]
+        );
+        assert_eq!(
+            document.footnotes.iter().map(|f| f.id).collect::<Vec<_>>(),
+            [1, 2]
+        );
+        let children = &document.paragraphs[1].children;
+        assert_eq!(children[0], ParagraphChild::CommentRangeStart(1));
+        assert_eq!(children[2], ParagraphChild::CommentRangeEnd(1));
+        let ParagraphChild::Run(reference) = &children[3] else {
+            panic!("Reference run was not kept");
+        };
+        assert_eq!(reference.comment_reference, Some(1));
+        assert_eq!(reference.footnote_reference, Some(2));
+        assert!(document.validate().is_ok());
+    }
+
+    #[test]
+    fn test_insert_and_remove_paragraph() {
+        let paragraph = |text: &str| Paragraph {
+            children: vec![ParagraphChild::Run(Run::from(text))],
+            ..Default::default()
+        };
+        let mut document = Document::from_text("first\nsecond\nthird");
+        assert_eq!(document.paragraph_count(), 3);
+
+        document.insert_paragraph(0, paragraph("front"));
+        assert_eq!(document.text(), "front\nfirst\nsecond\nthird");
+
+        let removed = document.remove_paragraph(2).unwrap();
+        assert_eq!(removed.text(), "second");
+        assert_eq!(document.text(), "front\nfirst\nthird");
+        assert_eq!(document.paragraph_count(), 3);
+
+        assert!(document.remove_paragraph(3).is_none());
+        document.insert_paragraph(10, paragraph("back"));
+        assert_eq!(document.text(), "front\nfirst\nthird\nback");
+    }
+
+    #[test]
+    fn test_hyperlinks() {
+        let mut document = document_with_link();
+        let second = Hyperlink::new("https://rust-lang.org", &mut document.relationship_manager);
+        document.paragraphs.push(Paragraph {
+            children: vec![
+                ParagraphChild::Hyperlink(second),
+                ParagraphChild::Hyperlink(Hyperlink::new_anchor("top", "Back to top")),
+            ],
+            ..Default::default()
+        });
+
+        let hyperlinks = document
+            .hyperlinks()
+            .map(|(h, target)| (h.runs[0].text.as_str(), target))
+            .collect::<Vec<(&str, Option<&str>)>>();
+        assert_eq!(
+            hyperlinks,
+            [
+                ("Example", Some("https://example.com")),
+                ("https://rust-lang.org", Some("https://rust-lang.org")),
+                ("Back to top", None),
+            ]
+        );
+    }
 }
@@ -0,0 +1,35 @@
+use crate::elements::Run;
+
+/// Represents a simple field (`w:fldSimple`), whose value is computed by the consumer software.
+///
+/// The runs hold the last computed result, which is shown until the field gets updated.
+///
+/// # Examples
+///
+/// ```rust
+/// use rudocx::elements::SimpleField;
+///
+/// let page_number = SimpleField::page_number();
+/// assert_eq!(page_number.instruction, "PAGE");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SimpleField {
+    /// The field instruction (e.g. `PAGE`, `NUMPAGES`, `DATE \@ "dd/MM/yyyy"`)
+    pub instruction: String,
+    /// The runs containing the cached result of the field
+    pub runs: Vec<Run>,
+}
+
+impl SimpleField {
+    pub fn new(instruction: &str, runs: Vec<Run>) -> Self {
+        Self {
+            instruction: instruction.to_string(),
+            runs,
+        }
+    }
+
+    /// Field displaying the current page number.
+    pub fn page_number() -> Self {
+        Self::new("PAGE", vec![Run::from("1".to_string())])
+    }
+}
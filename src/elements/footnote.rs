@@ -0,0 +1,21 @@
+use crate::elements::Paragraph;
+
+/// Represents a footnote of the document [`w:footnote`](). Footnotes are saved in the `word/footnotes.xml` part.
+///
+/// The footnote mark is shown where a run with [Run::footnote_reference](crate::elements::Run::footnote_reference) is placed.
+///
+/// ### Fields
+/// > - **id:** `u32` - Identifier shared by the footnote and its reference. `0` is reserved for the separators of the part,
+/// >   so ids start at `1`. [`w:id`]()
+/// > - **paragraphs:** `Vec<Paragraph>` - The content of the footnote.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Footnote {
+    pub id: u32,
+    pub paragraphs: Vec<Paragraph>,
+}
+
+impl Footnote {
+    pub fn new(id: u32, paragraphs: Vec<Paragraph>) -> Self {
+        Self { id, paragraphs }
+    }
+}
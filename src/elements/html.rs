@@ -0,0 +1,440 @@
+// --- HTML export ---
+
+use crate::elements::{
+    AlignValues, Document, Drawing, HighlightPalette, Paragraph, ParagraphChild,
+    ParagraphJustificationValues, ParagraphProperties, Run, RunProperties,
+};
+
+/// Options to tweak how a document is exported by [Document::to_html_document].
+///
+/// ### Fields
+/// > - **title:** `Option<String>` - Content of the `<title>` of the page. `None` writes an empty title.
+/// > - **link_images:** `bool` - Point the `<img>` elements to the path of the image parts (e.g. `media/image1.png`)
+/// >   instead of embedding the images as base64 `data:` URLs. The output is no longer self-contained.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HtmlOptions {
+    pub title: Option<String>,
+    pub link_images: bool,
+}
+
+impl Document {
+    /// Export the body as an HTML fragment, one `<p>` per paragraph, with the formatting written in `style`
+    /// attributes. Images are embedded as base64 `data:` URLs.
+    pub fn to_html(&self) -> String {
+        let options = HtmlOptions::default();
+        let mut writer = HtmlWriter::new(self, &options, true);
+        writer.write_body();
+        writer.html
+    }
+
+    /// Export the document as a complete, self-contained HTML page. Paragraphs and runs with the same formatting
+    /// share a class of the `<style>` block of the `<head>`. See [HtmlOptions].
+    pub fn to_html_document(&self, options: &HtmlOptions) -> String {
+        let mut writer = HtmlWriter::new(self, options, false);
+        writer.write_body();
+
+        let mut html = String::from("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+        html.push_str(&format!(
+            "<title>{}</title>\n<style>\n",
+            escape(options.title.as_deref().unwrap_or_default())
+        ));
+        for (i, css) in writer.classes.iter().enumerate() {
+            html.push_str(&format!(".c{i} {{ {css} }}\n"));
+        }
+        html.push_str("</style>\n</head>\n<body>\n");
+        html.push_str(&writer.html);
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+}
+
+struct HtmlWriter<'a> {
+    document: &'a Document,
+    options: &'a HtmlOptions,
+    inline_styles: bool,
+    // CSS of every class, named `c` followed by its index
+    classes: Vec<String>,
+    html: String,
+}
+
+impl<'a> HtmlWriter<'a> {
+    fn new(document: &'a Document, options: &'a HtmlOptions, inline_styles: bool) -> Self {
+        Self {
+            document,
+            options,
+            inline_styles,
+            classes: Vec::new(),
+            html: String::new(),
+        }
+    }
+
+    fn write_body(&mut self) {
+        for paragraph in &self.document.paragraphs {
+            self.write_paragraph(paragraph);
+        }
+    }
+
+    fn write_paragraph(&mut self, paragraph: &Paragraph) {
+        let style = self.style_attribute(paragraph_css(&paragraph.properties));
+        self.html.push_str(&format!("<p{style}>"));
+        for child in &paragraph.children {
+            match child {
+                ParagraphChild::Run(run) => self.write_run(run),
+                ParagraphChild::Hyperlink(hyperlink) => {
+                    // Targets with another scheme (e.g. `javascript:`) are not linked, only their text is kept
+                    let target = hyperlink
+                        .href(&self.document.relationship_manager)
+                        .filter(|href| is_safe_url(href));
+                    if let Some(href) = &target {
+                        self.html
+                            .push_str(&format!("<a href=\"{}\">", escape(href)));
+                    }
+                    hyperlink.runs.iter().for_each(|r| self.write_run(r));
+                    if target.is_some() {
+                        self.html.push_str("</a>");
+                    }
+                }
+                ParagraphChild::SimpleField(field) => {
+                    field.runs.iter().for_each(|r| self.write_run(r))
+                }
+                ParagraphChild::Drawing(drawing) => self.write_drawing(drawing),
+                ParagraphChild::CommentRangeStart(_) | ParagraphChild::CommentRangeEnd(_) => (),
+            }
+        }
+        self.html.push_str("</p>\n");
+    }
+
+    fn write_run(&mut self, run: &Run) {
+        if run.break_type.is_some() {
+            self.html.push_str("<br>");
+        }
+        if run.text.is_empty() {
+            return;
+        }
+        let css = run_css(&run.properties);
+        if css.is_empty() {
+            self.html.push_str(&escape(&run.text));
+        } else {
+            let style = self.style_attribute(css);
+            self.html
+                .push_str(&format!("<span{style}>{}</span>", escape(&run.text)));
+        }
+    }
+
+    fn write_drawing(&mut self, drawing: &Drawing) {
+        let Some(r) = self.document.relationship_manager.get(&drawing.image_id) else {
+            return;
+        };
+        let src = match self
+            .document
+            .images
+            .iter()
+            .find(|i| i.id == drawing.image_id)
+        {
+            Some(image) if !self.options.link_images => {
+                format!("data:{};base64,{}", image.content_type, base64(&image.data))
+            }
+            _ => r.target.clone(),
+        };
+        // Drawing sizes are in EMUs, 9525 per CSS pixel
+        self.html.push_str(&format!(
+            "<img src=\"{}\" width=\"{}\" height=\"{}\">",
+            escape(&src),
+            drawing.width / 9525,
+            drawing.height / 9525
+        ));
+    }
+
+    /// The `style` or `class` attribute applying `css`, reusing the class of the same CSS if there is one.
+    fn style_attribute(&mut self, css: String) -> String {
+        if css.is_empty() {
+            return String::new();
+        }
+        if self.inline_styles {
+            return format!(" style=\"{}\"", escape(&css));
+        }
+        let index = match self.classes.iter().position(|c| c == &css) {
+            Some(index) => index,
+            None => {
+                self.classes.push(css);
+                self.classes.len() - 1
+            }
+        };
+        format!(" class=\"c{index}\"")
+    }
+}
+
+fn paragraph_css(properties: &ParagraphProperties) -> String {
+    let mut css = Vec::new();
+    if let Some(justification) = &properties.justification {
+        let align = match justification {
+            ParagraphJustificationValues::Start | ParagraphJustificationValues::Left => "left",
+            ParagraphJustificationValues::Center => "center",
+            ParagraphJustificationValues::End | ParagraphJustificationValues::Right => "right",
+            ParagraphJustificationValues::Both => "justify",
+            ParagraphJustificationValues::Distribute => "justify;text-align-last:justify",
+        };
+        css.push(format!("text-align:{align}"));
+    }
+    if let Some(indentation) = &properties.indentation {
+        if let Some(left) = indentation.left {
+            css.push(format!("margin-left:{}pt", left as f32 / 20.0));
+        }
+        if let Some(right) = indentation.right {
+            css.push(format!("margin-right:{}pt", right as f32 / 20.0));
+        }
+        if let Some(first_line) = indentation.first_line {
+            css.push(format!("text-indent:{}pt", first_line as f32 / 20.0));
+        }
+        if let Some(hanging) = indentation.hanging {
+            css.push(format!("text-indent:-{}pt", hanging as f32 / 20.0));
+        }
+    }
+    if let Some(fill) = properties
+        .shading
+        .as_ref()
+        .and_then(|s| s.fill.as_ref())
+        .filter(|f| f.is_valid())
+    {
+        css.push(format!("background-color:#{}", fill.value()));
+    }
+    css.join(";")
+}
+
+fn run_css(properties: &RunProperties) -> String {
+    let mut css = Vec::new();
+    if properties.bold {
+        css.push(String::from("font-weight:bold"));
+    }
+    if properties.italic {
+        css.push(String::from("font-style:italic"));
+    }
+
+    let mut decorations = Vec::new();
+    if properties
+        .underline
+        .as_ref()
+        .is_some_and(|u| u.value.is_some())
+    {
+        decorations.push("underline");
+    }
+    if properties.strike || properties.dstrike {
+        decorations.push("line-through");
+    }
+    if !decorations.is_empty() {
+        css.push(format!("text-decoration:{}", decorations.join(" ")));
+    }
+    if properties.dstrike {
+        css.push(String::from("text-decoration-style:double"));
+    }
+
+    if let Some(color) = properties.color.as_ref().filter(|c| c.is_valid()) {
+        css.push(format!("color:#{}", color.value()));
+    }
+    if let Some(size) = properties.size {
+        css.push(format!("font-size:{}pt", size as f32 / 2.0));
+    }
+    if let Some(font) = properties
+        .font
+        .as_ref()
+        .and_then(|f| f.ascii.as_ref().or(f.hi_ansi.as_ref()))
+    {
+        css.push(format!("font-family:{}", css_string(font)));
+    }
+    if let Some(highlight) = properties.highlight.as_ref().and_then(|h| h.value.as_ref()) {
+        css.push(format!("background-color:{}", highlight_css(highlight)));
+    }
+    match properties.valign.as_ref().map(|v| &v.value) {
+        Some(AlignValues::Superscript) => css.push(String::from("vertical-align:super")),
+        Some(AlignValues::Subscript) => css.push(String::from("vertical-align:sub")),
+        _ => {
+            if let Some(position) = properties.position.filter(|p| *p != 0) {
+                css.push(format!("vertical-align:{}pt", position as f32 / 2.0));
+            }
+        }
+    }
+    if let Some(spacing) = properties.spacing {
+        css.push(format!("letter-spacing:{}pt", spacing as f32 / 20.0));
+    }
+    css.join(";")
+}
+
+fn highlight_css(highlight: &HighlightPalette) -> &'static str {
+    match highlight {
+        HighlightPalette::Yellow => "yellow",
+        HighlightPalette::DarkYellow => "olive",
+        HighlightPalette::Green => "lime",
+        HighlightPalette::DarkGreen => "green",
+        HighlightPalette::Cyan => "cyan",
+        HighlightPalette::DarkCyan => "teal",
+        HighlightPalette::Magenta => "magenta",
+        HighlightPalette::DarkMagenta => "purple",
+        HighlightPalette::Blue => "blue",
+        HighlightPalette::DarkBlue => "navy",
+        HighlightPalette::Red => "red",
+        HighlightPalette::DarkRed => "maroon",
+        HighlightPalette::Black => "black",
+        HighlightPalette::White => "white",
+    }
+}
+
+/// Whether a hyperlink target can be linked from the export: web and mail addresses, and anchors in the page.
+pub(crate) fn is_safe_url(url: &str) -> bool {
+    if url.starts_with('#') {
+        return true;
+    }
+    let scheme = url.split_once(':').map(|(s, _)| s.to_ascii_lowercase());
+    matches!(scheme.as_deref(), Some("http" | "https" | "mailto"))
+}
+
+/// `text` as a quoted CSS string. Everything but letters, digits, spaces, `-` and `_` is written as a CSS escape, so
+/// the value can't end the string, the declaration or the surrounding `style` attribute or `<style>` block.
+fn css_string(text: &str) -> String {
+    let mut quoted = String::from("'");
+    for c in text.chars() {
+        if c.is_alphanumeric() || matches!(c, ' ' | '-' | '_') {
+            quoted.push(c);
+        } else {
+            quoted.push_str(&format!("\\{:x} ", c as u32));
+        }
+    }
+    quoted.push('\'');
+    quoted
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let group = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(group >> (18 - 6 * i) & 0x3F) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::{FontSet, Hyperlink};
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_to_html() {
+        let mut document = Document::from_text("Tom & Jerry");
+        document.paragraphs[0]
+            .children
+            .push(ParagraphChild::Run(Run {
+                properties: RunProperties {
+                    bold: true,
+                    ..Default::default()
+                },
+                text: String::from(" <3"),
+                ..Default::default()
+            }));
+        let link = Hyperlink::new("https://example.com", &mut document.relationship_manager);
+        document.paragraphs.push(Paragraph {
+            children: vec![ParagraphChild::Hyperlink(link)],
+            ..Default::default()
+        });
+
+        assert_eq!(
+            document.to_html(),
+            "<p>Tom &amp; Jerry<span style=\"font-weight:bold\"> &lt;3</span></p>\n\
+             <p><a href=\"https://example.com\">https://example.com</a></p>\n"
+        );
+    }
+
+    #[test]
+    fn test_to_html_document() {
+        let mut document = Document::from_text("Picture:");
+        let drawing = document.add_image(b"png".to_vec(), "image/png", 952500, 476250);
+        document.paragraphs[0]
+            .children
+            .push(ParagraphChild::Drawing(drawing));
+        for paragraph in &mut document.paragraphs {
+            paragraph
+                .runs_mut()
+                .for_each(|r| r.properties.italic = true);
+        }
+
+        let options = HtmlOptions {
+            title: Some(String::from("Preview")),
+            ..Default::default()
+        };
+        let html = document.to_html_document(&options);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>Preview</title>"));
+        assert!(html.contains("<style>\n.c0 { font-style:italic }\n</style>"));
+        assert!(html.contains("<span class=\"c0\">Picture:</span>"));
+        assert!(
+            html.contains("<img src=\"data:image/png;base64,cG5n\" width=\"100\" height=\"50\">")
+        );
+
+        let options = HtmlOptions {
+            link_images: true,
+            ..Default::default()
+        };
+        assert!(
+            document
+                .to_html_document(&options)
+                .contains("<img src=\"media/image1.png\"")
+        );
+    }
+
+    #[test]
+    fn test_to_html_escapes_css_and_urls() {
+        let mut document = Document::from_text("Hi");
+        for paragraph in &mut document.paragraphs {
+            paragraph.runs_mut().for_each(|r| {
+                r.properties.font = Some(FontSet {
+                    ascii: Some(String::from("x'}</style><script>\"")),
+                    ..Default::default()
+                });
+            });
+        }
+        let script = Hyperlink::new("javascript:alert(1)", &mut document.relationship_manager);
+        let mail = Hyperlink::new("MAILTO:me@example.com", &mut document.relationship_manager);
+        document.paragraphs.push(Paragraph {
+            children: vec![
+                ParagraphChild::Hyperlink(script),
+                ParagraphChild::Hyperlink(mail),
+            ],
+            ..Default::default()
+        });
+
+        let html = document.to_html_document(&HtmlOptions::default());
+        assert!(html.contains(
+            ".c0 { font-family:'x\\27 \\7d \\3c \\2f style\\3e \\3c script\\3e \\22 ' }"
+        ));
+        assert!(!html.contains("<script>"));
+        assert!(html.contains("<p>javascript:alert(1)<a href=\"MAILTO:me@example.com\">"));
+        assert!(!document.to_html().contains("</style>"));
+    }
+}
@@ -4,7 +4,8 @@ use crate::rels::RelationshipManager;
 /// Represents a hyperlink in a DOCX document.
 /// 
 /// A hyperlink consists of:
-/// - An ID that references a relationship in the document's relationship manager
+/// - An ID that references a relationship in the document's relationship manager, or an anchor naming a bookmark
+///   of the document for internal links
 /// - One or more runs containing the display text (which can be formatted)
 /// 
 /// # Examples
@@ -24,23 +25,20 @@ use crate::rels::RelationshipManager;
 ///     &mut document.relationship_manager
 /// );
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct Hyperlink {
-    /// The relationship ID that links to the target URL
-    pub id: String,
+    /// The relationship ID that links to the target URL [`r:id`]()
+    pub id: Option<String>,
+    /// The name of the bookmark of the document the hyperlink points to [`w:anchor`]()
+    pub anchor: Option<String>,
+    /// The screen tip shown when hovering the hyperlink [`w:tooltip`]()
+    pub tooltip: Option<String>,
+    /// Whether following the hyperlink adds its target to the list of viewed hyperlinks [`w:history`]()
+    pub history: Option<bool>,
     /// The runs containing the display text (can be formatted)
     pub runs: Vec<Run>,
 }
 
-impl Default for Hyperlink {
-    fn default() -> Self {
-        Self {
-            id: String::new(),
-            runs: Vec::new(),
-        }
-    }
-}
-
 impl Hyperlink {
     /// Create a new hyperlink with a relationship manager.
     /// The display text will be the same as the target URL.
@@ -57,8 +55,9 @@ impl Hyperlink {
         let id = relationship_manager.generate_rid(target);
 
         Self {
-            id,
+            id: Some(id),
             runs: vec![Run::from(target.to_string())],
+            ..Default::default()
         }
     }
 
@@ -73,8 +72,9 @@ impl Hyperlink {
         let id = relationship_manager.generate_rid(target);
 
         Self {
-            id,
+            id: Some(id),
             runs: vec![Run::from(display_text.to_string())],
+            ..Default::default()
         }
     }
 
@@ -88,7 +88,31 @@ impl Hyperlink {
     pub fn new_with_runs(target: &str, runs: Vec<Run>, relationship_manager: &mut RelationshipManager) -> Self {
         let id = relationship_manager.generate_rid(target);
 
-        Self { id, runs }
+        Self { id: Some(id), runs, ..Default::default() }
+    }
+
+    /// Create a new hyperlink to a bookmark of the document. No relationship is needed.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `anchor` - The name of the bookmark (e.g. `section2`)
+    /// * `display_text` - The text to display for the hyperlink
+    pub fn new_anchor(anchor: &str, display_text: &str) -> Self {
+        Self {
+            anchor: Some(anchor.to_string()),
+            runs: vec![Run::from(display_text.to_string())],
+            ..Default::default()
+        }
+    }
+
+    /// Where the hyperlink points to: the target of its relationship, or `#` followed by its anchor. `None` if
+    /// the relationship isn't registered in `relationship_manager` and there is no anchor.
+    pub fn href(&self, relationship_manager: &RelationshipManager) -> Option<String> {
+        match (&self.id, &self.anchor) {
+            (Some(id), _) if let Some(r) = relationship_manager.get(id) => Some(r.target.clone()),
+            (_, Some(anchor)) => Some(format!("#{anchor}")),
+            _ => None,
+        }
     }
 }
 
@@ -102,7 +126,7 @@ mod tests {
         let mut rel_manager = RelationshipManager::new();
         let hyperlink = Hyperlink::new("https://example.com", &mut rel_manager);
         
-        assert_eq!(hyperlink.id, "rId1");
+        assert_eq!(hyperlink.id.as_deref(), Some("rId1"));
         assert_eq!(hyperlink.runs.len(), 1);
         assert_eq!(hyperlink.runs[0].text, "https://example.com");
         assert_eq!(rel_manager.get_links().len(), 1);
@@ -118,7 +142,7 @@ mod tests {
             &mut rel_manager
         );
         
-        assert_eq!(hyperlink.id, "rId1");
+        assert_eq!(hyperlink.id.as_deref(), Some("rId1"));
         assert_eq!(hyperlink.runs.len(), 1);
         assert_eq!(hyperlink.runs[0].text, "Rust Programming Language");
         assert_eq!(rel_manager.get_links().get("rId1"), Some(&"https://rust-lang.org".to_string()));
@@ -133,7 +157,7 @@ mod tests {
         ];
         let hyperlink = Hyperlink::new_with_runs("https://example.com", runs, &mut rel_manager);
         
-        assert_eq!(hyperlink.id, "rId1");
+        assert_eq!(hyperlink.id.as_deref(), Some("rId1"));
         assert_eq!(hyperlink.runs.len(), 2);
         assert_eq!(hyperlink.runs[0].text, "First part ");
         assert_eq!(hyperlink.runs[1].text, "Second part");
@@ -147,9 +171,9 @@ mod tests {
         let hyperlink2 = Hyperlink::new("https://example2.com", &mut rel_manager);
         let hyperlink3 = Hyperlink::new("https://example3.com", &mut rel_manager);
         
-        assert_eq!(hyperlink1.id, "rId1");
-        assert_eq!(hyperlink2.id, "rId2");
-        assert_eq!(hyperlink3.id, "rId3");
+        assert_eq!(hyperlink1.id.as_deref(), Some("rId1"));
+        assert_eq!(hyperlink2.id.as_deref(), Some("rId2"));
+        assert_eq!(hyperlink3.id.as_deref(), Some("rId3"));
         assert_eq!(rel_manager.get_links().len(), 3);
     }
 
@@ -163,8 +187,8 @@ mod tests {
         let hyperlink2 = Hyperlink::new("https://example2.com", &mut rel_manager2);
         
         // Both should get rId1 since they use different managers
-        assert_eq!(hyperlink1.id, "rId1");
-        assert_eq!(hyperlink2.id, "rId1");
+        assert_eq!(hyperlink1.id.as_deref(), Some("rId1"));
+        assert_eq!(hyperlink2.id.as_deref(), Some("rId1"));
         
         // Each manager should only have one relationship
         assert_eq!(rel_manager1.get_links().len(), 1);
@@ -174,7 +198,8 @@ mod tests {
     #[test]
     fn test_default_hyperlink() {
         let hyperlink = Hyperlink::default();
-        assert_eq!(hyperlink.id, "");
+        assert_eq!(hyperlink.id, None);
+        assert_eq!(hyperlink.anchor, None);
         assert_eq!(hyperlink.runs.len(), 0);
     }
 }
@@ -0,0 +1,64 @@
+/// Picture embedded in the document. Saved as its own `word/media/imageN.<ext>` part and shown wherever a
+/// [Drawing] references it. Use [Document::add_image](crate::elements::Document::add_image) to embed one.
+///
+/// ### Fields
+/// > - **id:** `String` - Relationship id of the image part, registered in the relationship manager of the document.
+/// > - **content_type:** `String` - MIME type of the image (e.g. `image/png`). See [Image::extension].
+/// > - **data:** `Vec<u8>` - The encoded bytes of the image file.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Image {
+    pub id: String,
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
+
+impl Image {
+    /// File extension of the image part for its content type. Unknown image types are saved as `.bin`.
+    pub fn extension(&self) -> &'static str {
+        match self.content_type.as_str() {
+            "image/png" => "png",
+            "image/jpeg" => "jpeg",
+            "image/gif" => "gif",
+            "image/bmp" => "bmp",
+            "image/tiff" => "tiff",
+            "image/svg+xml" => "svg",
+            _ => "bin",
+        }
+    }
+
+    /// Content type of an image part from its file extension, the opposite of [Image::extension].
+    pub fn content_type_of(extension: &str) -> &'static str {
+        match extension.to_ascii_lowercase().as_str() {
+            "png" => "image/png",
+            "jpeg" | "jpg" => "image/jpeg",
+            "gif" => "image/gif",
+            "bmp" => "image/bmp",
+            "tiff" | "tif" => "image/tiff",
+            "svg" => "image/svg+xml",
+            _ => "application/octet-stream",
+        }
+    }
+}
+
+/// Inline picture shown in the text of a paragraph [`w:drawing/wp:inline`]().
+///
+/// ### Fields
+/// > - **image_id:** `String` - Relationship id of the [Image] shown. [`a:blip r:embed="<ID>"`]()
+/// > - **width:** `u64` - Width of the picture in EMUs (English Metric Units, 914400 per inch). [`wp:extent cx="<NUM>"`]()
+/// > - **height:** `u64` - Height of the picture in EMUs. [`wp:extent cy="<NUM>"`]()
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Drawing {
+    pub image_id: String,
+    pub width: u64,
+    pub height: u64,
+}
+
+impl Drawing {
+    pub fn new(image_id: &str, width: u64, height: u64) -> Self {
+        Self {
+            image_id: image_id.to_string(),
+            width,
+            height,
+        }
+    }
+}
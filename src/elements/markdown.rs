@@ -0,0 +1,229 @@
+// --- Markdown export ---
+
+use crate::elements::html::is_safe_url;
+use crate::elements::{Document, Paragraph, ParagraphChild, Run};
+
+impl Document {
+    /// Export the body as Markdown, keeping bold (`**`), italic (`*`), hyperlinks and images. Paragraphs are
+    /// separated by a blank line and empty ones are skipped.
+    ///
+    /// The export is best-effort: any other formatting is dropped and Markdown syntax in the text is escaped.
+    pub fn to_markdown(&self) -> String {
+        self.paragraphs
+            .iter()
+            .map(|p| self.paragraph_markdown(p))
+            .filter(|p| !p.is_empty())
+            .collect::<Vec<String>>()
+            .join("\n\n")
+    }
+
+    fn paragraph_markdown(&self, paragraph: &Paragraph) -> String {
+        let mut markdown = String::new();
+        // Consecutive runs are exported together so that their emphasis can be merged
+        let mut runs = Vec::new();
+        for child in &paragraph.children {
+            if let ParagraphChild::Run(run) = child {
+                runs.push(run);
+                continue;
+            }
+            markdown.push_str(&runs_markdown(runs.drain(..)));
+            match child {
+                ParagraphChild::Run(_) => (),
+                ParagraphChild::Hyperlink(hyperlink) => {
+                    let text = runs_markdown(&hyperlink.runs);
+                    // Targets with another scheme (e.g. `javascript:`) are not linked, only their text is kept
+                    match hyperlink
+                        .href(&self.relationship_manager)
+                        .filter(|href| is_safe_url(href))
+                    {
+                        Some(href) => {
+                            markdown.push_str(&format!("[{text}]({})", destination(&href)))
+                        }
+                        None => markdown.push_str(&text),
+                    }
+                }
+                ParagraphChild::SimpleField(field) => {
+                    markdown.push_str(&runs_markdown(&field.runs))
+                }
+                ParagraphChild::Drawing(drawing) => {
+                    // Image parts are relative paths, external images need a safe scheme
+                    if let Some(r) = self.relationship_manager.get(&drawing.image_id)
+                        && (is_safe_url(&r.target) || !r.target.contains(':'))
+                    {
+                        markdown.push_str(&format!("![]({})", destination(&r.target)));
+                    }
+                }
+                ParagraphChild::CommentRangeStart(_) | ParagraphChild::CommentRangeEnd(_) => (),
+            }
+        }
+        markdown.push_str(&runs_markdown(runs));
+        markdown
+    }
+}
+
+/// Markdown of consecutive runs, merging the ones with the same emphasis so that `**a****b**` becomes `**ab**`.
+fn runs_markdown<'a>(runs: impl IntoIterator<Item = &'a Run>) -> String {
+    let mut markdown = String::new();
+    // Text waiting to be emphasized, with its delimiter
+    let mut pending = (String::new(), "");
+    for run in runs {
+        if run.break_type.is_some() {
+            markdown.push_str(&emphasize(&pending.0, pending.1));
+            pending.0.clear();
+            markdown.push_str("  \n");
+        }
+        let delimiter = match (run.properties.bold, run.properties.italic) {
+            (true, true) => "***",
+            (true, false) => "**",
+            (false, true) => "*",
+            (false, false) => "",
+        };
+        if delimiter != pending.1 {
+            markdown.push_str(&emphasize(&pending.0, pending.1));
+            pending = (String::new(), delimiter);
+        }
+        pending.0.push_str(&escape(&run.text));
+    }
+    markdown.push_str(&emphasize(&pending.0, pending.1));
+    markdown
+}
+
+/// Wrap `text` in `delimiter`, leaving the surrounding whitespace out as Markdown doesn't allow it inside.
+fn emphasize(text: &str, delimiter: &str) -> String {
+    let trimmed = text.trim();
+    if delimiter.is_empty() || trimmed.is_empty() {
+        return text.to_string();
+    }
+    let start = text.len() - text.trim_start().len();
+    format!(
+        "{}{delimiter}{trimmed}{delimiter}{}",
+        &text[..start],
+        &text[start + trimmed.len()..]
+    )
+}
+
+/// Link destination of `url`. It's written between `<` and `>` if it has spaces, parentheses or other characters that
+/// would end it.
+fn destination(url: &str) -> String {
+    if !url
+        .chars()
+        .any(|c| c.is_whitespace() || c.is_control() || matches!(c, '(' | ')' | '<' | '>' | '\\'))
+    {
+        return url.to_string();
+    }
+    let mut destination = String::from("<");
+    for c in url.chars() {
+        match c {
+            '\\' | '<' | '>' => {
+                destination.push('\\');
+                destination.push(c);
+            }
+            '\n' => destination.push_str("%0A"),
+            '\r' => destination.push_str("%0D"),
+            c => destination.push(c),
+        }
+    }
+    destination.push('>');
+    destination
+}
+
+/// Escape the Markdown syntax of `text`, raw HTML (`<`) and entity references (`&`) included.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '[' | ']' | '`' | '<' | '&') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::Hyperlink;
+    use crate::rels::{Relationship, RelationshipKind, TargetMode};
+    use crate::xml::parse;
+
+    const SAMPLE: &str = r#"
+        <w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+            <w:body>
+                <w:p>
+                    <w:r><w:t>This is plain text.</w:t></w:r>
+                </w:p>
+                <w:p>
+                    <w:r><w:rPr><w:b/></w:rPr><w:t xml:space="preserve">This is bold. </w:t></w:r>
+                    <w:r><w:rPr><w:i/></w:rPr><w:t>This is italic.</w:t></w:r>
+                </w:p>
+                <w:p>
+                    <w:hyperlink r:id="rId1">
+                        <w:r><w:rPr><w:i/></w:rPr><w:t>rudocx</w:t></w:r>
+                    </w:hyperlink>
+                    <w:r><w:t> That was hyperlink_1.</w:t></w:r>
+                </w:p>
+                <w:p/>
+            </w:body>
+        </w:document>
+    "#;
+
+    #[test]
+    fn test_to_markdown() {
+        let mut document = parse(SAMPLE).unwrap();
+        document.relationship_manager.add_relationship_typed(
+            String::from("rId1"),
+            Relationship::new(
+                "https://github.com/cmgsk/rudocx",
+                RelationshipKind::Hyperlink,
+                TargetMode::External,
+            ),
+        );
+
+        assert_eq!(
+            document.to_markdown(),
+            "This is plain text.\n\n\
+             **This is bold.** *This is italic.*\n\n\
+             [*rudocx*](https://github.com/cmgsk/rudocx) That was hyperlink\\_1."
+        );
+    }
+
+    #[test]
+    fn test_merges_runs_with_same_emphasis() {
+        let mut document = Document::from_text("");
+        let paragraph = &mut document.paragraphs[0];
+        paragraph.children = ["a", "b"]
+            .into_iter()
+            .map(|text| {
+                let mut run = Run::from(text.to_string());
+                run.properties.bold = true;
+                ParagraphChild::Run(run)
+            })
+            .collect();
+
+        assert_eq!(document.to_markdown(), "**ab**");
+    }
+
+    #[test]
+    fn test_escapes_targets_and_html() {
+        let mut document = Document::from_text("1 < 2 & <b>");
+        let links = [
+            "https://example.com/a b_(c)",
+            "javascript:alert(1)",
+            "mailto:me@example.com",
+        ];
+        for target in links {
+            let link = Hyperlink::new_with_text(target, "link", &mut document.relationship_manager);
+            document.paragraphs[0]
+                .children
+                .push(ParagraphChild::Hyperlink(link));
+        }
+
+        assert_eq!(
+            document.to_markdown(),
+            "1 \\< 2 \\& \\<b>\
+             [link](<https://example.com/a b_(c)>)\
+             link\
+             [link](mailto:me@example.com)"
+        );
+    }
+}
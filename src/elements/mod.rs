@@ -1,11 +1,34 @@
+pub use builder::*;
+pub use comment::*;
+pub use diff::*;
 pub use document::*;
+pub use field::*;
+pub use footnote::*;
+pub use html::*;
 pub use hyperlink::*;
+pub use image::*;
 pub use paragraph::*;
+pub use paragraph_properties::*;
 pub use run::*;
 pub use run_properties::*;
+pub use section::*;
+pub use style::*;
+pub use table::*;
 
+mod builder;
+mod comment;
+mod diff;
 mod document;
+mod field;
+mod footnote;
+mod html;
 mod hyperlink;
+mod image;
+mod markdown;
 mod paragraph;
+mod paragraph_properties;
 mod run;
 mod run_properties;
+mod section;
+mod style;
+mod table;
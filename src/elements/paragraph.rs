@@ -1,12 +1,277 @@
-use crate::elements::{Hyperlink, Run};
+use crate::elements::{Drawing, Hyperlink, ParagraphProperties, Run, SimpleField};
+use crate::rels::RelationshipKind;
 
 #[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+#[non_exhaustive]
 pub enum ParagraphChild {
     Run(Run),
     Hyperlink(Hyperlink),
+    SimpleField(SimpleField),
+    /// Start of the content commented by the [Comment](crate::elements::Comment) with the given id [`w:commentRangeStart`]()
+    CommentRangeStart(u32),
+    /// End of the content commented by the [Comment](crate::elements::Comment) with the given id [`w:commentRangeEnd`]()
+    CommentRangeEnd(u32),
+    /// Inline picture, written inside a run of its own [`w:r/w:drawing`]()
+    Drawing(Drawing),
 }
 
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct Paragraph {
+    pub properties: ParagraphProperties,
     pub children: Vec<ParagraphChild>,
 }
+
+impl Paragraph {
+    /// Iterate over every run of the paragraph, including the ones inside hyperlinks and fields.
+    pub fn runs(&self) -> impl Iterator<Item = &Run> {
+        self.children.iter().flat_map(|child| match child {
+            ParagraphChild::Run(run) => std::slice::from_ref(run).iter(),
+            ParagraphChild::Hyperlink(hyperlink) => hyperlink.runs.iter(),
+            ParagraphChild::SimpleField(field) => field.runs.iter(),
+            ParagraphChild::CommentRangeStart(_)
+            | ParagraphChild::CommentRangeEnd(_)
+            | ParagraphChild::Drawing(_) => [].iter(),
+        })
+    }
+
+    /// Mutable version of [runs](crate::elements::Paragraph::runs).
+    pub fn runs_mut(&mut self) -> impl Iterator<Item = &mut Run> {
+        self.children.iter_mut().flat_map(|child| match child {
+            ParagraphChild::Run(run) => std::slice::from_mut(run).iter_mut(),
+            ParagraphChild::Hyperlink(hyperlink) => hyperlink.runs.iter_mut(),
+            ParagraphChild::SimpleField(field) => field.runs.iter_mut(),
+            ParagraphChild::CommentRangeStart(_)
+            | ParagraphChild::CommentRangeEnd(_)
+            | ParagraphChild::Drawing(_) => [].iter_mut(),
+        })
+    }
+
+    /// Relationship ids used by the paragraph, along with the kind of their relationship: the ones of its
+    /// hyperlinks, drawings and legacy pictures.
+    pub(crate) fn relationship_ids_mut(&mut self) -> Vec<(&mut String, RelationshipKind)> {
+        fn pictures(runs: &mut [Run]) -> impl Iterator<Item = (&mut String, RelationshipKind)> {
+            runs.iter_mut()
+                .filter_map(|r| r.picture_id.as_mut())
+                .map(|id| (id, RelationshipKind::Image))
+        }
+
+        let mut ids = Vec::new();
+        for child in &mut self.children {
+            match child {
+                ParagraphChild::Run(run) => ids.extend(pictures(std::slice::from_mut(run))),
+                ParagraphChild::Hyperlink(hyperlink) => {
+                    if let Some(id) = &mut hyperlink.id {
+                        ids.push((id, RelationshipKind::Hyperlink));
+                    }
+                    ids.extend(pictures(&mut hyperlink.runs));
+                }
+                ParagraphChild::SimpleField(field) => ids.extend(pictures(&mut field.runs)),
+                ParagraphChild::Drawing(drawing) => {
+                    ids.push((&mut drawing.image_id, RelationshipKind::Image))
+                }
+                ParagraphChild::CommentRangeStart(_) | ParagraphChild::CommentRangeEnd(_) => (),
+            }
+        }
+        ids
+    }
+
+    /// Immutable version of [relationship_ids_mut](Paragraph::relationship_ids_mut), without the kinds.
+    pub(crate) fn relationship_ids(&self) -> impl Iterator<Item = &str> {
+        let children = self.children.iter().filter_map(|child| match child {
+            ParagraphChild::Hyperlink(hyperlink) => hyperlink.id.as_deref(),
+            ParagraphChild::Drawing(drawing) => Some(drawing.image_id.as_str()),
+            _ => None,
+        });
+        children.chain(self.runs().filter_map(|r| r.picture_id.as_deref()))
+    }
+
+    /// An empty paragraph starting on a new page [`w:pageBreakBefore`]().
+    ///
+    /// The break belongs to the paragraph, so it moves along with it when content is added before. To break the page in
+    /// the middle of a paragraph, use [Run::page_break] instead.
+    pub fn page_break() -> Self {
+        Self {
+            properties: ParagraphProperties {
+                page_break_before: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Text of every run of the paragraph, including the ones inside hyperlinks and fields.
+    pub fn text(&self) -> String {
+        self.runs().map(|r| r.text.as_str()).collect()
+    }
+
+    /// Whether the paragraph has no text. Paragraphs with only drawings or comment marks are empty as well.
+    pub fn is_empty(&self) -> bool {
+        self.runs().all(|r| r.text.is_empty())
+    }
+
+    /// Number of runs of the paragraph, including the ones inside hyperlinks and fields.
+    pub fn run_count(&self) -> usize {
+        self.runs().count()
+    }
+
+    /// Number of `char`s of the text of every run of the paragraph. See [Run::char_len].
+    pub fn char_len(&self) -> usize {
+        self.runs().map(Run::char_len).sum()
+    }
+
+    /// Merge every run into the previous one when both have the same `RunProperties`, which is common in documents
+    /// edited many times. Runs inside hyperlinks and fields are merged with each other, never with the ones outside.
+    pub fn coalesce_runs(&mut self) {
+        let children = std::mem::take(&mut self.children);
+        for child in children {
+            match (self.children.last_mut(), child) {
+                (Some(ParagraphChild::Run(previous)), ParagraphChild::Run(run))
+                    if can_coalesce(previous, &run) =>
+                {
+                    append_run(previous, run)
+                }
+                (_, mut child) => {
+                    match &mut child {
+                        ParagraphChild::Hyperlink(hyperlink) => coalesce(&mut hyperlink.runs),
+                        ParagraphChild::SimpleField(field) => coalesce(&mut field.runs),
+                        ParagraphChild::Run(_)
+                        | ParagraphChild::CommentRangeStart(_)
+                        | ParagraphChild::CommentRangeEnd(_)
+                        | ParagraphChild::Drawing(_) => (),
+                    }
+                    self.children.push(child);
+                }
+            }
+        }
+    }
+}
+
+fn coalesce(runs: &mut Vec<Run>) {
+    let mut coalesced: Vec<Run> = Vec::with_capacity(runs.len());
+    for run in runs.drain(..) {
+        match coalesced.last_mut() {
+            Some(previous) if can_coalesce(previous, &run) => append_run(previous, run),
+            _ => coalesced.push(run),
+        }
+    }
+    *runs = coalesced;
+}
+
+// Runs holding a picture or a comment or footnote reference are kept apart, the text can't be merged around them
+fn can_coalesce(previous: &Run, run: &Run) -> bool {
+    previous.properties == run.properties
+        && [previous, run].iter().all(|r| {
+            r.picture_id.is_none()
+                && r.comment_reference.is_none()
+                && r.footnote_reference.is_none()
+                && r.break_type.is_none()
+        })
+}
+
+fn append_run(previous: &mut Run, run: Run) {
+    previous.text.push_str(&run.text);
+    previous.space_preserve |= run.space_preserve;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::xml::{generate, parse};
+
+    const SAMPLE: &str = r#"
+        <w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+            <w:body>
+                <w:p>
+                    <w:r><w:t>This is plain text.</w:t></w:r>
+                </w:p>
+                <w:p>
+                    <w:r><w:rPr><w:b/></w:rPr><w:t>This is bold.</w:t></w:r>
+                    <w:r><w:t xml:space="preserve"> </w:t></w:r>
+                    <w:r><w:rPr><w:i/></w:rPr><w:t>This is italic.</w:t></w:r>
+                </w:p>
+                <w:p>
+                    <w:hyperlink r:id="rId1">
+                        <w:r><w:rPr><w:i/></w:rPr><w:t>www.github.com/cmgsk/rudocx</w:t></w:r>
+                    </w:hyperlink>
+                    <w:r><w:t> That was hyperlink.</w:t></w:r>
+                </w:p>
+                <w:p/>
+            </w:body>
+        </w:document>
+    "#;
+
+    #[test]
+    fn test_text() {
+        let doc = parse(SAMPLE).unwrap();
+        let text = doc
+            .paragraphs
+            .iter()
+            .map(Paragraph::text)
+            .collect::<Vec<String>>();
+        assert_eq!(
+            text,
+            [
+                "This is plain text.",
+                "This is bold. This is italic.",
+                "www.github.com/cmgsk/rudocx That was hyperlink.",
+                "",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let doc = parse(SAMPLE).unwrap();
+        let empty = doc
+            .paragraphs
+            .iter()
+            .map(Paragraph::is_empty)
+            .collect::<Vec<bool>>();
+        assert_eq!(empty, [false, false, false, true]);
+
+        let paragraph = Paragraph {
+            children: vec![
+                ParagraphChild::Run(Run::default()),
+                ParagraphChild::CommentRangeStart(0),
+            ],
+            ..Default::default()
+        };
+        assert!(paragraph.is_empty());
+    }
+
+    #[test]
+    fn test_run_count_and_len() {
+        let doc = parse(SAMPLE).unwrap();
+        let counts = doc
+            .paragraphs
+            .iter()
+            .map(Paragraph::run_count)
+            .collect::<Vec<usize>>();
+        assert_eq!(counts, [1, 3, 2, 0]);
+
+        let lens = doc.paragraphs[1]
+            .runs()
+            .map(|r| (r.len(), r.is_empty()))
+            .collect::<Vec<(usize, bool)>>();
+        assert_eq!(lens, [(13, false), (1, false), (15, false)]);
+        assert!(Run::page_break().is_empty());
+    }
+
+    #[test]
+    fn test_whitespace_run_is_preserved() {
+        let doc = parse(SAMPLE).unwrap();
+        let run = doc.paragraphs[1].runs().nth(1).unwrap();
+        assert_eq!(run.text, " ");
+        assert!(run.space_preserve);
+        assert!(
+            doc.paragraphs[1]
+                .runs()
+                .next()
+                .is_some_and(|r| !r.space_preserve)
+        );
+        let xml = generate(&doc).unwrap();
+        assert!(xml.contains(r#"<w:t xml:space="preserve"> </w:t>"#));
+        assert_eq!(parse(&xml).unwrap().paragraphs[1], doc.paragraphs[1]);
+    }
+}
@@ -0,0 +1,207 @@
+// --- Direction ---
+
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Represents the direction of the text flow of a paragraph [`w:textDirection w:val="<VALUE>"`]().
+///
+/// The name states the direction of the characters first and then the one of the lines (e.g. `TbRl` is top to bottom,
+/// right to left). The `V` variants also rotate East Asian characters.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ParagraphTextDirValues {
+    LrTb,
+    TbRl,
+    BtLr,
+    LrTbV,
+    TbRlV,
+    TbLrV,
+}
+
+///Note that it will not return the correct value if you dont follow OOXML standard capitalization
+impl<T: Into<String>> From<T> for ParagraphTextDirValues {
+    fn from(v: T) -> Self {
+        match v.into().as_ref() {
+            "lrTb" => ParagraphTextDirValues::LrTb,
+            "tbRl" => ParagraphTextDirValues::TbRl,
+            "btLr" => ParagraphTextDirValues::BtLr,
+            "lrTbV" => ParagraphTextDirValues::LrTbV,
+            "tbRlV" => ParagraphTextDirValues::TbRlV,
+            "tbLrV" => ParagraphTextDirValues::TbLrV,
+            _ => ParagraphTextDirValues::LrTb,
+        }
+    }
+}
+
+impl fmt::Display for ParagraphTextDirValues {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ParagraphTextDirValues::LrTb => "lrTb",
+                ParagraphTextDirValues::TbRl => "tbRl",
+                ParagraphTextDirValues::BtLr => "btLr",
+                ParagraphTextDirValues::LrTbV => "lrTbV",
+                ParagraphTextDirValues::TbRlV => "tbRlV",
+                ParagraphTextDirValues::TbLrV => "tbLrV",
+            }
+        )
+    }
+}
+
+/// Represents the vertical alignment of the characters of a line that have different sizes
+/// [`w:textAlignment w:val="<VALUE>"`]().
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ParagraphTextAlignValues {
+    Top,
+    Center,
+    Baseline,
+    Bottom,
+    Auto,
+}
+
+///Note that it will not return the correct value if you dont follow OOXML standard capitalization
+impl<T: Into<String>> From<T> for ParagraphTextAlignValues {
+    fn from(v: T) -> Self {
+        match v.into().as_ref() {
+            "top" => ParagraphTextAlignValues::Top,
+            "center" => ParagraphTextAlignValues::Center,
+            "baseline" => ParagraphTextAlignValues::Baseline,
+            "bottom" => ParagraphTextAlignValues::Bottom,
+            "auto" => ParagraphTextAlignValues::Auto,
+            _ => ParagraphTextAlignValues::Auto,
+        }
+    }
+}
+
+impl fmt::Display for ParagraphTextAlignValues {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ParagraphTextAlignValues::Top => "top",
+                ParagraphTextAlignValues::Center => "center",
+                ParagraphTextAlignValues::Baseline => "baseline",
+                ParagraphTextAlignValues::Bottom => "bottom",
+                ParagraphTextAlignValues::Auto => "auto",
+            }
+        )
+    }
+}
+
+/// Represents which lines of a paragraph inside a text box are used to tighten the wrapping around it
+/// [`w:textboxTightWrap w:val="<VALUE>"`]().
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ParagraphTBoxTightWrapValues {
+    None,
+    AllLines,
+    FirstAndLastLine,
+    FirstLineOnly,
+    LastLineOnly,
+}
+
+///Note that it will not return the correct value if you dont follow OOXML standard capitalization
+impl<T: Into<String>> From<T> for ParagraphTBoxTightWrapValues {
+    fn from(v: T) -> Self {
+        match v.into().as_ref() {
+            "none" => ParagraphTBoxTightWrapValues::None,
+            "allLines" => ParagraphTBoxTightWrapValues::AllLines,
+            "firstAndLastLine" => ParagraphTBoxTightWrapValues::FirstAndLastLine,
+            "firstLineOnly" => ParagraphTBoxTightWrapValues::FirstLineOnly,
+            "lastLineOnly" => ParagraphTBoxTightWrapValues::LastLineOnly,
+            _ => ParagraphTBoxTightWrapValues::None,
+        }
+    }
+}
+
+impl fmt::Display for ParagraphTBoxTightWrapValues {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ParagraphTBoxTightWrapValues::None => "none",
+                ParagraphTBoxTightWrapValues::AllLines => "allLines",
+                ParagraphTBoxTightWrapValues::FirstAndLastLine => "firstAndLastLine",
+                ParagraphTBoxTightWrapValues::FirstLineOnly => "firstLineOnly",
+                ParagraphTBoxTightWrapValues::LastLineOnly => "lastLineOnly",
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_direction_from() {
+        let values = [
+            ("lrTb", ParagraphTextDirValues::LrTb),
+            ("tbRl", ParagraphTextDirValues::TbRl),
+            ("btLr", ParagraphTextDirValues::BtLr),
+            ("lrTbV", ParagraphTextDirValues::LrTbV),
+            ("tbRlV", ParagraphTextDirValues::TbRlV),
+            ("tbLrV", ParagraphTextDirValues::TbLrV),
+        ];
+        for (s, value) in values {
+            assert_eq!(ParagraphTextDirValues::from(s), value);
+            assert_eq!(value.to_string(), s);
+        }
+        assert_eq!(
+            ParagraphTextDirValues::from("sideways"),
+            ParagraphTextDirValues::LrTb
+        );
+    }
+
+    #[test]
+    fn test_bottom_to_top_direction() {
+        assert_eq!(ParagraphTextDirValues::BtLr.to_string(), "btLr");
+        assert_eq!(
+            ParagraphTextDirValues::from(ParagraphTextDirValues::BtLr.to_string()),
+            ParagraphTextDirValues::BtLr
+        );
+    }
+
+    #[test]
+    fn test_text_alignment_from() {
+        let values = [
+            ("top", ParagraphTextAlignValues::Top),
+            ("center", ParagraphTextAlignValues::Center),
+            ("baseline", ParagraphTextAlignValues::Baseline),
+            ("bottom", ParagraphTextAlignValues::Bottom),
+            ("auto", ParagraphTextAlignValues::Auto),
+        ];
+        for (s, value) in values {
+            assert_eq!(ParagraphTextAlignValues::from(s), value);
+            assert_eq!(value.to_string(), s);
+        }
+        assert_eq!(
+            ParagraphTextAlignValues::from("Top"),
+            ParagraphTextAlignValues::Auto
+        );
+    }
+
+    #[test]
+    fn test_textbox_tight_wrap_from() {
+        let values = [
+            ("none", ParagraphTBoxTightWrapValues::None),
+            ("allLines", ParagraphTBoxTightWrapValues::AllLines),
+            (
+                "firstAndLastLine",
+                ParagraphTBoxTightWrapValues::FirstAndLastLine,
+            ),
+            ("firstLineOnly", ParagraphTBoxTightWrapValues::FirstLineOnly),
+            ("lastLineOnly", ParagraphTBoxTightWrapValues::LastLineOnly),
+        ];
+        for (s, value) in values {
+            assert_eq!(ParagraphTBoxTightWrapValues::from(s), value);
+            assert_eq!(value.to_string(), s);
+        }
+        assert_eq!(
+            ParagraphTBoxTightWrapValues::from("everyLine"),
+            ParagraphTBoxTightWrapValues::None
+        );
+    }
+}
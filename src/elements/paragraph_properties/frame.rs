@@ -0,0 +1,136 @@
+// --- Frame ---
+
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Represents the text frame a paragraph is placed in, such as a pull quote or a drop cap [`w:framePr`]().
+/// Lengths and positions are measured in twentieths of a point (twips).
+///
+/// ## Fields
+/// > - `width`: Width of the frame. `None` sizes it to the content.
+/// > - `height`: Height of the frame, interpreted according to `height_rule`.
+/// > - `height_rule`: How `height` is applied. See [FrameHeightRule].
+/// > - `wrap`: How the surrounding text wraps around the frame. See [FrameWrap].
+/// > - `h_anchor`: What `x` is measured from. See [FrameAnchor].
+/// > - `v_anchor`: What `y` is measured from. See [FrameAnchor].
+/// > - `x`: Horizontal position of the frame from `h_anchor`.
+/// > - `y`: Vertical position of the frame from `v_anchor`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct FrameProperties {
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub height_rule: Option<FrameHeightRule>,
+    pub wrap: Option<FrameWrap>,
+    pub h_anchor: Option<FrameAnchor>,
+    pub v_anchor: Option<FrameAnchor>,
+    pub x: Option<i32>,
+    pub y: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FrameWrap {
+    Auto,
+    NotBeside,
+    Around,
+    Tight,
+    Through,
+    None,
+}
+
+///Note that it will not return the correct value if you dont follow OOXML standard capitalization
+impl<T: Into<String>> From<T> for FrameWrap {
+    fn from(v: T) -> Self {
+        match v.into().as_ref() {
+            "auto" => FrameWrap::Auto,
+            "notBeside" => FrameWrap::NotBeside,
+            "around" => FrameWrap::Around,
+            "tight" => FrameWrap::Tight,
+            "through" => FrameWrap::Through,
+            "none" => FrameWrap::None,
+            _ => FrameWrap::Auto,
+        }
+    }
+}
+
+impl fmt::Display for FrameWrap {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                FrameWrap::Auto => "auto",
+                FrameWrap::NotBeside => "notBeside",
+                FrameWrap::Around => "around",
+                FrameWrap::Tight => "tight",
+                FrameWrap::Through => "through",
+                FrameWrap::None => "none",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FrameAnchor {
+    Text,
+    Margin,
+    Page,
+}
+
+///Note that it will not return the correct value if you dont follow OOXML standard capitalization
+impl<T: Into<String>> From<T> for FrameAnchor {
+    fn from(v: T) -> Self {
+        match v.into().as_ref() {
+            "text" => FrameAnchor::Text,
+            "margin" => FrameAnchor::Margin,
+            "page" => FrameAnchor::Page,
+            _ => FrameAnchor::Text,
+        }
+    }
+}
+
+impl fmt::Display for FrameAnchor {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                FrameAnchor::Text => "text",
+                FrameAnchor::Margin => "margin",
+                FrameAnchor::Page => "page",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FrameHeightRule {
+    Auto,
+    Exact,
+    AtLeast,
+}
+
+///Note that it will not return the correct value if you dont follow OOXML standard capitalization
+impl<T: Into<String>> From<T> for FrameHeightRule {
+    fn from(v: T) -> Self {
+        match v.into().as_ref() {
+            "auto" => FrameHeightRule::Auto,
+            "exact" => FrameHeightRule::Exact,
+            "atLeast" => FrameHeightRule::AtLeast,
+            _ => FrameHeightRule::Auto,
+        }
+    }
+}
+
+impl fmt::Display for FrameHeightRule {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                FrameHeightRule::Auto => "auto",
+                FrameHeightRule::Exact => "exact",
+                FrameHeightRule::AtLeast => "atLeast",
+            }
+        )
+    }
+}
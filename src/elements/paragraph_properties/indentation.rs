@@ -0,0 +1,87 @@
+// --- Indentation ---
+
+use crate::elements::paragraph_properties::Result;
+use crate::errors::RudocxParagraphStyleError;
+
+/// Represents the indentation of a paragraph [`w:ind`](). All values are measured in twentieths of a point (twips).
+///
+/// ## Fields
+/// > - `left`: Indentation from the left text margin. Negative values move the paragraph into the margin.
+/// > - `right`: Indentation from the right text margin. Negative values move the paragraph into the margin.
+/// > - `first_line`: Extra indentation of the first line.
+/// > - `hanging`: Indentation removed from the first line.
+///
+/// **Note:** `first_line` and `hanging` are mutually exclusive.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct ParagraphIndentation {
+    pub left: Option<i32>,
+    pub right: Option<i32>,
+    pub first_line: Option<u32>,
+    pub hanging: Option<u32>,
+}
+
+impl ParagraphIndentation {
+    /// Creates a new indentation. Returns `Err` if both `first_line` and `hanging` are set.
+    pub fn new(
+        left: Option<i32>,
+        right: Option<i32>,
+        first_line: Option<u32>,
+        hanging: Option<u32>,
+    ) -> Result<Self> {
+        check_first_line_hanging(first_line, hanging)?;
+        Ok(Self {
+            left,
+            right,
+            first_line,
+            hanging,
+        })
+    }
+
+    /// Set the first line indentation. Returns `Err` if `hanging` is already set.
+    pub fn set_first_line(&mut self, value: Option<u32>) -> Result<()> {
+        check_first_line_hanging(value, self.hanging)?;
+        self.first_line = value;
+        Ok(())
+    }
+
+    /// Set the hanging indentation. Returns `Err` if `first_line` is already set.
+    pub fn set_hanging(&mut self, value: Option<u32>) -> Result<()> {
+        check_first_line_hanging(self.first_line, value)?;
+        self.hanging = value;
+        Ok(())
+    }
+}
+
+fn check_first_line_hanging(first_line: Option<u32>, hanging: Option<u32>) -> Result<()> {
+    if first_line.is_some() && hanging.is_some() {
+        return Err(RudocxParagraphStyleError::MutuallyExclusive(
+            String::from("firstLine"),
+            String::from("hanging"),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_indentation_first_line_hanging_exclusive() {
+        assert!(matches!(
+            ParagraphIndentation::new(Some(720), None, Some(360), Some(360)),
+            Err(RudocxParagraphStyleError::MutuallyExclusive(_, _))
+        ));
+
+        let mut indentation = ParagraphIndentation::new(Some(720), None, None, Some(360)).unwrap();
+        assert!(matches!(
+            indentation.set_first_line(Some(360)),
+            Err(RudocxParagraphStyleError::MutuallyExclusive(_, _))
+        ));
+        assert_eq!(indentation.first_line, None);
+
+        indentation.set_hanging(None).unwrap();
+        indentation.set_first_line(Some(360)).unwrap();
+        assert_eq!(indentation.first_line, Some(360));
+    }
+}
@@ -0,0 +1,91 @@
+// --- Justification ---
+
+use crate::elements::paragraph_properties::Result;
+use crate::errors::RudocxParagraphStyleError;
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Represents the alignment of the lines of a paragraph [`w:jc w:val="<VALUE>"`]().
+///
+/// `Start` and `End` follow the direction of the text, `Left` and `Right` are their legacy names. `Distribute`
+/// stretches every line to the full width, the last one included, while `Both` leaves the last line as is.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ParagraphJustificationValues {
+    Start,
+    Center,
+    End,
+    Both,
+    Distribute,
+    Left,
+    Right,
+}
+
+///Note that it will not return the correct value if you dont follow OOXML standard capitalization
+impl<T: Into<String>> From<T> for ParagraphJustificationValues {
+    fn from(v: T) -> Self {
+        match v.into().as_ref() {
+            "start" => ParagraphJustificationValues::Start,
+            "center" => ParagraphJustificationValues::Center,
+            "end" => ParagraphJustificationValues::End,
+            "both" => ParagraphJustificationValues::Both,
+            "distribute" => ParagraphJustificationValues::Distribute,
+            "left" => ParagraphJustificationValues::Left,
+            "right" => ParagraphJustificationValues::Right,
+            _ => ParagraphJustificationValues::Start,
+        }
+    }
+}
+
+impl fmt::Display for ParagraphJustificationValues {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ParagraphJustificationValues::Start => "start",
+                ParagraphJustificationValues::Center => "center",
+                ParagraphJustificationValues::End => "end",
+                ParagraphJustificationValues::Both => "both",
+                ParagraphJustificationValues::Distribute => "distribute",
+                ParagraphJustificationValues::Left => "left",
+                ParagraphJustificationValues::Right => "right",
+            }
+        )
+    }
+}
+
+/// Parse a justification, returning `Err` for the values the `From` impl would silently turn into `Start`.
+pub(crate) fn check_justification(value: &str) -> Result<ParagraphJustificationValues> {
+    let justification = ParagraphJustificationValues::from(value);
+    if justification.to_string() != value {
+        return Err(RudocxParagraphStyleError::InvalidJustification(
+            value.to_string(),
+        ));
+    }
+    Ok(justification)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_justification() {
+        assert_eq!(
+            check_justification("both").unwrap(),
+            ParagraphJustificationValues::Both
+        );
+        assert_eq!(
+            check_justification("distribute").unwrap(),
+            ParagraphJustificationValues::Distribute
+        );
+        assert!(matches!(
+            check_justification("justify"),
+            Err(RudocxParagraphStyleError::InvalidJustification(v)) if v == "justify"
+        ));
+        assert_eq!(
+            ParagraphJustificationValues::from("justify"),
+            ParagraphJustificationValues::Start
+        );
+    }
+}
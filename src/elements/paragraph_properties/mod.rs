@@ -0,0 +1,233 @@
+use crate::elements::RunProperties;
+use crate::errors::RudocxParagraphStyleError;
+
+pub use direction::*;
+pub use frame::*;
+pub use indentation::*;
+pub use justification::*;
+pub use shading::*;
+pub use spacing::*;
+pub use tabs::*;
+
+mod direction;
+mod frame;
+mod indentation;
+mod justification;
+mod shading;
+mod spacing;
+mod tabs;
+
+type Result<T> = std::result::Result<T, RudocxParagraphStyleError>;
+
+/// Representation of the format applied to a `Paragraph` in a docx document [`w:pPr`]().
+///
+/// ### Fields
+/// > - **style:** `Option<String>` - Id of the paragraph [Style](crate::elements::Style) applied to the paragraph. `None` is unused. [`w:pStyle w:val="<ID>"`]()
+/// > - **keep_next:** `Option<bool>` - Keeps the paragraph on the same page as the next one. `Some(false)` is written explicitly,
+/// >   to turn off the one of the style. `None` is unused. [`w:keepNext w:val="<BOOL>"`]()
+/// > - **keep_lines:** `Option<bool>` - Keeps all the lines of the paragraph on the same page. `Some(false)` is written explicitly,
+/// >   to turn off the one of the style. `None` is unused. [`w:keepLines w:val="<BOOL>"`]()
+/// > - **page_break_before:** `bool` - Starts the paragraph on a new page. [`w:pageBreakBefore`]()
+/// > - **frame:** `Option<FrameProperties>` - Places the paragraph in a text frame. `None` is unused. [`w:framePr w:w="<NUM>" w:h="<NUM>" w:hRule="<RULE>" w:wrap="<WRAP>" w:hAnchor="<ANCHOR>" w:vAnchor="<ANCHOR>" w:x="<NUM>" w:y="<NUM>"`]()
+/// > - **shading:** `Option<ParagraphShading>` - Indicates the background shading of the paragraph. `None` is unused. [`w:shd w:val="<PATTERN>" w:color="<HEX_VAL>" w:fill="<HEX_VAL>"`]()
+/// > - **tabs:** `Vec<ParagraphTab>` - Custom tab stops of the paragraph. Empty is unused. [`w:tabs/w:tab w:val="<TYPE>" w:pos="<NUM>" w:leader="<LEADER>"`]()
+/// > - **spacing:** `Option<ParagraphSpacing>` - Indicates the spacing between the lines of the paragraph and around it. `None` is unused. [`w:spacing w:before="<NUM>" w:after="<NUM>" w:line="<NUM>" w:lineRule="<RULE>"`]()
+/// > - **indentation:** `Option<ParagraphIndentation>` - Indicates the indentation of the paragraph. `None` is unused. [`w:ind w:left="<NUM>" w:right="<NUM>" w:firstLine="<NUM>" w:hanging="<NUM>"`]()
+/// > - **justification:** `Option<ParagraphJustificationValues>` - Indicates the alignment of the lines of the paragraph. `None` is unused. [`w:jc w:val="<VALUE>"`]()
+/// > - **text_direction:** `Option<ParagraphTextDirValues>` - Indicates the direction of the text flow. `None` is unused. [`w:textDirection w:val="<VALUE>"`]()
+/// > - **text_alignment:** `Option<ParagraphTextAlignValues>` - Indicates the vertical alignment of the characters on each line. `None` is unused. [`w:textAlignment w:val="<VALUE>"`]()
+/// > - **textbox_tight_wrap:** `Option<ParagraphTBoxTightWrapValues>` - Indicates which lines tighten the wrapping of the text box containing the paragraph. `None` is unused. [`w:textboxTightWrap w:val="<VALUE>"`]()
+/// > - **outline_level:** `Option<u8>` - Indicates the outline level of the paragraph, from `0` to `9` (`9` being body text). `None` is unused. [`w:outlineLvl w:val="<NUM>"`]()
+/// > - **div_id:** `Option<u32>` - Id of the HTML `div` the paragraph belonged to, kept by documents converted from web pages. `None` is unused. [`w:divId w:val="<NUM>"`]()
+/// > - **kinsoku:** `bool` - Applies the East Asian rules on which characters can't start or end a line. [`w:kinsoku`]()
+/// > - **overflow_punct:** `bool` - Allows punctuation to extend past the end of the line. [`w:overflowPunct`]()
+/// > - **default_run_properties:** `Option<RunProperties>` - Formatting of the paragraph mark, which consumers also apply to the text
+/// >   typed at the end of the paragraph. It doesn't change the runs already in it. `None` is unused. [`w:rPr`]()
+/// > - **conditional_formatting:** `Option<String>` - Raw bit string of the table conditional formatting (first row, last column...)
+/// >   applied to the paragraph. `None` is unused. [`w:cnfStyle w:val="<BITS>"`]()
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ParagraphProperties {
+    pub style: Option<String>,
+    pub keep_next: Option<bool>,
+    pub keep_lines: Option<bool>,
+    pub page_break_before: bool,
+    pub frame: Option<FrameProperties>,
+    pub shading: Option<ParagraphShading>,
+    pub tabs: Vec<ParagraphTab>,
+    pub spacing: Option<ParagraphSpacing>,
+    pub indentation: Option<ParagraphIndentation>,
+    pub justification: Option<ParagraphJustificationValues>,
+    pub text_direction: Option<ParagraphTextDirValues>,
+    pub text_alignment: Option<ParagraphTextAlignValues>,
+    pub textbox_tight_wrap: Option<ParagraphTBoxTightWrapValues>,
+    pub outline_level: Option<u8>,
+    pub div_id: Option<u32>,
+    pub kinsoku: bool,
+    pub overflow_punct: bool,
+    pub conditional_formatting: Option<String>,
+    pub default_run_properties: Option<RunProperties>,
+}
+
+impl ParagraphProperties {
+    /// Set the outline level of the paragraph. Returns `Err` if it is greater than `9`.
+    pub fn set_outline_level(&mut self, level: Option<u8>) -> Result<()> {
+        if let Some(level) = level {
+            check_outline_level(level)?;
+        }
+        self.outline_level = level;
+        Ok(())
+    }
+
+    /// Set whether East Asian line breaking rules apply to the paragraph.
+    pub fn with_kinsoku(mut self, kinsoku: bool) -> Self {
+        self.kinsoku = kinsoku;
+        self
+    }
+
+    /// Set whether punctuation can extend past the end of the lines of the paragraph.
+    pub fn with_overflow_punct(mut self, overflow_punct: bool) -> Self {
+        self.overflow_punct = overflow_punct;
+        self
+    }
+
+    /// Get a copy of the properties with the unset ones taken from `defaults`. See
+    /// [apply_onto](crate::elements::ParagraphProperties::apply_onto) for the in-place version.
+    pub fn merge(&self, defaults: &ParagraphProperties) -> ParagraphProperties {
+        let mut merged = self.clone();
+        defaults.apply_onto(&mut merged);
+        merged
+    }
+
+    /// Fill the unset properties of `target` with the ones of `self`, in place. Tab stops are only copied if `target`
+    /// has none.
+    pub fn apply_onto(&self, target: &mut ParagraphProperties) {
+        let ParagraphProperties {
+            style,
+            keep_next,
+            keep_lines,
+            page_break_before,
+            frame,
+            shading,
+            tabs,
+            spacing,
+            indentation,
+            justification,
+            text_direction,
+            text_alignment,
+            textbox_tight_wrap,
+            outline_level,
+            div_id,
+            kinsoku,
+            overflow_punct,
+            conditional_formatting,
+            default_run_properties,
+        } = self;
+
+        target.page_break_before |= page_break_before;
+        target.kinsoku |= kinsoku;
+        target.overflow_punct |= overflow_punct;
+
+        if target.style.is_none() {
+            target.style.clone_from(style);
+        }
+        if target.keep_next.is_none() {
+            target.keep_next = *keep_next;
+        }
+        if target.keep_lines.is_none() {
+            target.keep_lines = *keep_lines;
+        }
+        if target.frame.is_none() {
+            target.frame.clone_from(frame);
+        }
+        if target.shading.is_none() {
+            target.shading.clone_from(shading);
+        }
+        if target.tabs.is_empty() {
+            target.tabs.clone_from(tabs);
+        }
+        if target.spacing.is_none() {
+            target.spacing.clone_from(spacing);
+        }
+        if target.indentation.is_none() {
+            target.indentation.clone_from(indentation);
+        }
+        if target.justification.is_none() {
+            target.justification.clone_from(justification);
+        }
+        if target.text_direction.is_none() {
+            target.text_direction.clone_from(text_direction);
+        }
+        if target.text_alignment.is_none() {
+            target.text_alignment.clone_from(text_alignment);
+        }
+        if target.textbox_tight_wrap.is_none() {
+            target.textbox_tight_wrap.clone_from(textbox_tight_wrap);
+        }
+        if target.outline_level.is_none() {
+            target.outline_level = *outline_level;
+        }
+        if target.div_id.is_none() {
+            target.div_id = *div_id;
+        }
+        if target.conditional_formatting.is_none() {
+            target
+                .conditional_formatting
+                .clone_from(conditional_formatting);
+        }
+        match (&mut target.default_run_properties, default_run_properties) {
+            (Some(target), Some(defaults)) => defaults.apply_onto(target),
+            (target @ None, defaults) => target.clone_from(defaults),
+            _ => (),
+        }
+    }
+
+    /// Whether any property is set. Empty `default_run_properties` don't count, as there is nothing to write for them.
+    pub fn has_formatting(&self) -> bool {
+        if self
+            .default_run_properties
+            .as_ref()
+            .is_some_and(RunProperties::has_formatting)
+        {
+            return true;
+        }
+        let without_run_properties = ParagraphProperties {
+            default_run_properties: None,
+            ..self.clone()
+        };
+        without_run_properties != Self::default()
+    }
+}
+
+pub(crate) use justification::check_justification;
+
+pub(crate) fn check_outline_level(level: u8) -> Result<()> {
+    if level > 9 {
+        return Err(RudocxParagraphStyleError::InvalidOutlineLevel(level));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_onto_matches_merge() {
+        let defaults = ParagraphProperties {
+            tabs: vec![ParagraphTab::new(ParagraphTabType::Left, 720, None)],
+            outline_level: Some(1),
+            ..Default::default()
+        };
+        let properties = ParagraphProperties {
+            outline_level: Some(3),
+            ..Default::default()
+        };
+
+        let mut target = properties.clone();
+        defaults.apply_onto(&mut target);
+        assert_eq!(target, properties.merge(&defaults));
+        assert_eq!(target.outline_level, Some(3));
+        assert_eq!(target.tabs, defaults.tabs);
+        assert!(target.shading.is_none());
+    }
+}
@@ -0,0 +1,198 @@
+// --- Shading ---
+
+use crate::elements::HexColor;
+use crate::elements::paragraph_properties::Result;
+use crate::errors::RudocxParagraphStyleError;
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Represents the shading of a paragraph [`w:shd`]().
+///
+/// ## Fields
+/// > - `val`: The pattern drawn over the background. See [ParagraphShadingValues].
+/// > - `color`: The color of the pattern. Ignored by consumers when `val` is `Clear`.
+/// > - `fill`: The background color below the pattern.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParagraphShading {
+    pub val: ParagraphShadingValues,
+    pub color: Option<HexColor>,
+    pub fill: Option<HexColor>,
+}
+
+impl Default for ParagraphShading {
+    fn default() -> Self {
+        Self {
+            val: ParagraphShadingValues::Clear,
+            color: None,
+            fill: None,
+        }
+    }
+}
+
+impl ParagraphShading {
+    /// Creates a new shading. Returns `Err` if `val` draws a pattern (anything but `Nil` or `Clear`)
+    /// and neither `color` nor `fill` is set, since there would be nothing to draw it with.
+    pub fn new(
+        val: ParagraphShadingValues,
+        color: Option<HexColor>,
+        fill: Option<HexColor>,
+    ) -> Result<Self> {
+        let is_pattern = !matches!(
+            val,
+            ParagraphShadingValues::Nil | ParagraphShadingValues::Clear
+        );
+        if is_pattern && color.is_none() && fill.is_none() {
+            return Err(RudocxParagraphStyleError::InvalidShading(format!(
+                "pattern {val} has neither color nor fill"
+            )));
+        }
+        Ok(Self { val, color, fill })
+    }
+
+    /// Shading with a plain background color.
+    pub fn fill(fill: HexColor) -> Self {
+        Self {
+            val: ParagraphShadingValues::Clear,
+            color: None,
+            fill: Some(fill),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ParagraphShadingValues {
+    Nil,
+    Clear,
+    Solid,
+    HorzStripe,
+    VertStripe,
+    ReverseDiagStripe,
+    DiagStripe,
+    HorzCross,
+    DiagCross,
+    ThinHorzStripe,
+    ThinVertStripe,
+    ThinReverseDiagStripe,
+    ThinDiagStripe,
+    ThinHorzCross,
+    ThinDiagCross,
+    Pct5,
+    Pct10,
+    Pct12,
+    Pct15,
+    Pct20,
+    Pct25,
+    Pct30,
+    Pct35,
+    Pct37,
+    Pct40,
+    Pct45,
+    Pct50,
+    Pct55,
+    Pct60,
+    Pct62,
+    Pct65,
+    Pct70,
+    Pct75,
+    Pct80,
+    Pct85,
+    Pct87,
+    Pct90,
+    Pct95,
+}
+
+///Note that it will not return the correct value if you dont follow OOXML standard capitalization
+impl<T: Into<String>> From<T> for ParagraphShadingValues {
+    fn from(v: T) -> Self {
+        match v.into().as_ref() {
+            "nil" => ParagraphShadingValues::Nil,
+            "clear" => ParagraphShadingValues::Clear,
+            "solid" => ParagraphShadingValues::Solid,
+            "horzStripe" => ParagraphShadingValues::HorzStripe,
+            "vertStripe" => ParagraphShadingValues::VertStripe,
+            "reverseDiagStripe" => ParagraphShadingValues::ReverseDiagStripe,
+            "diagStripe" => ParagraphShadingValues::DiagStripe,
+            "horzCross" => ParagraphShadingValues::HorzCross,
+            "diagCross" => ParagraphShadingValues::DiagCross,
+            "thinHorzStripe" => ParagraphShadingValues::ThinHorzStripe,
+            "thinVertStripe" => ParagraphShadingValues::ThinVertStripe,
+            "thinReverseDiagStripe" => ParagraphShadingValues::ThinReverseDiagStripe,
+            "thinDiagStripe" => ParagraphShadingValues::ThinDiagStripe,
+            "thinHorzCross" => ParagraphShadingValues::ThinHorzCross,
+            "thinDiagCross" => ParagraphShadingValues::ThinDiagCross,
+            "pct5" => ParagraphShadingValues::Pct5,
+            "pct10" => ParagraphShadingValues::Pct10,
+            "pct12" => ParagraphShadingValues::Pct12,
+            "pct15" => ParagraphShadingValues::Pct15,
+            "pct20" => ParagraphShadingValues::Pct20,
+            "pct25" => ParagraphShadingValues::Pct25,
+            "pct30" => ParagraphShadingValues::Pct30,
+            "pct35" => ParagraphShadingValues::Pct35,
+            "pct37" => ParagraphShadingValues::Pct37,
+            "pct40" => ParagraphShadingValues::Pct40,
+            "pct45" => ParagraphShadingValues::Pct45,
+            "pct50" => ParagraphShadingValues::Pct50,
+            "pct55" => ParagraphShadingValues::Pct55,
+            "pct60" => ParagraphShadingValues::Pct60,
+            "pct62" => ParagraphShadingValues::Pct62,
+            "pct65" => ParagraphShadingValues::Pct65,
+            "pct70" => ParagraphShadingValues::Pct70,
+            "pct75" => ParagraphShadingValues::Pct75,
+            "pct80" => ParagraphShadingValues::Pct80,
+            "pct85" => ParagraphShadingValues::Pct85,
+            "pct87" => ParagraphShadingValues::Pct87,
+            "pct90" => ParagraphShadingValues::Pct90,
+            "pct95" => ParagraphShadingValues::Pct95,
+            _ => ParagraphShadingValues::Clear,
+        }
+    }
+}
+
+impl fmt::Display for ParagraphShadingValues {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ParagraphShadingValues::Nil => "nil",
+                ParagraphShadingValues::Clear => "clear",
+                ParagraphShadingValues::Solid => "solid",
+                ParagraphShadingValues::HorzStripe => "horzStripe",
+                ParagraphShadingValues::VertStripe => "vertStripe",
+                ParagraphShadingValues::ReverseDiagStripe => "reverseDiagStripe",
+                ParagraphShadingValues::DiagStripe => "diagStripe",
+                ParagraphShadingValues::HorzCross => "horzCross",
+                ParagraphShadingValues::DiagCross => "diagCross",
+                ParagraphShadingValues::ThinHorzStripe => "thinHorzStripe",
+                ParagraphShadingValues::ThinVertStripe => "thinVertStripe",
+                ParagraphShadingValues::ThinReverseDiagStripe => "thinReverseDiagStripe",
+                ParagraphShadingValues::ThinDiagStripe => "thinDiagStripe",
+                ParagraphShadingValues::ThinHorzCross => "thinHorzCross",
+                ParagraphShadingValues::ThinDiagCross => "thinDiagCross",
+                ParagraphShadingValues::Pct5 => "pct5",
+                ParagraphShadingValues::Pct10 => "pct10",
+                ParagraphShadingValues::Pct12 => "pct12",
+                ParagraphShadingValues::Pct15 => "pct15",
+                ParagraphShadingValues::Pct20 => "pct20",
+                ParagraphShadingValues::Pct25 => "pct25",
+                ParagraphShadingValues::Pct30 => "pct30",
+                ParagraphShadingValues::Pct35 => "pct35",
+                ParagraphShadingValues::Pct37 => "pct37",
+                ParagraphShadingValues::Pct40 => "pct40",
+                ParagraphShadingValues::Pct45 => "pct45",
+                ParagraphShadingValues::Pct50 => "pct50",
+                ParagraphShadingValues::Pct55 => "pct55",
+                ParagraphShadingValues::Pct60 => "pct60",
+                ParagraphShadingValues::Pct62 => "pct62",
+                ParagraphShadingValues::Pct65 => "pct65",
+                ParagraphShadingValues::Pct70 => "pct70",
+                ParagraphShadingValues::Pct75 => "pct75",
+                ParagraphShadingValues::Pct80 => "pct80",
+                ParagraphShadingValues::Pct85 => "pct85",
+                ParagraphShadingValues::Pct87 => "pct87",
+                ParagraphShadingValues::Pct90 => "pct90",
+                ParagraphShadingValues::Pct95 => "pct95",
+            }
+        )
+    }
+}
@@ -0,0 +1,93 @@
+// --- Spacing ---
+
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Represents the spacing between the lines of a paragraph and around it [`w:spacing`]().
+///
+/// ## Fields
+/// > - `before`: Space above the paragraph, in twentieths of a point (twips).
+/// > - `after`: Space below the paragraph, in twentieths of a point (twips).
+/// > - `line`: Space between the lines. In 240ths of a line if `line_rule` is [LineRule::Auto] (e.g. `360` is 1.5 lines),
+/// >   in twips otherwise.
+/// > - `line_rule`: How `line` is interpreted. `None` doesn't write the attribute, which consumers treat as [LineRule::Auto].
+/// > - `before_autospacing`: Let the consumer pick the space above the paragraph, ignoring `before`.
+/// > - `after_autospacing`: Let the consumer pick the space below the paragraph, ignoring `after`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct ParagraphSpacing {
+    pub before: Option<u32>,
+    pub after: Option<u32>,
+    pub line: Option<u32>,
+    pub line_rule: Option<LineRule>,
+    pub before_autospacing: Option<bool>,
+    pub after_autospacing: Option<bool>,
+}
+
+impl ParagraphSpacing {
+    /// Spacing of `lines` lines between the lines of the paragraph (e.g. `1.5`), rounded to 240ths of a line.
+    pub fn lines(lines: f32) -> Self {
+        Self {
+            line: Some((lines * 240.0).round() as u32),
+            line_rule: Some(LineRule::Auto),
+            ..Default::default()
+        }
+    }
+}
+
+/// Represents how the `line` of a [ParagraphSpacing] is interpreted [`w:lineRule`]().
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LineRule {
+    /// Proportional to the height of the line, in 240ths of a line
+    Auto,
+    /// Exact height in twips, clipping taller content
+    Exact,
+    /// Minimum height in twips
+    AtLeast,
+}
+
+///Note that it will not return the correct value if you dont follow OOXML standard capitalization
+impl<T: Into<String>> From<T> for LineRule {
+    fn from(v: T) -> Self {
+        match v.into().as_ref() {
+            "auto" => LineRule::Auto,
+            "exact" => LineRule::Exact,
+            "atLeast" => LineRule::AtLeast,
+            _ => LineRule::Auto,
+        }
+    }
+}
+
+impl fmt::Display for LineRule {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                LineRule::Auto => "auto",
+                LineRule::Exact => "exact",
+                LineRule::AtLeast => "atLeast",
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_rule_from() {
+        assert_eq!(LineRule::from("auto"), LineRule::Auto);
+        assert_eq!(LineRule::from("exact"), LineRule::Exact);
+        assert_eq!(LineRule::from("atLeast"), LineRule::AtLeast);
+        assert_eq!(LineRule::from("atleast"), LineRule::Auto);
+        assert_eq!(LineRule::AtLeast.to_string(), "atLeast");
+    }
+
+    #[test]
+    fn test_lines() {
+        let spacing = ParagraphSpacing::lines(1.5);
+        assert_eq!(spacing.line, Some(360));
+        assert_eq!(spacing.line_rule, Some(LineRule::Auto));
+    }
+}
@@ -0,0 +1,121 @@
+// --- Tabs ---
+
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Represents a custom tab stop of a paragraph [`w:tab`]().
+///
+/// ## Fields
+/// > - `val`: The alignment of the text at the tab stop. See [ParagraphTabType].
+/// > - `position`: Position of the tab stop from the leading edge of the paragraph, in twentieths of a point (twips).
+/// > - `leader`: The character filling the space before the tab stop. `None` doesn't write the attribute, which
+/// >   consumers treat as [ParagraphTabLeaders::None].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParagraphTab {
+    pub val: ParagraphTabType,
+    pub position: i32,
+    pub leader: Option<ParagraphTabLeaders>,
+}
+
+impl ParagraphTab {
+    pub fn new(val: ParagraphTabType, position: i32, leader: Option<ParagraphTabLeaders>) -> Self {
+        Self {
+            val,
+            position,
+            leader,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ParagraphTabType {
+    Clear,
+    Start,
+    Center,
+    End,
+    Decimal,
+    Bar,
+    Num,
+    Left,
+    Right,
+}
+
+///Note that it will not return the correct value if you dont follow OOXML standard capitalization
+impl<T: Into<String>> From<T> for ParagraphTabType {
+    fn from(v: T) -> Self {
+        match v.into().as_ref() {
+            "clear" => ParagraphTabType::Clear,
+            "start" => ParagraphTabType::Start,
+            "center" => ParagraphTabType::Center,
+            "end" => ParagraphTabType::End,
+            "decimal" => ParagraphTabType::Decimal,
+            "bar" => ParagraphTabType::Bar,
+            "num" => ParagraphTabType::Num,
+            "left" => ParagraphTabType::Left,
+            "right" => ParagraphTabType::Right,
+            _ => ParagraphTabType::Start,
+        }
+    }
+}
+
+impl fmt::Display for ParagraphTabType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ParagraphTabType::Clear => "clear",
+                ParagraphTabType::Start => "start",
+                ParagraphTabType::Center => "center",
+                ParagraphTabType::End => "end",
+                ParagraphTabType::Decimal => "decimal",
+                ParagraphTabType::Bar => "bar",
+                ParagraphTabType::Num => "num",
+                ParagraphTabType::Left => "left",
+                ParagraphTabType::Right => "right",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ParagraphTabLeaders {
+    None,
+    Dot,
+    Hyphen,
+    Underscore,
+    Heavy,
+    MiddleDot,
+}
+
+///Note that it will not return the correct value if you dont follow OOXML standard capitalization
+impl<T: Into<String>> From<T> for ParagraphTabLeaders {
+    fn from(v: T) -> Self {
+        match v.into().as_ref() {
+            "none" => ParagraphTabLeaders::None,
+            "dot" => ParagraphTabLeaders::Dot,
+            "hyphen" => ParagraphTabLeaders::Hyphen,
+            "underscore" => ParagraphTabLeaders::Underscore,
+            "heavy" => ParagraphTabLeaders::Heavy,
+            "middleDot" => ParagraphTabLeaders::MiddleDot,
+            _ => ParagraphTabLeaders::None,
+        }
+    }
+}
+
+impl fmt::Display for ParagraphTabLeaders {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ParagraphTabLeaders::None => "none",
+                ParagraphTabLeaders::Dot => "dot",
+                ParagraphTabLeaders::Hyphen => "hyphen",
+                ParagraphTabLeaders::Underscore => "underscore",
+                ParagraphTabLeaders::Heavy => "heavy",
+                ParagraphTabLeaders::MiddleDot => "middleDot",
+            }
+        )
+    }
+}
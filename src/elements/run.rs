@@ -1,19 +1,28 @@
 use crate::elements::RunProperties;
-#[derive(Debug, Clone, PartialEq)]
+use std::fmt;
+use std::fmt::Formatter;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Representation of a text `Run` in a docx document [`w:r`]().
+///
+/// ### Fields
+/// > - **properties:** `RunProperties` - The format applied to the text. [`w:rPr`]()
+/// > - **text:** `String` - The text of the run. [`w:t`]()
+/// > - **space_preserve:** `bool` - Indicates if leading and trailing whitespace of the text must be kept. [`w:t xml:space="preserve"`]()
+/// > - **picture_id:** `Option<String>` - Relationship id of the image of a legacy VML drawing in the run. Only the image
+/// >   is kept, it's written back as a bare shape showing it. [`w:pict/v:shape/v:imagedata r:id="<ID>"`]()
+/// > - **comment_reference:** `Option<u32>` - Id of the [Comment](crate::elements::Comment) whose mark is shown in the run. [`w:commentReference w:id="<ID>"`]()
+/// > - **footnote_reference:** `Option<u32>` - Id of the [Footnote](crate::elements::Footnote) whose mark is shown in the run. [`w:footnoteReference w:id="<ID>"`]()
+/// > - **break_type:** `Option<BreakType>` - Break placed before the text of the run. `None` is unused. [`w:br w:type="<TYPE>"`]()
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct Run {
     pub properties: RunProperties,
     pub text: String,
     pub space_preserve: bool,
-}
-
-impl Default for Run {
-    fn default() -> Self {
-        Self {
-            properties: RunProperties::default(),
-            text: String::new(),
-            space_preserve: false,
-        }
-    }
+    pub picture_id: Option<String>,
+    pub comment_reference: Option<u32>,
+    pub footnote_reference: Option<u32>,
+    pub break_type: Option<BreakType>,
 }
 
 impl From<String> for Run {
@@ -25,6 +34,18 @@ impl From<String> for Run {
     }
 }
 
+impl From<&str> for Run {
+    fn from(s: &str) -> Self {
+        Self::from(s.to_string())
+    }
+}
+
+impl From<&String> for Run {
+    fn from(s: &String) -> Self {
+        Self::from(s.clone())
+    }
+}
+
 impl From<RunProperties> for Run {
     fn from(rp: RunProperties) -> Self {
         Self {
@@ -40,6 +61,114 @@ impl Run {
             properties,
             text,
             space_preserve,
+            picture_id: None,
+            comment_reference: None,
+            footnote_reference: None,
+            break_type: None,
+        }
+    }
+
+    /// A run breaking the page inline, at its position in the paragraph [`w:br w:type="page"`]().
+    ///
+    /// Unlike [Paragraph::page_break](crate::elements::Paragraph::page_break), which starts a paragraph on a new page,
+    /// the text before the run stays on the current page as part of the same paragraph.
+    pub fn page_break() -> Self {
+        Self {
+            break_type: Some(BreakType::Page),
+            ..Default::default()
+        }
+    }
+
+    /// Number of `char`s of the text of the run.
+    pub fn char_len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    /// Number of characters of the text of the run, same as [char_len](crate::elements::Run::char_len).
+    pub fn len(&self) -> usize {
+        self.char_len()
+    }
+
+    /// Whether the run has no text. Runs with only a break are empty as well.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Whether `text` starts or ends with whitespace. Consumers collapse it unless it's preserved, so such text is always
+    /// written with [`xml:space="preserve"`](), whatever `space_preserve` says.
+    pub(crate) fn has_surrounding_whitespace(text: &str) -> bool {
+        text.starts_with(|c: char| c.is_ascii_whitespace())
+            || text.ends_with(|c: char| c.is_ascii_whitespace())
+    }
+
+    /// Number of user-perceived characters (extended grapheme clusters) of the text of the run. Combining characters and
+    /// emoji sequences count as one, unlike in [char_len](crate::elements::Run::char_len).
+    pub fn grapheme_len(&self) -> usize {
+        self.text.graphemes(true).count()
+    }
+}
+
+/// Kind of a break in a run [`w:br w:type="<TYPE>"`]().
+#[derive(Debug, Clone, PartialEq)]
+pub enum BreakType {
+    /// Line break, the default
+    TextWrapping,
+    Page,
+    Column,
+}
+
+///Note that it will not return the correct value if you dont follow OOXML standard capitalization
+impl<T: Into<String>> From<T> for BreakType {
+    fn from(v: T) -> Self {
+        match v.into().as_ref() {
+            "textWrapping" => BreakType::TextWrapping,
+            "page" => BreakType::Page,
+            "column" => BreakType::Column,
+            _ => BreakType::TextWrapping,
         }
     }
 }
+
+impl fmt::Display for BreakType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                BreakType::TextWrapping => "textWrapping",
+                BreakType::Page => "page",
+                BreakType::Column => "column",
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_char_and_grapheme_len() {
+        let plain = Run::from("Hello".to_string());
+        assert_eq!(plain.char_len(), 5);
+        assert_eq!(plain.grapheme_len(), 5);
+
+        // `e` followed by a combining acute accent
+        let combining = Run::from("Cafe\u{301}".to_string());
+        assert_eq!(combining.char_len(), 5);
+        assert_eq!(combining.grapheme_len(), 4);
+
+        // Family emoji, made of three people joined by zero width joiners
+        let emoji = Run::from("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}!".to_string());
+        assert_eq!(emoji.char_len(), 6);
+        assert_eq!(emoji.grapheme_len(), 2);
+    }
+
+    #[test]
+    fn test_from_str_and_string() {
+        let text = String::from("hello");
+        assert_eq!(Run::from("hello"), Run::from(text.clone()));
+        assert_eq!(Run::from(&text), Run::from(text));
+        assert_eq!(Run::from("hello").text, "hello");
+    }
+}
@@ -3,15 +3,30 @@
 use crate::errors::RudocxStyleError;
 use std::fmt;
 use std::fmt::Formatter;
+use std::hash::{Hash, Hasher};
 
 type Result<T> = std::result::Result<T, RudocxStyleError>;
 
 ///Represents a HEX color code, without the `#` character.
-#[derive(Debug, Clone, PartialEq)]
+///
+///The comparison ignores the case of the digits, so `ff0000` and `FF0000` are the same color.
+#[derive(Debug, Clone, Eq)]
 pub struct HexColor {
     pub value: String,
 }
 
+impl PartialEq for HexColor {
+    fn eq(&self, other: &Self) -> bool {
+        self.value.eq_ignore_ascii_case(&other.value)
+    }
+}
+
+impl Hash for HexColor {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.to_ascii_uppercase().hash(state);
+    }
+}
+
 impl Default for HexColor {
     fn default() -> Self {
         Self {
@@ -22,33 +37,57 @@ impl Default for HexColor {
 
 impl HexColor {
     /// Receives a HEX color code. `#` is **NOT** required. Alpha is not supported. Wrong input defaults to Black.
-    pub fn new(color: &str) -> Self {
-        match check_hex(color) {
-            Ok(_) => Self {
-                value: String::from(color),
-            },
+    pub fn new<T: Into<String>>(color: T) -> Self {
+        let color = color.into();
+        match check_hex(&color) {
+            Ok(_) => Self { value: color },
             Err(_) => Self {
                 value: String::from("FFFFFF"),
             },
         }
     }
 
+    /// Builds the color from its red, green and blue components.
+    pub fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        Self {
+            value: format!("{r:02X}{g:02X}{b:02X}"),
+        }
+    }
+
     /// Get the value of the struct as `String`.
     pub fn value(&self) -> String {
         self.value.clone()
     }
 
+    /// Whether the value is a valid HEX color code. It can only be invalid if the `value` field was modified directly.
+    pub fn is_valid(&self) -> bool {
+        check_hex(&self.value).is_ok()
+    }
+
+    /// Get the red, green and blue components of the color. Returns `None` if the value is not an
+    /// actual color, like `auto`.
+    pub fn to_rgb(&self) -> Option<(u8, u8, u8)> {
+        if self.value.len() != 6 || !self.value.chars().all(|x| x.is_ascii_hexdigit()) {
+            return None;
+        }
+        let component = |i: usize| u8::from_str_radix(&self.value[i..i + 2], 16).ok();
+        Some((component(0)?, component(2)?, component(4)?))
+    }
+
     /// Change the value of the struct. Same rules as [new](crate::properties::HexColor::new) apply, but wrong input value results in an `Err()`
     pub fn change_value(&mut self, value: &str) -> Result<()> {
         match check_hex(value) {
-            Ok(_) => Ok(self.value = value.to_string()),
+            Ok(_) => {
+                self.value = value.to_string();
+                Ok(())
+            }
             Err(e) => Err(e),
         }
     }
 }
 
 fn check_hex(value: &str) -> Result<()> {
-    if !value.len() == 6 {
+    if value.len() != 6 {
         return Err(RudocxStyleError::InvalidHex(value.to_string()));
     }
     if !value.chars().all(|x| x.is_ascii_hexdigit()) {
@@ -57,20 +96,68 @@ fn check_hex(value: &str) -> Result<()> {
     Ok(())
 }
 
-#[derive(Debug, Clone, PartialEq)]
+///Represents a fill percentage, from `0` to `100`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PercentFill {
+    pub value: u8,
+}
+
+impl PercentFill {
+    /// Receives a percentage from `0` to `100`. Wrong input defaults to `100`.
+    pub fn new(value: u8) -> Self {
+        match check_percentage(value) {
+            Ok(_) => Self { value },
+            Err(_) => Self { value: 100 },
+        }
+    }
+
+    /// Get the value of the struct.
+    pub fn value(&self) -> u8 {
+        self.value
+    }
+
+    /// Change the value of the struct. Same rules as [new](crate::elements::PercentFill::new) apply, but wrong input value results in an `Err()`
+    pub fn change_value(&mut self, value: u8) -> Result<()> {
+        check_percentage(value)?;
+        self.value = value;
+        Ok(())
+    }
+}
+
+fn check_percentage(value: u8) -> Result<()> {
+    if value > 100 {
+        return Err(RudocxStyleError::InvalidPercentage(value));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct HLColor {
     pub value: Option<HighlightPalette>,
 }
 
 impl HLColor {
-    pub fn new(color: HighlightPalette) -> Self {
-        Self { value: Some(color) }
+    /// Receives a `HighlightPalette` or its OOXML name (e.g. `darkBlue`).
+    pub fn new<T: Into<HighlightPalette>>(color: T) -> Self {
+        Self {
+            value: Some(color.into()),
+        }
+    }
+
+    /// Receives the OOXML name of a highlight color (e.g. `darkBlue`). `none` and unknown names give no highlight,
+    /// unlike [new](crate::elements::HLColor::new), which turns unknown names white.
+    pub fn from_name(name: &str) -> Self {
+        let color = HighlightPalette::from(name);
+        Self {
+            value: (color != HighlightPalette::White || name == "white").then_some(color),
+        }
     }
 
+    /// The OOXML value of the highlight. `None` is written as `none`, which explicitly clears an inherited highlight.
     pub fn value(&self) -> String {
         match &self.value {
             Some(v) => v.to_string(),
-            None => String::new(),
+            None => String::from("none"),
         }
     }
 
@@ -80,7 +167,7 @@ impl HLColor {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum HighlightPalette {
     Yellow,
     DarkYellow,
@@ -99,7 +186,8 @@ pub enum HighlightPalette {
     // Note: "None" is represented by Option::None in the HLColor struct value.
 }
 
-///Note that it will not return the correct value if you dont follow OOXML standard capitalization
+///Note that it will not return the correct value if you dont follow OOXML standard capitalization.
+///Unknown names, including `none`, fall back to `White`. Use [HLColor::from_name] to get no highlight for them instead.
 impl<T: Into<String>> From<T> for HighlightPalette {
     fn from(color: T) -> Self {
         match color.into().as_ref() {
@@ -146,3 +234,78 @@ impl fmt::Display for HighlightPalette {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_color_rgb() {
+        assert_eq!(HexColor::from_rgb(255, 0, 0).value(), "FF0000");
+        assert_eq!(HexColor::from_rgb(1, 171, 205).value(), "01ABCD");
+        assert_eq!(HexColor::new("FF0000").to_rgb(), Some((255, 0, 0)));
+        assert_eq!(HexColor::new("01abcd").to_rgb(), Some((1, 171, 205)));
+        let auto = HexColor {
+            value: String::from("auto"),
+        };
+        assert_eq!(auto.to_rgb(), None);
+    }
+
+    #[test]
+    fn test_constructors_accept_strings() {
+        assert_eq!(
+            HexColor::new("00FF00"),
+            HexColor::new(String::from("00FF00"))
+        );
+        assert_eq!(HexColor::new(String::from("00FF00")).value(), "00FF00");
+        assert_eq!(HexColor::new(String::from("green")), HexColor::default());
+
+        assert_eq!(
+            HLColor::new("darkBlue"),
+            HLColor::new(HighlightPalette::DarkBlue)
+        );
+        assert_eq!(
+            HLColor::new(String::from("darkBlue")),
+            HLColor::new(HighlightPalette::DarkBlue)
+        );
+    }
+
+    #[test]
+    fn test_percent_fill() {
+        assert_eq!(PercentFill::new(40).value(), 40);
+        assert_eq!(PercentFill::new(150).value(), 100);
+
+        let mut fill = PercentFill::new(25);
+        assert!(fill.change_value(100).is_ok());
+        assert_eq!(fill.value(), 100);
+        assert!(matches!(
+            fill.change_value(150),
+            Err(RudocxStyleError::InvalidPercentage(150))
+        ));
+        assert_eq!(fill.value(), 100);
+    }
+
+    #[test]
+    fn test_hex_color_ignores_case() {
+        assert_eq!(HexColor::new("ff0000"), HexColor::new("FF0000"));
+        assert_ne!(HexColor::new("ff0000"), HexColor::new("FF0001"));
+        let colors =
+            std::collections::HashSet::from([HexColor::new("aBcDeF"), HexColor::new("ABCDEF")]);
+        assert_eq!(colors.len(), 1);
+    }
+
+    #[test]
+    fn test_highlight_from_name() {
+        assert_eq!(
+            HLColor::from_name("yellow"),
+            HLColor::new(HighlightPalette::Yellow)
+        );
+        assert_eq!(
+            HLColor::from_name("white"),
+            HLColor::new(HighlightPalette::White)
+        );
+        assert_eq!(HLColor::from_name("none").value, None);
+        assert_eq!(HLColor::from_name("bogus").value, None);
+        assert_eq!(HLColor::from_name("bogus").value(), "none");
+    }
+}
@@ -1,9 +1,14 @@
 // --- FontSet ---
 
 use crate::errors::RudocxStyleError;
+#[cfg(feature = "font-check")]
+use std::collections::HashSet;
 use std::fmt;
 use std::fmt::Formatter;
+#[cfg(feature = "font-check")]
 use std::path::Path;
+#[cfg(feature = "font-check")]
+use std::sync::{PoisonError, RwLock};
 
 /// Represents font settings for a run in a DOCX document.
 ///
@@ -13,6 +18,9 @@ use std::path::Path;
 /// This, however, does not apply to this specific struct, where although all of the values within it can be `None`. Constructor always fallback to a default.
 /// If getter is invoked with all attributes set to `None`, it will result in an `Err()`.
 ///
+/// An explicit empty `w:rFonts` is represented by a `FontSet` with every value set to `None` (see [theme_default](crate::elements::FontSet::theme_default)),
+/// while a run without `w:rFonts` at all has no `FontSet`.
+///
 /// ## Fields
 /// > - `ascii`: Font name for ASCII characters (U+0000–U+007F).
 /// > - `hAnsi`: Font for high ANSI characters (U+0080+), e.g., accented letters.
@@ -27,7 +35,10 @@ use std::path::Path;
 /// **Note:** Checking whether the selected font has the correct `FontType` is not in the scope of this library. Using a `cs` font as `ascii` or vice-versa can
 /// result in unexpected rendering or behaviours depending on your software.
 ///
-#[derive(Debug, Clone, PartialEq)]
+/// Fonts are checked against the system font directories only with the `font-check` feature (enabled by default).
+/// Without it, any font name is accepted and the filesystem is never read.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct FontSet {
     pub ascii: Option<String>,
     pub hi_ansi: Option<String>,
@@ -57,7 +68,7 @@ impl Default for FontSet {
 }
 
 /// Trying to set or access a `default` hint value will result in an `Err`. Default value is a fallback to fetch the value from software or system configurations.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum FontType {
     Ascii,
     HiAnsi,
@@ -79,9 +90,9 @@ impl<T: Into<String>> From<T> for FontType {
             "eastAsia" => FontType::EastAsia,
             "cs" => FontType::Cs,
             "asciiTheme" => FontType::AsciiTheme,
-            "hiAnsiTheme" => FontType::HiAnsiTheme,
+            "hAnsiTheme" | "hiAnsiTheme" => FontType::HiAnsiTheme,
             "eastAsiaTheme" => FontType::EastAsiaTheme,
-            "csTheme" => FontType::CsTheme,
+            "cstheme" | "csTheme" => FontType::CsTheme,
             "default" => FontType::Default,
             _ => FontType::Default,
         }
@@ -96,15 +107,25 @@ impl fmt::Display for FontType {
             FontType::EastAsia => write!(f, "eastAsia"),
             FontType::Cs => write!(f, "cs"),
             FontType::AsciiTheme => write!(f, "asciiTheme"),
-            FontType::HiAnsiTheme => write!(f, "hiAnsiTheme"),
+            FontType::HiAnsiTheme => write!(f, "hAnsiTheme"),
             FontType::EastAsiaTheme => write!(f, "eastAsiaTheme"),
-            FontType::CsTheme => write!(f, "csTheme"),
+            FontType::CsTheme => write!(f, "cstheme"),
             FontType::Default => write!(f, "default"),
         }
     }
 }
 
 impl FontSet {
+    /// Creates an empty font set, written as an empty `w:rFonts` that makes the software fall back to the theme/style fonts.
+    pub fn theme_default() -> Self {
+        Self::default()
+    }
+
+    /// Whether the font set is empty, meaning a fallback to the theme/style fonts. See [theme_default](crate::elements::FontSet::theme_default)
+    pub fn is_theme_default(&self) -> bool {
+        self == &Self::default()
+    }
+
     /// Creates a new font set with a single `FontType`. Once created, you can also set other `FontType` through [get_value](crate::properties::FontSet::change_value)
     pub fn new(value: String, r#type: FontType) -> crate::elements::run_properties::Result<Self> {
         let mut new_font = Self::default();
@@ -290,71 +311,186 @@ impl FontSet {
         Ok(())
     }
 
+    #[cfg(not(feature = "font-check"))]
+    fn check_font(_font: &str) -> crate::elements::run_properties::Result<()> {
+        Ok(())
+    }
+
+    /// List again the fonts installed in the system, e.g. after installing one while the program runs. Otherwise they are
+    /// listed once, the first time a font is checked.
+    #[cfg(feature = "font-check")]
+    pub fn refresh_installed_fonts() {
+        *INSTALLED_FONTS
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(list_installed_fonts());
+    }
+
+    #[cfg(feature = "font-check")]
     fn check_font(font: &str) -> crate::elements::run_properties::Result<()> {
-        #[cfg(target_os = "linux")]
+        if let Some(fonts) = INSTALLED_FONTS
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
         {
-            let dirs = [
-                "/usr/share/fonts/",
-                "/usr/local/share/fonts/",
-                &format!("{}/.fonts", std::env::var("HOME").unwrap()),
-            ];
-            let fonts = dirs
-                .iter()
-                .flat_map(|x| list_fonts(x))
-                .collect::<Vec<String>>();
             return check_installed(font, fonts);
         }
+        let mut cache = INSTALLED_FONTS
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        check_installed(font, cache.get_or_insert_with(list_installed_fonts))
+    }
+}
 
-        #[cfg(target_os = "windows")]
-        {
-            let fonts = list_fonts("C:\\Windows\\Fonts");
-            return check_installed(font, fonts);
+/// Fonts installed in the system, listed by the first [FontSet] constructed. See [FontSet::refresh_installed_fonts]
+#[cfg(feature = "font-check")]
+static INSTALLED_FONTS: RwLock<Option<HashSet<String>>> = RwLock::new(None);
+
+#[cfg(feature = "font-check")]
+fn list_installed_fonts() -> HashSet<String> {
+    let home = std::env::var("HOME").ok();
+    font_dirs(home.as_deref())
+        .iter()
+        .flat_map(list_fonts)
+        .collect()
+}
+
+/// Directories the fonts are installed in. Without a home directory (e.g. `HOME` unset) only the system wide ones are
+/// listed.
+#[cfg(feature = "font-check")]
+fn font_dirs(home: Option<&str>) -> Vec<String> {
+    let mut dirs: Vec<String> = Vec::new();
+
+    #[cfg(target_os = "linux")]
+    {
+        dirs.push(String::from("/usr/share/fonts/"));
+        dirs.push(String::from("/usr/local/share/fonts/"));
+        if let Some(home) = home {
+            dirs.push(format!("{home}/.fonts"));
         }
+    }
 
-        #[cfg(target_os = "macos")]
-        {
-            let dirs = [
-                "/System/Library/Fonts",
-                "/Library/Fonts",
-                &format!("{}/Library/Fonts", std::env::var("HOME").unwrap()),
-            ];
-            let fonts = dirs
-                .iter()
-                .flat_map(|x| list_fonts(x))
-                .collect::<Vec<String>>();
-            return check_installed(font, fonts);
+    #[cfg(target_os = "windows")]
+    {
+        let _ = home;
+        dirs.push(String::from("C:\\Windows\\Fonts"));
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        dirs.push(String::from("/System/Library/Fonts"));
+        dirs.push(String::from("/Library/Fonts"));
+        if let Some(home) = home {
+            dirs.push(format!("{home}/Library/Fonts"));
         }
+    }
 
-        fn list_fonts<P: AsRef<Path>>(path: P) -> Vec<String> {
-            let mut fonts: Vec<String> = Vec::new();
-            if let Ok(entries) = std::fs::read_dir(path) {
-                for entry in entries.flatten() {
-                    let p = entry.path();
-                    if let Some(ext) = p.extension() {
-                        if ext == "ttf" || ext == "otf" {
-                            if let Some(name) = p.file_name().and_then(|x| x.to_str()) {
-                                fonts.push(name.to_string());
-                            }
-                        }
-                    }
-                }
+    dirs
+}
+
+#[cfg(feature = "font-check")]
+fn list_fonts<P: AsRef<Path>>(path: P) -> Vec<String> {
+    let mut fonts: Vec<String> = Vec::new();
+    if let Ok(entries) = std::fs::read_dir(path) {
+        for entry in entries.flatten() {
+            let p = entry.path();
+            if let Some(ext) = p.extension()
+                && (ext == "ttf" || ext == "otf")
+                && let Some(name) = p.file_stem().and_then(|x| x.to_str())
+            {
+                fonts.push(normalize_font_name(name));
             }
+        }
+    }
 
-            fonts
+    fonts
+}
+
+/// Reduce a font name or font file stem to its family, so `Arial` matches `arial.ttf` and `DejaVu Sans` matches
+/// `DejaVuSans-Bold.ttf`
+#[cfg(feature = "font-check")]
+fn normalize_font_name(name: &str) -> String {
+    name.split('-')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[cfg(feature = "font-check")]
+fn check_installed(
+    value: &str,
+    fonts: &HashSet<String>,
+) -> crate::elements::run_properties::Result<()> {
+    if fonts.is_empty() {
+        Err(RudocxStyleError::SystemFontsNotFound)
+    } else {
+        match fonts.contains(&normalize_font_name(value)) {
+            true => Ok(()),
+            false => Err(RudocxStyleError::FontNotInstalled(value.to_owned())),
         }
+    }
+}
 
-        fn check_installed(
-            value: &str,
-            fonts: Vec<String>,
-        ) -> crate::elements::run_properties::Result<()> {
-            if fonts.is_empty() {
-                Err(RudocxStyleError::SystemFontsNotFound)
-            } else {
-                match fonts.iter().any(|f| value == f) {
-                    true => Ok(()),
-                    false => Err(RudocxStyleError::FontNotInstalled(value.to_owned())),
-                }
-            }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(feature = "font-check", any(target_os = "linux", target_os = "macos")))]
+    #[test]
+    fn test_font_dirs_without_home() {
+        let system = font_dirs(None);
+        assert!(!system.is_empty());
+        assert!(!system.iter().any(|d| d.contains("/home/user")));
+
+        let with_home = font_dirs(Some("/home/user"));
+        assert_eq!(with_home.len(), system.len() + 1);
+        assert!(with_home.iter().any(|d| d.starts_with("/home/user/")));
+    }
+
+    #[cfg(feature = "font-check")]
+    #[test]
+    fn test_installed_fonts_are_cached() {
+        let check = || {
+            format!(
+                "{:?}",
+                FontSet::new(String::from("Surely Not Installed Font"), FontType::Ascii)
+            )
+        };
+        let first = check();
+        assert!(INSTALLED_FONTS.read().unwrap().is_some());
+        for _ in 0..100 {
+            assert_eq!(check(), first);
         }
     }
+
+    #[cfg(feature = "font-check")]
+    #[test]
+    fn test_installed_font_matches_family() {
+        let dir = std::env::temp_dir().join("rudocx_test_fonts");
+        std::fs::create_dir_all(&dir).unwrap();
+        for file in ["arial.ttf", "DejaVuSans-Bold.otf", "readme.txt"] {
+            std::fs::write(dir.join(file), []).unwrap();
+        }
+        let fonts = list_fonts(&dir).into_iter().collect::<HashSet<String>>();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(fonts.len(), 2);
+        assert!(check_installed("Arial", &fonts).is_ok());
+        assert!(check_installed("DejaVu Sans", &fonts).is_ok());
+        assert!(matches!(
+            check_installed("Calibri", &fonts),
+            Err(RudocxStyleError::FontNotInstalled(name)) if name == "Calibri"
+        ));
+    }
+
+    #[cfg(not(feature = "font-check"))]
+    #[test]
+    fn test_new_without_font_check() {
+        // No font directory lists this name, so it only succeeds if the filesystem is not consulted
+        let font =
+            FontSet::new(String::from("Surely Not Installed Font"), FontType::Ascii).unwrap();
+        assert_eq!(font.ascii.as_deref(), Some("Surely Not Installed Font"));
+    }
 }
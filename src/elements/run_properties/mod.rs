@@ -23,17 +23,28 @@ type Result<T> = std::result::Result<T, RudocxStyleError>;
 /// > - **italic:** `bool` - Indicates if a text is italic [`w:i`]
 /// > - **underline:** `Option<Underline>` - Indicates the `Underline` of a text [`w:u`]. `None` is unused.
 /// > - **color:** `Option<HexColor>` - Indicates the `HexColor` of a text font. `None` defaults to `FFFFFF`. _Note:_ XML tag value does **not** prepend the `#` to the HEX code. [`w:color w:val="<HEX_VAL>"`]()
-/// > - **size:** `Option<u32>` - Indicates the font size of a text in half points (e.g. `21` == `10.5 pt.`). `None` defaults to 22 (11pt). [`w:sz w:val="<NUM>"`]()
+/// > - **size:** `Option<u32>` - Indicates the font size of a text in half points (e.g. `21` == `10.5 pt.`). `None` defaults to 22 (11pt). Also written as the size of complex script text. [`w:sz w:val="<NUM>"`, `w:szCs w:val="<NUM>"`]()
 /// > - **font:** `Option<FontSet>` - Indicates the `FontSet` of a text. For `None` and other details, please refere to: [FontSet](crate::properties::FontSet) [`w:rFonts[...]`]()
 /// > - **highlight:** `Option<HLColor>` - Indicates the highlighting `HLColor` of a text. `None` is unused. Only predefined colors are accepted. For custom coloring, `Shading` is used instead. [`w:highlight w:val="<COLOR>"`]()
 /// > - **strike:** `bool` - Indicates if the text is striked through [`w:strike`]()
 /// > - **dstrike:** `bool` - Indicates if the text is double striked through [`w:dstrike`]()
 /// > - **vailgn:** `Option<VerticalAlign>` - Indicates if the text is superscripted, underscripted or normal [`w:vertAlign` w:val="<VALUE>"]()
 /// > - **spacing:** `Option<u32>` - Indicates if the distance between characters. Measured in twentieths of a point (e.g. 15 = 0.75pt) [`w:spacing` w:val="<NUM>"]()
+/// > - **theme_color:** `Option<String>` - Indicates the theme color of a text font (e.g. `accent1`). Takes precedence over `color` on consumers that support themes. `None` is unused. [`w:color w:themeColor="<THEME_COLOR>"`]()
+/// > - **theme_tint:** `Option<String>` - Indicates the tint applied to `theme_color`, as a HEX byte (e.g. `99`). `None` is unused. [`w:color w:themeTint="<HEX_BYTE>"`]()
+/// > - **theme_shade:** `Option<String>` - Indicates the shade applied to `theme_color`, as a HEX byte (e.g. `BF`). `None` is unused. [`w:color w:themeShade="<HEX_BYTE>"`]()
+/// > - **position:** `Option<i32>` - Indicates how much the text is raised (positive) or lowered (negative) from the baseline, in half points. Can't be combined with a superscript or subscript `valign`. `None` is unused. [`w:position w:val="<NUM>"`]()
+/// > - **no_proof:** `bool` - Indicates if spelling and grammar checking must be skipped for the text (e.g. code snippets) [`w:noProof`]()
+/// > - **rtl:** `bool` - Indicates if the text is laid out right to left, for runs of Arabic or Hebrew text among left to right text [`w:rtl`]()
+/// > - **lang:** `Option<String>` - Indicates the language used to check the spelling and grammar of the text, as a BCP 47 tag (e.g. `fr-FR`). `None` uses the language of the document defaults. [`w:lang w:val="<LANG>"`]()
+/// > - **style:** `Option<String>` - Id of the character [Style](crate::elements::Style) applied to the text. `None` is unused. [`w:rStyle w:val="<ID>"`]()
 ///
-/// Note: It's not in the scope right now to add direct support for `Cs` `TypeFont` properties such as szCs, bCs, etc. It is in the scope to add new functionalities
+/// Two runs are only coalesced by [Paragraph::coalesce_runs](crate::elements::Paragraph::coalesce_runs) when their properties are equal,
+/// so every field above takes part in the comparison. They also implement `Hash`, so they can key a `HashMap`. New fields must also be added to [describe](crate::elements::RunProperties::describe).
+///
+/// Note: `Cs` properties (e.g. bCs, iCs) are not supported on their own, only szCs is written, as a mirror of `size`. It is in the scope to add new functionalities
 /// such as capitalization, outline, emboss, etc. but it is not yet supported.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 pub struct RunProperties {
     pub bold: bool,
     pub italic: bool,
@@ -46,29 +57,18 @@ pub struct RunProperties {
     pub dstrike: bool,
     pub valign: Option<VerticalAlign>,
     pub spacing: Option<u32>,
-}
-
-//TODO: Change all constructors to accept T: Into<String> as in UnderlineStyle
-
-impl Default for RunProperties {
-    fn default() -> Self {
-        Self {
-            bold: false,
-            italic: false,
-            underline: None,
-            color: None,
-            size: None,
-            font: None,
-            highlight: None,
-            strike: false,
-            dstrike: false,
-            valign: None,
-            spacing: None,
-        }
-    }
+    pub theme_color: Option<String>,
+    pub theme_tint: Option<String>,
+    pub theme_shade: Option<String>,
+    pub position: Option<i32>,
+    pub no_proof: bool,
+    pub rtl: bool,
+    pub lang: Option<String>,
+    pub style: Option<String>,
 }
 
 impl RunProperties {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         bold: bool,
         italic: bool,
@@ -94,6 +94,197 @@ impl RunProperties {
             dstrike,
             valign,
             spacing,
+            ..Default::default()
+        }
+    }
+
+    /// Set the `FontSet` of the text. Returns `Err` if the `FontSet` is empty, since it is most likely a mistake.
+    /// To explicitly fall back to the theme fonts, assign [FontSet::theme_default] to the `font` field instead.
+    pub fn set_font(&mut self, font: Option<FontSet>) -> Result<()> {
+        if let Some(font) = &font
+            && font.is_theme_default()
+        {
+            return Err(RudocxStyleError::EmptyFontSet);
+        }
+        self.font = font;
+        Ok(())
+    }
+
+    /// Check that the properties don't contradict each other. Returns `Err` if `valign` is a superscript or subscript and
+    /// `position` is also set, since both move the baseline.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(valign) = &self.valign
+            && matches!(
+                valign.value,
+                AlignValues::Superscript | AlignValues::Subscript
+            )
+            && self.position.is_some()
+        {
+            return Err(RudocxStyleError::MutuallyExclusive(
+                String::from("vertAlign"),
+                String::from("position"),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Get a copy of the properties with the unset ones taken from `defaults` (e.g. the properties of a style). Flags are
+    /// set if they are set on either side. See [apply_onto](crate::elements::RunProperties::apply_onto) for the in-place version.
+    pub fn merge(&self, defaults: &RunProperties) -> RunProperties {
+        let mut merged = self.clone();
+        defaults.apply_onto(&mut merged);
+        merged
+    }
+
+    /// Fill the unset properties of `target` with the ones of `self`, in place. Only the values actually copied are cloned.
+    pub fn apply_onto(&self, target: &mut RunProperties) {
+        // Destructured so that adding a field without merging it doesn't compile
+        let RunProperties {
+            bold,
+            italic,
+            underline,
+            color,
+            size,
+            font,
+            highlight,
+            strike,
+            dstrike,
+            valign,
+            spacing,
+            theme_color,
+            theme_tint,
+            theme_shade,
+            position,
+            no_proof,
+            rtl,
+            lang,
+            style,
+        } = self;
+
+        target.bold |= bold;
+        target.italic |= italic;
+        target.strike |= strike;
+        target.dstrike |= dstrike;
+        target.no_proof |= no_proof;
+        target.rtl |= rtl;
+        fill(&mut target.underline, underline);
+        fill(&mut target.color, color);
+        fill(&mut target.size, size);
+        fill(&mut target.font, font);
+        fill(&mut target.highlight, highlight);
+        fill(&mut target.valign, valign);
+        fill(&mut target.spacing, spacing);
+        fill(&mut target.theme_color, theme_color);
+        fill(&mut target.theme_tint, theme_tint);
+        fill(&mut target.theme_shade, theme_shade);
+        fill(&mut target.position, position);
+        fill(&mut target.lang, lang);
+        fill(&mut target.style, style);
+    }
+
+    /// Human-readable summary of the properties that are set (e.g. `bold, color FF0000, highlight yellow`).
+    /// Returns `default` if none is set.
+    pub fn describe(&self) -> String {
+        // Destructured so that adding a field without describing it doesn't compile
+        let RunProperties {
+            bold,
+            italic,
+            underline,
+            color,
+            size,
+            font,
+            highlight,
+            strike,
+            dstrike,
+            valign,
+            spacing,
+            theme_color,
+            theme_tint,
+            theme_shade,
+            position,
+            no_proof,
+            rtl,
+            lang,
+            style,
+        } = self;
+
+        let mut parts = Vec::new();
+        for (set, name) in [
+            (*bold, "bold"),
+            (*italic, "italic"),
+            (*strike, "strike"),
+            (*dstrike, "double strike"),
+            (*no_proof, "no proof"),
+            (*rtl, "right to left"),
+        ] {
+            if set {
+                parts.push(name.to_string());
+            }
+        }
+        if let Some(underline) = underline {
+            parts.push(format!("underline {}", underline.value()));
+        }
+        if let Some(color) = color {
+            parts.push(format!("color {}", color.value()));
+        }
+        for (value, name) in [
+            (theme_color, "theme color"),
+            (theme_tint, "theme tint"),
+            (theme_shade, "theme shade"),
+        ] {
+            if let Some(value) = value {
+                parts.push(format!("{name} {value}"));
+            }
+        }
+        if let Some(size) = size {
+            parts.push(format!("size {size}"));
+        }
+        if let Some(font) = font {
+            let names = [
+                &font.ascii,
+                &font.hi_ansi,
+                &font.east_asia,
+                &font.cs,
+                &font.ascii_theme,
+                &font.hi_ansi_theme,
+                &font.east_asia_theme,
+                &font.cs_theme,
+            ]
+            .into_iter()
+            .flatten()
+            .fold(Vec::<&str>::new(), |mut names, name| {
+                if !names.contains(&name.as_str()) {
+                    names.push(name);
+                }
+                names
+            });
+            match names.is_empty() {
+                true => parts.push(String::from("theme font")),
+                false => parts.push(format!("font {}", names.join("/"))),
+            }
+        }
+        if let Some(highlight) = highlight {
+            parts.push(format!("highlight {}", highlight.value()));
+        }
+        if let Some(valign) = valign {
+            parts.push(format!("vertical align {}", valign.value()));
+        }
+        if let Some(spacing) = spacing {
+            parts.push(format!("spacing {spacing}"));
+        }
+        if let Some(position) = position {
+            parts.push(format!("position {position}"));
+        }
+        if let Some(lang) = lang {
+            parts.push(format!("language {lang}"));
+        }
+        if let Some(style) = style {
+            parts.push(format!("style {style}"));
+        }
+
+        match parts.is_empty() {
+            true => String::from("default"),
+            false => parts.join(", "),
         }
     }
 
@@ -101,3 +292,217 @@ impl RunProperties {
         self != &Self::default()
     }
 }
+
+fn fill<T: Clone>(target: &mut Option<T>, default: &Option<T>) {
+    if target.is_none() {
+        target.clone_from(default);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::{Paragraph, ParagraphChild, Run};
+
+    /// One value per field, each different from the default. Keep it in sync with `RunProperties`.
+    fn distinct_values() -> Vec<RunProperties> {
+        let d = RunProperties::default;
+        vec![
+            RunProperties { bold: true, ..d() },
+            RunProperties {
+                italic: true,
+                ..d()
+            },
+            RunProperties {
+                underline: Some(Underline::default()),
+                ..d()
+            },
+            RunProperties {
+                color: Some(HexColor::new("FF0000")),
+                ..d()
+            },
+            RunProperties {
+                size: Some(24),
+                ..d()
+            },
+            RunProperties {
+                font: Some(FontSet::theme_default()),
+                ..d()
+            },
+            RunProperties {
+                highlight: Some(HLColor::new(HighlightPalette::Red)),
+                ..d()
+            },
+            RunProperties {
+                strike: true,
+                ..d()
+            },
+            RunProperties {
+                dstrike: true,
+                ..d()
+            },
+            RunProperties {
+                valign: Some(VerticalAlign::new(AlignValues::Superscript)),
+                ..d()
+            },
+            RunProperties {
+                spacing: Some(15),
+                ..d()
+            },
+            RunProperties {
+                theme_color: Some(String::from("accent1")),
+                ..d()
+            },
+            RunProperties {
+                theme_tint: Some(String::from("99")),
+                ..d()
+            },
+            RunProperties {
+                theme_shade: Some(String::from("BF")),
+                ..d()
+            },
+            RunProperties {
+                position: Some(-4),
+                ..d()
+            },
+            RunProperties {
+                no_proof: true,
+                ..d()
+            },
+            RunProperties { rtl: true, ..d() },
+            RunProperties {
+                lang: Some(String::from("fr-FR")),
+                ..d()
+            },
+            RunProperties {
+                style: Some(String::from("Strong")),
+                ..d()
+            },
+        ]
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(RunProperties::default().describe(), "default");
+        let properties = RunProperties {
+            bold: true,
+            color: Some(HexColor::new("FF0000")),
+            highlight: Some(HLColor::new(HighlightPalette::Yellow)),
+            ..Default::default()
+        };
+        assert_eq!(
+            properties.describe(),
+            "bold, color FF0000, highlight yellow"
+        );
+
+        for properties in distinct_values() {
+            assert_ne!(properties.describe(), "default");
+        }
+    }
+
+    #[test]
+    fn test_every_field_prevents_coalescing() {
+        for properties in distinct_values() {
+            let mut paragraph = Paragraph {
+                children: vec![
+                    ParagraphChild::Run(Run::from("plain ".to_string())),
+                    ParagraphChild::Run(Run::from(properties.clone())),
+                ],
+                ..Default::default()
+            };
+            paragraph.coalesce_runs();
+            assert_eq!(paragraph.children.len(), 2, "{}", properties.describe());
+        }
+
+        let mut paragraph = Paragraph {
+            children: vec![
+                ParagraphChild::Run(Run::from("Hello".to_string())),
+                ParagraphChild::Run(Run::from(" world".to_string())),
+            ],
+            ..Default::default()
+        };
+        paragraph.coalesce_runs();
+        assert_eq!(
+            paragraph.children,
+            vec![ParagraphChild::Run(Run::from("Hello world".to_string()))]
+        );
+    }
+
+    #[test]
+    fn test_apply_onto_matches_merge() {
+        let values = distinct_values();
+        for defaults in &values {
+            for properties in &values {
+                let mut target = properties.clone();
+                defaults.apply_onto(&mut target);
+                assert_eq!(target, properties.merge(defaults));
+            }
+        }
+
+        let defaults = RunProperties {
+            bold: true,
+            size: Some(24),
+            color: Some(HexColor::new("0000FF")),
+            ..Default::default()
+        };
+        let mut target = RunProperties {
+            color: Some(HexColor::new("FF0000")),
+            ..Default::default()
+        };
+        defaults.apply_onto(&mut target);
+        assert!(target.bold);
+        assert_eq!(target.size, Some(24));
+        assert_eq!(target.color, Some(HexColor::new("FF0000")));
+    }
+
+    #[test]
+    fn test_set_empty_font() {
+        let mut properties = RunProperties::default();
+        assert!(matches!(
+            properties.set_font(Some(FontSet::default())),
+            Err(RudocxStyleError::EmptyFontSet)
+        ));
+        assert!(properties.font.is_none());
+        assert!(!properties.has_formatting());
+
+        let font = FontSet {
+            ascii: Some(String::from("Arial")),
+            hint: FontType::Ascii,
+            ..Default::default()
+        };
+        properties.set_font(Some(font.clone())).unwrap();
+        assert_eq!(properties.font, Some(font));
+        properties.set_font(None).unwrap();
+        assert!(properties.font.is_none());
+    }
+
+    #[test]
+    fn test_validate_valign_and_position() {
+        let mut properties = RunProperties {
+            valign: Some(VerticalAlign::new(AlignValues::Superscript)),
+            ..Default::default()
+        };
+        assert!(properties.validate().is_ok());
+
+        properties.position = Some(-4);
+        assert!(matches!(
+            properties.validate(),
+            Err(RudocxStyleError::MutuallyExclusive(_, _))
+        ));
+
+        properties.valign = Some(VerticalAlign::new(AlignValues::Baseline));
+        assert!(properties.validate().is_ok());
+    }
+
+    #[test]
+    fn test_hash_dedups_equal_properties() {
+        let properties = RunProperties {
+            bold: true,
+            color: Some(HexColor::new("FF0000")),
+            ..Default::default()
+        };
+        let set = std::collections::HashSet::from([properties.clone(), properties.clone()]);
+        assert_eq!(set.len(), 1);
+        assert!(set.contains(&properties));
+    }
+}
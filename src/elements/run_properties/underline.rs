@@ -4,20 +4,17 @@ use std::fmt;
 use std::fmt::Formatter;
 
 ///Represents an underline style.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 pub struct Underline {
     pub value: Option<UnderlineStyle>,
 }
 
-impl Default for Underline {
-    fn default() -> Self {
-        Self { value: None }
-    }
-}
-
 impl Underline {
-    pub fn new(style: UnderlineStyle) -> Self {
-        Self { value: Some(style) }
+    /// Receives an `UnderlineStyle` or its OOXML name (e.g. `wavyDouble`).
+    pub fn new<T: Into<UnderlineStyle>>(style: T) -> Self {
+        Self {
+            value: Some(style.into()),
+        }
     }
 
     pub fn value(&self) -> String {
@@ -36,7 +33,7 @@ impl Underline {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum UnderlineStyle {
     Single,
     Words,
@@ -111,3 +108,20 @@ impl fmt::Display for UnderlineStyle {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_accepts_strings() {
+        assert_eq!(
+            Underline::new("double"),
+            Underline::new(UnderlineStyle::Double)
+        );
+        assert_eq!(
+            Underline::new(String::from("double")),
+            Underline::new(UnderlineStyle::Double)
+        );
+    }
+}
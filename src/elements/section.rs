@@ -0,0 +1,165 @@
+use crate::elements::Paragraph;
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Content repeated at the top of every page of a section. Saved as its own `word/headerN.xml` part.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Header {
+    pub paragraphs: Vec<Paragraph>,
+}
+
+/// Content repeated at the bottom of every page of a section. Saved as its own `word/footerN.xml` part.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Footer {
+    pub paragraphs: Vec<Paragraph>,
+}
+
+impl Header {
+    pub fn new(paragraphs: Vec<Paragraph>) -> Self {
+        Self { paragraphs }
+    }
+}
+
+impl Footer {
+    pub fn new(paragraphs: Vec<Paragraph>) -> Self {
+        Self { paragraphs }
+    }
+}
+
+/// Representation of the `w:sectPr` of the document body.
+///
+/// ### Fields
+/// > - **default_header/default_footer:** Used on every page unless overridden [`w:headerReference w:type="default"`]()
+/// > - **even_header/even_footer:** Used on even pages. _Note:_ Word only honours them when `w:evenAndOddHeaders` is enabled in the document settings [`w:type="even"`]()
+/// > - **first_header/first_footer:** Used on the first page of the section. Setting any of them also writes `w:titlePg` [`w:type="first"`]()
+/// > - **vertical_alignment:** `Option<PageVAlign>` - Indicates how the text is aligned vertically between the top and bottom margins of the pages. `None` defaults to `top`. [`w:vAlign w:val="<VALUE>"`]()
+/// > - **raw_elements:** `Vec<String>` - XML of the children rudocx doesn't support yet (page size, margins, columns...), kept as read so that
+/// >   saving doesn't lose the page setup. They are written back verbatim, so they must be valid OOXML. Empty is unused.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SectionProperties {
+    pub default_header: Option<Header>,
+    pub even_header: Option<Header>,
+    pub first_header: Option<Header>,
+    pub default_footer: Option<Footer>,
+    pub even_footer: Option<Footer>,
+    pub first_footer: Option<Footer>,
+    pub vertical_alignment: Option<PageVAlign>,
+    pub raw_elements: Vec<String>,
+}
+
+impl SectionProperties {
+    pub fn header(&self, r#type: &HeaderFooterType) -> Option<&Header> {
+        match r#type {
+            HeaderFooterType::Default => self.default_header.as_ref(),
+            HeaderFooterType::Even => self.even_header.as_ref(),
+            HeaderFooterType::First => self.first_header.as_ref(),
+        }
+    }
+
+    pub fn footer(&self, r#type: &HeaderFooterType) -> Option<&Footer> {
+        match r#type {
+            HeaderFooterType::Default => self.default_footer.as_ref(),
+            HeaderFooterType::Even => self.even_footer.as_ref(),
+            HeaderFooterType::First => self.first_footer.as_ref(),
+        }
+    }
+
+    pub fn set_header(&mut self, r#type: HeaderFooterType, header: Option<Header>) {
+        match r#type {
+            HeaderFooterType::Default => self.default_header = header,
+            HeaderFooterType::Even => self.even_header = header,
+            HeaderFooterType::First => self.first_header = header,
+        }
+    }
+
+    pub fn set_footer(&mut self, r#type: HeaderFooterType, footer: Option<Footer>) {
+        match r#type {
+            HeaderFooterType::Default => self.default_footer = footer,
+            HeaderFooterType::Even => self.even_footer = footer,
+            HeaderFooterType::First => self.first_footer = footer,
+        }
+    }
+
+    pub fn has_formatting(&self) -> bool {
+        self != &Self::default()
+    }
+}
+
+/// Page variant a header or footer applies to [`w:type`]()
+#[derive(Debug, Clone, PartialEq)]
+pub enum HeaderFooterType {
+    Default,
+    Even,
+    First,
+}
+
+impl HeaderFooterType {
+    pub const ALL: [HeaderFooterType; 3] = [
+        HeaderFooterType::Default,
+        HeaderFooterType::Even,
+        HeaderFooterType::First,
+    ];
+}
+
+///Note that it will not return the correct value if you dont follow OOXML standard capitalization
+impl<T: Into<String>> From<T> for HeaderFooterType {
+    fn from(v: T) -> Self {
+        match v.into().as_ref() {
+            "default" => HeaderFooterType::Default,
+            "even" => HeaderFooterType::Even,
+            "first" => HeaderFooterType::First,
+            _ => HeaderFooterType::Default,
+        }
+    }
+}
+
+impl fmt::Display for HeaderFooterType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                HeaderFooterType::Default => "default",
+                HeaderFooterType::Even => "even",
+                HeaderFooterType::First => "first",
+            }
+        )
+    }
+}
+
+/// Vertical alignment of the text on the pages of a section [`w:vAlign`]()
+#[derive(Debug, Clone, PartialEq)]
+pub enum PageVAlign {
+    Top,
+    Center,
+    Both,
+    Bottom,
+}
+
+///Note that it will not return the correct value if you dont follow OOXML standard capitalization
+impl<T: Into<String>> From<T> for PageVAlign {
+    fn from(v: T) -> Self {
+        match v.into().as_ref() {
+            "top" => PageVAlign::Top,
+            "center" => PageVAlign::Center,
+            "both" => PageVAlign::Both,
+            "bottom" => PageVAlign::Bottom,
+            _ => PageVAlign::Top,
+        }
+    }
+}
+
+impl fmt::Display for PageVAlign {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                PageVAlign::Top => "top",
+                PageVAlign::Center => "center",
+                PageVAlign::Both => "both",
+                PageVAlign::Bottom => "bottom",
+            }
+        )
+    }
+}
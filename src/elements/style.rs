@@ -0,0 +1,106 @@
+// --- Style ---
+
+use crate::elements::{AlignValues, ParagraphProperties, RunProperties, VerticalAlign};
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Representation of a style definition of `word/styles.xml` [`w:style`](), referenced by the `style` of
+/// [ParagraphProperties] and [RunProperties].
+///
+/// ### Fields
+/// > - **id:** `String` - Identifier the references use (e.g. `Heading1`). [`w:styleId`]()
+/// > - **name:** `String` - Name shown to the user (e.g. `heading 1`). [`w:name w:val="<NAME>"`]()
+/// > - **r#type:** `StyleType` - Kind of content the style applies to. [`w:type`]()
+/// > - **default:** `bool` - Whether the style applies to the content that references none of its type. Only one
+/// >   style per type should be the default. [`w:default="1"`]()
+/// > - **based_on:** `Option<String>` - Id of the style this one inherits from. `None` is unused. [`w:basedOn w:val="<ID>"`]()
+/// > - **paragraph_properties:** `ParagraphProperties` - Formatting applied to the paragraphs. Only meaningful for paragraph styles. [`w:pPr`]()
+/// > - **run_properties:** `RunProperties` - Formatting applied to the text. [`w:rPr`]()
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Style {
+    pub id: String,
+    pub name: String,
+    pub r#type: StyleType,
+    pub default: bool,
+    pub based_on: Option<String>,
+    pub paragraph_properties: ParagraphProperties,
+    pub run_properties: RunProperties,
+}
+
+impl Style {
+    pub fn new<T: Into<String>, U: Into<String>>(id: T, name: U, r#type: StyleType) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            r#type,
+            default: false,
+            based_on: None,
+            paragraph_properties: ParagraphProperties::default(),
+            run_properties: RunProperties::default(),
+        }
+    }
+
+    /// Id of the character style of the footnote marks, applied to the footnote references without a style.
+    pub(crate) const FOOTNOTE_REFERENCE: &'static str = "FootnoteReference";
+
+    /// The default paragraph style written when the document doesn't define `Normal`.
+    pub(crate) fn normal() -> Self {
+        Self {
+            default: true,
+            ..Self::new("Normal", "Normal", StyleType::Paragraph)
+        }
+    }
+
+    /// The superscript character style of the footnote marks, written when the document has footnotes and doesn't
+    /// define it.
+    pub(crate) fn footnote_reference() -> Self {
+        Self {
+            run_properties: RunProperties {
+                valign: Some(VerticalAlign::new(AlignValues::Superscript)),
+                ..Default::default()
+            },
+            ..Self::new(
+                Self::FOOTNOTE_REFERENCE,
+                "footnote reference",
+                StyleType::Character,
+            )
+        }
+    }
+}
+
+/// Kind of content a style applies to [`w:type`]()
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StyleType {
+    Paragraph,
+    Character,
+    Table,
+    Numbering,
+}
+
+///Note that it will not return the correct value if you dont follow OOXML standard capitalization
+impl<T: Into<String>> From<T> for StyleType {
+    fn from(v: T) -> Self {
+        match v.into().as_ref() {
+            "paragraph" => StyleType::Paragraph,
+            "character" => StyleType::Character,
+            "table" => StyleType::Table,
+            "numbering" => StyleType::Numbering,
+            _ => StyleType::Paragraph,
+        }
+    }
+}
+
+impl fmt::Display for StyleType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                StyleType::Paragraph => "paragraph",
+                StyleType::Character => "character",
+                StyleType::Table => "table",
+                StyleType::Numbering => "numbering",
+            }
+        )
+    }
+}
@@ -0,0 +1,81 @@
+// --- Table rows ---
+
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Represents a table row [`w:tr`](). Tables are not part of [Document](crate::elements::Document) yet, rows are
+/// read and written on their own with [parse_table_row](crate::xml::parse_table_row) and
+/// [generate_table_row](crate::xml::generate_table_row).
+///
+/// ### Fields
+/// > - **height:** `Option<RowHeight>` - Height of the row. `None` lets the row grow with its content. [`w:trPr/w:trHeight`]()
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct TableRow {
+    pub height: Option<RowHeight>,
+}
+
+/// Represents the height of a table row [`w:trHeight`]().
+///
+/// ## Fields
+/// > - `value`: Height of the row, in twentieths of a point (twips).
+/// > - `rule`: How `value` is applied. `None` doesn't write the attribute, which consumers treat as [HeightRule::AtLeast].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RowHeight {
+    pub value: u32,
+    pub rule: Option<HeightRule>,
+}
+
+impl RowHeight {
+    /// Height of exactly `value` twips, clipping taller content.
+    pub fn exact(value: u32) -> Self {
+        Self {
+            value,
+            rule: Some(HeightRule::Exact),
+        }
+    }
+
+    /// Height of at least `value` twips.
+    pub fn at_least(value: u32) -> Self {
+        Self {
+            value,
+            rule: Some(HeightRule::AtLeast),
+        }
+    }
+}
+
+/// Represents how the height of a [RowHeight] is applied [`w:hRule`]().
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum HeightRule {
+    /// Determined by the content, ignoring the value
+    Auto,
+    /// Exact height, clipping taller content
+    Exact,
+    /// Minimum height
+    AtLeast,
+}
+
+///Note that it will not return the correct value if you dont follow OOXML standard capitalization
+impl<T: Into<String>> From<T> for HeightRule {
+    fn from(v: T) -> Self {
+        match v.into().as_ref() {
+            "auto" => HeightRule::Auto,
+            "exact" => HeightRule::Exact,
+            "atLeast" => HeightRule::AtLeast,
+            _ => HeightRule::AtLeast,
+        }
+    }
+}
+
+impl fmt::Display for HeightRule {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                HeightRule::Auto => "auto",
+                HeightRule::Exact => "exact",
+                HeightRule::AtLeast => "atLeast",
+            }
+        )
+    }
+}
@@ -2,6 +2,7 @@ use crate::elements::FontType;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum RudocxError {
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
@@ -13,14 +14,22 @@ pub enum RudocxError {
     XmlAttributeError(#[from] quick_xml::events::attributes::AttrError),
     #[error("UTF8 error: {0}")]
     Utf8Error(#[from] std::string::FromUtf8Error),
+    #[error("Formatting error: {0}")]
+    FmtError(#[from] std::fmt::Error),
     #[error("Required part not found: {0}")]
     MissingPart(String),
     #[error("Content structure mismatch: {0}")]
     LoadContentMismatch(String),
+    #[error("Character not allowed in XML: {0:?}")]
+    InvalidXmlChar(char),
+    #[error("Document is encrypted or password protected")]
+    Encrypted,
     #[error("Feature not supported: {0}")]
     Unsupported(String),
     #[error("Run property error: {0}")]
     RunPropertyError(RudocxStyleError),
+    #[error("Paragraph property error: {0}")]
+    ParagraphPropertyError(#[from] RudocxParagraphStyleError),
     #[error("Could not convert to Integer: {0}")]
     NumParseError(#[from] std::num::ParseIntError),
 }
@@ -29,6 +38,10 @@ pub enum RudocxError {
 pub enum RudocxStyleError {
     #[error("HEX code not valid: {0}")]
     InvalidHex(String),
+    #[error("Invalid percentage: {0}")]
+    InvalidPercentage(u8),
+    #[error("Properties are mutually exclusive: {0} and {1}")]
+    MutuallyExclusive(String, String),
     #[error("Property not set: {0}")]
     PropertyNotSet(String),
     #[error("Hint points to None value: {0} FontType is None")]
@@ -45,9 +58,47 @@ pub enum RudocxStyleError {
     Undefined(String),
 }
 
+#[derive(Error, Debug, Clone)]
+pub enum RudocxParagraphStyleError {
+    #[error("Shading not valid: {0}")]
+    InvalidShading(String),
+    #[error("Properties are mutually exclusive: {0} and {1}")]
+    MutuallyExclusive(String, String),
+    #[error("Invalid outline level: {0}. Must be between 0 and 9")]
+    InvalidOutlineLevel(u8),
+    #[error("Invalid justification: {0}")]
+    InvalidJustification(String),
+}
+
+/// A structural problem of a `Document`, as found by [Document::validate](crate::elements::Document::validate).
+/// Paragraphs and runs are referred to by their position, see [Paragraph::runs](crate::elements::Paragraph::runs).
+#[derive(Error, Debug, Clone)]
+#[non_exhaustive]
+pub enum ValidationError {
+    #[error("Hyperlink in paragraph {paragraph} references unknown relationship: {id}")]
+    DanglingHyperlink { paragraph: usize, id: String },
+    #[error("Invalid HEX color in paragraph {paragraph}, run {run:?}: {value}")]
+    InvalidHexColor {
+        paragraph: usize,
+        run: Option<usize>,
+        value: String,
+    },
+    #[error("Invalid run properties in paragraph {paragraph}, run {run}: {error}")]
+    InvalidRunProperties {
+        paragraph: usize,
+        run: usize,
+        error: RudocxStyleError,
+    },
+    #[error("Invalid paragraph properties in paragraph {paragraph}: {error}")]
+    InvalidParagraphProperties {
+        paragraph: usize,
+        error: RudocxParagraphStyleError,
+    },
+}
+
 impl From<RudocxError> for std::io::Error {
     fn from(error: RudocxError) -> Self {
-        std::io::Error::new(std::io::ErrorKind::Other, error.to_string())
+        std::io::Error::other(error.to_string())
     }
 }
 
@@ -1,5 +1,10 @@
+use crate::errors::RudocxError;
+use quick_xml::escape::escape;
+use quick_xml::events::Event;
+use quick_xml::Reader;
 use std::collections::HashMap;
-use std::fmt::Write;
+use std::fmt;
+use std::fmt::{Formatter, Write};
 
 /// Manages relationships for a single document, ensuring thread-safety and preventing
 /// relationship ID collisions between different documents.
@@ -22,7 +27,113 @@ use std::fmt::Write;
 #[derive(Debug, Clone, PartialEq)]
 pub struct RelationshipManager {
     counter: u32,
+    relationships: HashMap<String, Relationship>,
+    // Targets of the hyperlink and image relationships, kept in sync for get_links and get_images
     links: HashMap<String, String>,
+    images: HashMap<String, String>,
+}
+
+/// A relationship of `word/document.xml` to another part of the package or to an external resource.
+///
+/// ### Fields
+/// > - **target:** `String` - The URL of external resources, or the path of the part relative to the `word/` folder
+/// >   (e.g. `media/image1.png`) [`Target`]()
+/// > - **kind:** `RelationshipKind` - What the target is [`Type`]()
+/// > - **mode:** `TargetMode` - Whether the target is inside the package or not [`TargetMode`]()
+#[derive(Debug, Clone, PartialEq)]
+pub struct Relationship {
+    pub target: String,
+    pub kind: RelationshipKind,
+    pub mode: TargetMode,
+}
+
+impl Relationship {
+    pub fn new(target: &str, kind: RelationshipKind, mode: TargetMode) -> Self {
+        Self {
+            target: target.to_string(),
+            kind,
+            mode,
+        }
+    }
+}
+
+/// Type of a relationship. Displayed as its OOXML type URL.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum RelationshipKind {
+    Hyperlink,
+    Image,
+    Header,
+    Footer,
+    Styles,
+    Comments,
+    Footnotes,
+    Other(String),
+}
+
+impl<T: Into<String>> From<T> for RelationshipKind {
+    fn from(v: T) -> Self {
+        let v = v.into();
+        match v.as_str() {
+            bp::HYPERLINK_REL_TYPE => RelationshipKind::Hyperlink,
+            bp::IMAGE_REL_TYPE => RelationshipKind::Image,
+            bp::HEADER_REL_TYPE => RelationshipKind::Header,
+            bp::FOOTER_REL_TYPE => RelationshipKind::Footer,
+            bp::STYLES_REL_TYPE => RelationshipKind::Styles,
+            bp::COMMENTS_REL_TYPE => RelationshipKind::Comments,
+            bp::FOOTNOTES_REL_TYPE => RelationshipKind::Footnotes,
+            _ => RelationshipKind::Other(v),
+        }
+    }
+}
+
+impl fmt::Display for RelationshipKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                RelationshipKind::Hyperlink => bp::HYPERLINK_REL_TYPE,
+                RelationshipKind::Image => bp::IMAGE_REL_TYPE,
+                RelationshipKind::Header => bp::HEADER_REL_TYPE,
+                RelationshipKind::Footer => bp::FOOTER_REL_TYPE,
+                RelationshipKind::Styles => bp::STYLES_REL_TYPE,
+                RelationshipKind::Comments => bp::COMMENTS_REL_TYPE,
+                RelationshipKind::Footnotes => bp::FOOTNOTES_REL_TYPE,
+                RelationshipKind::Other(v) => v,
+            }
+        )
+    }
+}
+
+/// Whether the target of a relationship is a part of the package or an external resource (e.g. a web page)
+#[derive(Debug, Clone, PartialEq)]
+pub enum TargetMode {
+    Internal,
+    External,
+}
+
+///Note that it will not return the correct value if you dont follow OOXML standard capitalization
+impl<T: Into<String>> From<T> for TargetMode {
+    fn from(v: T) -> Self {
+        match v.into().as_ref() {
+            "External" => TargetMode::External,
+            _ => TargetMode::Internal,
+        }
+    }
+}
+
+impl fmt::Display for TargetMode {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                TargetMode::Internal => "Internal",
+                TargetMode::External => "External",
+            }
+        )
+    }
 }
 
 impl Default for RelationshipManager {
@@ -35,71 +146,348 @@ impl RelationshipManager {
     pub fn new() -> Self {
         Self {
             counter: 0,
+            relationships: HashMap::new(),
             links: HashMap::new(),
+            images: HashMap::new(),
         }
     }
 
-    /// Generate a new relationship ID and store the target
+    /// Generate a new relationship ID and store the target as an external hyperlink
     pub fn generate_rid(&mut self, target: &str) -> String {
+        self.generate_rid_typed(target, RelationshipKind::Hyperlink, TargetMode::External)
+    }
+
+    /// Generate a new relationship ID and store the target with the given type and mode
+    pub fn generate_rid_typed(&mut self, target: &str, kind: RelationshipKind, mode: TargetMode) -> String {
         self.counter += 1;
         let rid = format!("rId{}", self.counter);
-        self.links.insert(rid.clone(), target.to_string());
+        self.insert(rid.clone(), Relationship::new(target, kind, mode));
         rid
     }
 
-    /// Get all stored relationships
+    /// Generate a new relationship ID for an image part, whose target is relative to the `word/` folder
+    /// (e.g. `media/image1.png`)
+    pub fn generate_image_rid(&mut self, target: &str) -> String {
+        self.generate_rid_typed(target, RelationshipKind::Image, TargetMode::Internal)
+    }
+
+    /// Get the relationship with the given ID
+    pub fn get(&self, rid: &str) -> Option<&Relationship> {
+        self.relationships.get(rid)
+    }
+
+    /// Get all stored relationships, whatever their type
+    pub fn get_relationships(&self) -> &HashMap<String, Relationship> {
+        &self.relationships
+    }
+
+    /// Get the targets of all stored hyperlink relationships
     pub fn get_links(&self) -> &HashMap<String, String> {
         &self.links
     }
 
+    /// Get the targets of all stored image relationships
+    pub fn get_images(&self) -> &HashMap<String, String> {
+        &self.images
+    }
+
+    /// Whether both managers hold the same relationships, whatever ids they are stored under
+    pub fn targets_eq(&self, other: &Self) -> bool {
+        self.relationships.len() == other.relationships.len() && self.sorted_targets() == other.sorted_targets()
+    }
+
+    fn sorted_targets(&self) -> Vec<(String, &str, String)> {
+        let mut targets = self
+            .relationships
+            .values()
+            .map(|r| (r.kind.to_string(), r.target.as_str(), r.mode.to_string()))
+            .collect::<Vec<_>>();
+        targets.sort();
+        targets
+    }
+
+    /// Numeric part of the highest `rId` handed out or loaded so far
+    pub fn last_id(&self) -> u32 {
+        self.counter
+    }
+
     /// Clear all relationships (useful for testing or document reset)
     pub fn clear(&mut self) {
         self.counter = 0;
+        self.relationships.clear();
         self.links.clear();
+        self.images.clear();
+    }
+
+    /// Remove a relationship, returning its target if it existed
+    pub fn remove(&mut self, rid: &str) -> Option<String> {
+        self.links.remove(rid);
+        self.images.remove(rid);
+        self.relationships.remove(rid).map(|r| r.target)
     }
 
-    /// Add a relationship with a specific ID (used when loading documents)
+    /// Add an external hyperlink relationship with a specific ID (used when loading documents)
     pub fn add_relationship(&mut self, id: String, target: String) {
+        self.add_relationship_typed(id, Relationship::new(&target, RelationshipKind::Hyperlink, TargetMode::External));
+    }
+
+    /// Add an image relationship with a specific ID (used when loading documents)
+    pub fn add_image_relationship(&mut self, id: String, target: String) {
+        self.add_relationship_typed(id, Relationship::new(&target, RelationshipKind::Image, TargetMode::Internal));
+    }
+
+    /// Add a relationship of any type with a specific ID
+    pub fn add_relationship_typed(&mut self, id: String, relationship: Relationship) {
         // Extract counter from ID if it follows the rId pattern
-        if let Some(num_str) = id.strip_prefix("rId") {
-            if let Ok(num) = num_str.parse::<u32>() {
-                self.counter = self.counter.max(num);
-            }
+        if let Some(num_str) = id.strip_prefix("rId")
+            && let Ok(num) = num_str.parse::<u32>()
+        {
+            self.counter = self.counter.max(num);
         }
-        self.links.insert(id, target);
+        self.insert(id, relationship);
     }
+
+    fn insert(&mut self, id: String, relationship: Relationship) {
+        // A replaced relationship may have been of another type
+        self.links.remove(&id);
+        self.images.remove(&id);
+        match relationship.kind {
+            RelationshipKind::Hyperlink => self.links.insert(id.clone(), relationship.target.clone()),
+            RelationshipKind::Image => self.images.insert(id.clone(), relationship.target.clone()),
+            _ => None,
+        };
+        self.relationships.insert(id, relationship);
+    }
+}
+
+pub fn generate_doc_rels<'a>(
+    xml: &'a mut String,
+    relationship_manager: &RelationshipManager,
+) -> Result<&'a str, RudocxError> {
+    generate_doc_rels_with_parts(xml, relationship_manager, &[])
 }
 
-pub fn generate_doc_rels<'a>(xml: &'a mut String, relationship_manager: &RelationshipManager) -> &'a str {
+/// Same as [generate_doc_rels], additionally writing the relationships to internal package parts
+/// (e.g. headers and footers) given as `(id, type, target)`.
+pub fn generate_doc_rels_with_parts<'a>(
+    xml: &'a mut String,
+    relationship_manager: &RelationshipManager,
+    parts: &[(&str, &str, &str)],
+) -> Result<&'a str, RudocxError> {
     xml.clear();
     xml.push_str(
         r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
 <Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
     );
 
-    for (id, target) in relationship_manager.get_links() {
-        if let Err(_) = write!(
+    // Sorted by number so the output is the same on every save. Ids not following the rId pattern go last.
+    let mut relationships = relationship_manager
+        .get_relationships()
+        .iter()
+        .collect::<Vec<(&String, &Relationship)>>();
+    relationships.sort_by_key(|(id, _)| {
+        let number = id.strip_prefix("rId").and_then(|n| n.parse::<u32>().ok());
+        (number.is_none(), number, id.as_str())
+    });
+
+    for (id, relationship) in relationships {
+        // Internal is the default mode, it's only written for external targets
+        let mode = match relationship.mode {
+            TargetMode::Internal => String::new(),
+            TargetMode::External => format!(r#" TargetMode="{}""#, relationship.mode),
+        };
+        write!(
             xml,
-            r#"<Relationship Id="{id}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink" Target="{target}" TargetMode="External"/>"#
-        ) {
-            // Handle write error - for now we'll continue, but this could be improved
-            eprintln!("Warning: Failed to write relationship for {}", id);
-        }
+            r#"<Relationship Id="{}" Type="{}" Target="{}"{mode}/>"#,
+            // Hyperlink targets may have query strings with `&`
+            escape(id), relationship.kind, escape(&relationship.target)
+        )?;
+    }
+
+    for (id, rel_type, target) in parts {
+        write!(
+            xml,
+            r#"<Relationship Id="{}" Type="{rel_type}" Target="{}"/>"#,
+            escape(*id), escape(*target)
+        )?;
     }
 
     xml.push_str("</Relationships>");
-    xml.as_str()
+    Ok(xml.as_str())
+}
+
+/// Generate the package relationships (`_rels/.rels`), pointing to `word/document.xml` and to the
+/// additional package parts (e.g. document properties) given as `(id, type, target)`.
+pub fn generate_package_rels<'a>(
+    xml: &'a mut String,
+    parts: &[(&str, &str, &str)],
+) -> Result<&'a str, RudocxError> {
+    xml.clear();
+    xml.push_str(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+    <Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/>"#,
+    );
+
+    for (id, rel_type, target) in parts {
+        write!(
+            xml,
+            r#"
+    <Relationship Id="{}" Type="{rel_type}" Target="{}"/>"#,
+            escape(*id), escape(*target)
+        )?;
+    }
+
+    xml.push_str("\n</Relationships>");
+    Ok(xml.as_str())
+}
+
+/// Generate `[Content_Types].xml`, adding an `Override` for each `(part name, content type)` given.
+pub fn generate_content_types<'a>(
+    xml: &'a mut String,
+    overrides: &[(&str, &str)],
+) -> Result<&'a str, RudocxError> {
+    generate_content_types_with_defaults(xml, &[], overrides)
+}
+
+/// Same as [generate_content_types], additionally adding a `Default` for each `(extension, content type)` given
+/// (e.g. the image formats of the media parts).
+pub fn generate_content_types_with_defaults<'a>(
+    xml: &'a mut String,
+    defaults: &[(&str, &str)],
+    overrides: &[(&str, &str)],
+) -> Result<&'a str, RudocxError> {
+    xml.clear();
+    xml.push_str(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+    <Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+    <Default Extension="xml" ContentType="application/xml"/>
+    <Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/>"#,
+    );
+
+    for (extension, content_type) in defaults {
+        write!(
+            xml,
+            r#"
+    <Default Extension="{extension}" ContentType="{content_type}"/>"#
+        )?;
+    }
+
+    for (part_name, content_type) in overrides {
+        write!(
+            xml,
+            r#"
+    <Override PartName="{part_name}" ContentType="{content_type}"/>"#
+        )?;
+    }
+
+    xml.push_str("\n</Types>");
+    Ok(xml.as_str())
+}
+
+/// Read the `Id -> Target` pairs of a `.rels` part.
+pub fn read_relationships(xml: &str) -> Result<HashMap<String, String>, RudocxError> {
+    let relationships = read_relationships_typed(xml)?;
+    Ok(relationships.into_iter().map(|(id, r)| (id, r.target)).collect())
+}
+
+/// Same as [read_relationships], also reading the type and the target mode of the relationships. Relationships
+/// without type are read as [RelationshipKind::Other].
+pub fn read_relationships_typed(xml: &str) -> Result<HashMap<String, Relationship>, RudocxError> {
+    let mut reader = Reader::from_str(xml);
+    let mut buf = Vec::new();
+    let mut relationships = HashMap::new();
+
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(e) | Event::Empty(e) if e.name().as_ref() == b"Relationship" => {
+                let mut id = None;
+                let mut target = None;
+                let mut kind = RelationshipKind::Other(String::new());
+                let mut mode = TargetMode::Internal;
+                for a in e.attributes().flatten() {
+                    let value = a.decode_and_unescape_value(reader.decoder())?;
+                    match a.key.as_ref() {
+                        b"Id" => id = Some(value.to_string()),
+                        b"Target" => target = Some(value.to_string()),
+                        b"Type" => kind = RelationshipKind::from(value),
+                        b"TargetMode" => mode = TargetMode::from(value),
+                        _ => (),
+                    }
+                }
+                if let (Some(id), Some(target)) = (id, target) {
+                    relationships.insert(id, Relationship { target, kind, mode });
+                }
+            }
+            Event::Eof => break,
+            _ => (),
+        }
+        buf.clear();
+    }
+
+    Ok(relationships)
 }
 
 pub mod bp {
     pub const DOCUMENT_XML_PATH: &str = "word/document.xml";
+    pub const DOCUMENT_RELS_PATH: &str = "word/_rels/document.xml.rels";
+
+    pub const HYPERLINK_REL_TYPE: &str =
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink";
+    pub const HEADER_REL_TYPE: &str =
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/header";
+    pub const FOOTER_REL_TYPE: &str =
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/footer";
+    pub const IMAGE_REL_TYPE: &str =
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/image";
+    pub const COMMENTS_REL_TYPE: &str =
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/comments";
+    pub const FOOTNOTES_REL_TYPE: &str =
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/footnotes";
+
+    pub const HEADER_CONTENT_TYPE: &str =
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.header+xml";
+    pub const FOOTER_CONTENT_TYPE: &str =
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.footer+xml";
+    pub const COMMENTS_CONTENT_TYPE: &str =
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.comments+xml";
+    pub const FOOTNOTES_CONTENT_TYPE: &str =
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.footnotes+xml";
+
+    pub const COMMENTS_XML_PATH: &str = "word/comments.xml";
+    pub const COMMENTS_RELS_PATH: &str = "word/_rels/comments.xml.rels";
+    pub const FOOTNOTES_XML_PATH: &str = "word/footnotes.xml";
+    pub const FOOTNOTES_RELS_PATH: &str = "word/_rels/footnotes.xml.rels";
+
+    // Minimal parts written on demand, see `SaveOptions::minimal_parts`
+    pub const STYLES_XML_PATH: &str = "word/styles.xml";
+    pub const APP_XML_PATH: &str = "docProps/app.xml";
+    pub const CORE_XML_PATH: &str = "docProps/core.xml";
+
+    pub const STYLES_REL_TYPE: &str =
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles";
+    pub const APP_REL_TYPE: &str =
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/extended-properties";
+    pub const CORE_REL_TYPE: &str =
+        "http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties";
+
+    pub const STYLES_CONTENT_TYPE: &str =
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml";
+    pub const APP_CONTENT_TYPE: &str =
+        "application/vnd.openxmlformats-officedocument.extended-properties+xml";
+    pub const CORE_CONTENT_TYPE: &str = "application/vnd.openxmlformats-package.core-properties+xml";
+
+    pub const APP_XML_CONTENT: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/extended-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes">
+    <Application>rudocx</Application>
+</Properties>"#;
+
+    pub const CORE_XML_CONTENT: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<cp:coreProperties xmlns:cp="http://schemas.openxmlformats.org/package/2006/metadata/core-properties" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:dcterms="http://purl.org/dc/terms/" xmlns:dcmitype="http://purl.org/dc/dcmitype/" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+</cp:coreProperties>"#;
 
     // Boilerplate XML content
-    pub const RELS_XML_CONTENT: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
-<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
-    <Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/>
-</Relationships>"#;
-
     pub const CONTENT_TYPES_XML_CONTENT: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
 <Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
     <Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
@@ -121,7 +509,7 @@ mod tests {
     fn test_relationship_manager_new() {
         let manager = RelationshipManager::new();
         assert_eq!(manager.counter, 0);
-        assert_eq!(manager.links.len(), 0);
+        assert_eq!(manager.relationships.len(), 0);
     }
 
     #[test]
@@ -155,6 +543,24 @@ mod tests {
         assert_eq!(rid, "rId1");
     }
 
+    #[test]
+    fn test_links_and_images_follow_changes() {
+        let mut manager = RelationshipManager::new();
+        let link = manager.generate_rid("https://example.com");
+        let image = manager.generate_image_rid("media/image1.png");
+        assert_eq!(manager.get_links().len(), 1);
+        assert_eq!(manager.get_images().get(&image), Some(&"media/image1.png".to_string()));
+
+        // Replacing a link with an image moves it from one map to the other
+        manager.add_image_relationship(link.clone(), "media/image2.png".to_string());
+        assert!(manager.get_links().is_empty());
+        assert_eq!(manager.get_images().len(), 2);
+
+        manager.remove(&image);
+        assert_eq!(manager.get_images().len(), 1);
+        assert!(!manager.get_images().contains_key(&image));
+    }
+
     #[test]
     fn test_add_relationship() {
         let mut manager = RelationshipManager::new();
@@ -180,6 +586,22 @@ mod tests {
         assert_eq!(next_rid, "rId1");
     }
 
+    #[test]
+    fn test_targets_eq() {
+        let mut manager = RelationshipManager::new();
+        manager.generate_rid("https://example.com");
+        manager.generate_image_rid("media/image1.png");
+
+        let mut other = RelationshipManager::new();
+        other.add_image_relationship("rId7".to_string(), "media/image1.png".to_string());
+        other.add_relationship("rId3".to_string(), "https://example.com".to_string());
+        assert_ne!(manager, other);
+        assert!(manager.targets_eq(&other));
+
+        other.generate_rid("https://example.com");
+        assert!(!manager.targets_eq(&other));
+    }
+
     #[test]
     fn test_generate_doc_rels() {
         let mut manager = RelationshipManager::new();
@@ -187,7 +609,7 @@ mod tests {
         manager.generate_rid("https://example2.com");
 
         let mut xml = String::new();
-        let result = generate_doc_rels(&mut xml, &manager);
+        let result = generate_doc_rels(&mut xml, &manager).unwrap();
 
         assert!(!result.is_empty());
         assert!(result.contains("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>"));
@@ -199,11 +621,66 @@ mod tests {
         assert!(result.contains("TargetMode=\"External\""));
     }
 
+    #[test]
+    fn test_generate_doc_rels_typed() {
+        let mut manager = RelationshipManager::new();
+        manager.generate_rid("https://example.com");
+        manager.generate_rid_typed("media/image1.png", RelationshipKind::Image, TargetMode::Internal);
+
+        let mut xml = String::new();
+        let result = generate_doc_rels(&mut xml, &manager).unwrap();
+
+        assert!(result.contains(&format!(
+            r#"<Relationship Id="rId1" Type="{}" Target="https://example.com" TargetMode="External"/>"#,
+            bp::HYPERLINK_REL_TYPE
+        )));
+        assert!(result.contains(&format!(
+            r#"<Relationship Id="rId2" Type="{}" Target="media/image1.png"/>"#,
+            bp::IMAGE_REL_TYPE
+        )));
+        assert_eq!(manager.get_links().len(), 1);
+        assert_eq!(manager.get("rId2").map(|r| &r.kind), Some(&RelationshipKind::Image));
+        assert_eq!(RelationshipKind::from(bp::IMAGE_REL_TYPE), RelationshipKind::Image);
+        assert_eq!(&read_relationships_typed(result).unwrap(), manager.get_relationships());
+    }
+
+    #[test]
+    fn test_generate_doc_rels_escaped() {
+        let mut manager = RelationshipManager::new();
+        manager.generate_rid("https://example.com/?a=1&b=2");
+        manager.add_relationship("rId<2>".to_string(), "https://example.com/\"quoted\"".to_string());
+
+        let xml = generate_doc_rels(&mut String::new(), &manager).unwrap().to_string();
+        assert!(xml.contains(r#"Target="https://example.com/?a=1&amp;b=2""#));
+        assert!(xml.contains(r#"Id="rId&lt;2&gt;" "#));
+
+        let relationships = read_relationships(&xml).unwrap();
+        assert_eq!(relationships.get("rId1").map(String::as_str), Some("https://example.com/?a=1&b=2"));
+        assert_eq!(relationships.get("rId<2>").map(String::as_str), Some("https://example.com/\"quoted\""));
+    }
+
+    #[test]
+    fn test_generate_doc_rels_sorted() {
+        let mut manager = RelationshipManager::new();
+        for n in 1..=12 {
+            manager.generate_rid(&format!("https://example{n}.com"));
+        }
+        manager.add_relationship("customId".to_string(), "https://custom.com".to_string());
+
+        let first = generate_doc_rels(&mut String::new(), &manager).unwrap().to_string();
+        let second = generate_doc_rels(&mut String::new(), &manager).unwrap().to_string();
+        assert_eq!(first, second);
+
+        let positions = ["\"rId1\"", "\"rId2\"", "\"rId10\"", "\"rId12\"", "\"customId\""]
+            .map(|id| first.find(id).unwrap());
+        assert!(positions.windows(2).all(|w| w[0] < w[1]));
+    }
+
     #[test]
     fn test_generate_doc_rels_empty() {
         let manager = RelationshipManager::new();
         let mut xml = String::new();
-        let result = generate_doc_rels(&mut xml, &manager);
+        let result = generate_doc_rels(&mut xml, &manager).unwrap();
 
         assert!(!result.is_empty());
         assert!(result.contains("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>"));
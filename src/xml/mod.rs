@@ -1,3 +1,4 @@
+mod prefixes;
 mod read;
 mod write;
 
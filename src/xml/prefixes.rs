@@ -0,0 +1,221 @@
+use crate::errors::RudocxError;
+use quick_xml::events::attributes::Attribute;
+use quick_xml::events::{BytesEnd, BytesStart, Event};
+use quick_xml::name::{Namespace, QName, ResolveResult};
+use quick_xml::{NsReader, Writer};
+use std::borrow::Cow;
+
+/// Prefix the reader matches for the elements and attributes of each namespace. Both the transitional and the strict
+/// OOXML namespaces are mapped.
+const PREFIXES: [(&str, &str); 10] = [
+    (
+        "w",
+        "http://schemas.openxmlformats.org/wordprocessingml/2006/main",
+    ),
+    ("w", "http://purl.oclc.org/ooxml/wordprocessingml/main"),
+    (
+        "r",
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
+    ),
+    (
+        "r",
+        "http://purl.oclc.org/ooxml/officeDocument/relationships",
+    ),
+    (
+        "wp",
+        "http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing",
+    ),
+    (
+        "wp",
+        "http://purl.oclc.org/ooxml/drawingml/wordprocessingDrawing",
+    ),
+    ("a", "http://schemas.openxmlformats.org/drawingml/2006/main"),
+    ("a", "http://purl.oclc.org/ooxml/drawingml/main"),
+    (
+        "pic",
+        "http://schemas.openxmlformats.org/drawingml/2006/picture",
+    ),
+    ("pic", "http://purl.oclc.org/ooxml/drawingml/picture"),
+];
+
+/// Rewrite the XML so that the known namespaces use the prefixes the reader matches (e.g. `<ns0:p>` or `<p>` in the
+/// WordprocessingML default namespace become `<w:p>`). Returns the XML as is when it already uses them, which is the
+/// case of every document written by Word.
+pub(crate) fn normalize_prefixes(content: &str) -> Result<Cow<'_, str>, RudocxError> {
+    if !has_foreign_prefixes(content) {
+        return Ok(Cow::Borrowed(content));
+    }
+
+    let mut reader = NsReader::from_str(content);
+    let mut writer = Writer::new(Vec::with_capacity(content.len()));
+    loop {
+        let event = match reader.read_event()? {
+            Event::Start(e) => Event::Start(rename_start(&reader, &e)?),
+            Event::Empty(e) => Event::Empty(rename_start(&reader, &e)?),
+            Event::End(e) => {
+                let name = canonical_name(reader.resolve_element(e.name()), e.name());
+                Event::End(BytesEnd::new(name))
+            }
+            Event::Eof => break,
+            event => event,
+        };
+        writer.write_event(event)?;
+    }
+    String::from_utf8(writer.into_inner())
+        .map(Cow::Owned)
+        .map_err(RudocxError::Utf8Error)
+}
+
+/// Whether a known namespace is declared with a prefix other than the expected one, or as the default namespace.
+fn has_foreign_prefixes(content: &str) -> bool {
+    content.match_indices("xmlns").any(|(i, _)| {
+        let declaration = &content[i + "xmlns".len()..];
+        let Some((prefix, rest)) = declaration.split_once('=') else {
+            return false;
+        };
+        let prefix = prefix.trim().strip_prefix(':').unwrap_or(prefix.trim());
+        let uri = rest
+            .trim_start()
+            .get(1..)
+            .and_then(|r| r.split(['"', '\'']).next());
+        PREFIXES
+            .iter()
+            .any(|(expected, known)| Some(*known) == uri && *expected != prefix)
+    })
+}
+
+fn rename_start(
+    reader: &NsReader<&[u8]>,
+    e: &BytesStart,
+) -> Result<BytesStart<'static>, RudocxError> {
+    let mut renamed = BytesStart::new(canonical_name(reader.resolve_element(e.name()), e.name()));
+    for a in e.attributes() {
+        let a = a?;
+        let key = match a.key.as_namespace_binding() {
+            // Declarations of the known namespaces are moved to the expected prefixes
+            Some(_) => match PREFIXES
+                .iter()
+                .find(|(_, uri)| uri.as_bytes() == a.value.as_ref())
+            {
+                Some((prefix, _)) => format!("xmlns:{prefix}"),
+                None => String::from_utf8_lossy(a.key.as_ref()).into_owned(),
+            },
+            None => canonical_name(reader.resolve_attribute(a.key), a.key),
+        };
+        renamed.push_attribute(Attribute {
+            key: QName(key.as_bytes()),
+            value: a.value,
+        });
+    }
+    Ok(renamed)
+}
+
+/// Name with the expected prefix if it belongs to a known namespace, or the original one otherwise.
+fn canonical_name(
+    (namespace, local): (ResolveResult, quick_xml::name::LocalName),
+    original: QName,
+) -> String {
+    let prefix = match namespace {
+        ResolveResult::Bound(Namespace(uri)) => PREFIXES
+            .iter()
+            .find(|(_, known)| known.as_bytes() == uri)
+            .map(|(prefix, _)| prefix),
+        _ => None,
+    };
+    match prefix {
+        Some(prefix) => format!("{prefix}:{}", String::from_utf8_lossy(local.as_ref())),
+        None => String::from_utf8_lossy(original.as_ref()).into_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::ParagraphChild;
+    use crate::xml::parse;
+
+    const SAMPLE: &str = r#"
+        <w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+            <w:body>
+                <w:p>
+                    <w:pPr><w:ind w:left="720"/></w:pPr>
+                    <w:r><w:rPr><w:b/><w:color w:val="FF0000"/></w:rPr><w:t>Bold &amp; red.</w:t></w:r>
+                </w:p>
+                <w:p>
+                    <w:hyperlink r:id="rId1">
+                        <w:r><w:t>Link</w:t></w:r>
+                    </w:hyperlink>
+                </w:p>
+            </w:body>
+        </w:document>
+    "#;
+
+    #[test]
+    fn test_expected_prefixes_are_kept_as_is() {
+        assert!(matches!(normalize_prefixes(SAMPLE), Ok(Cow::Borrowed(_))));
+    }
+
+    #[test]
+    fn test_renamed_prefix() {
+        let renamed = SAMPLE
+            .replace("<w:", "<ns0:")
+            .replace("</w:", "</ns0:")
+            .replace(" w:", " ns0:")
+            .replace("xmlns:w=", "xmlns:ns0=");
+        assert!(!renamed.contains("w:"));
+        assert_eq!(parse(&renamed).unwrap(), parse(SAMPLE).unwrap());
+    }
+
+    #[test]
+    fn test_default_namespace() {
+        let default = SAMPLE
+            .replace("<w:", "<")
+            .replace("</w:", "</")
+            .replace("xmlns:w=", "xmlns=")
+            .replace(" w:", " ns0:")
+            .replace(
+                "<document ",
+                r#"<document xmlns:ns0="http://schemas.openxmlformats.org/wordprocessingml/2006/main" "#,
+            );
+        assert_eq!(parse(&default).unwrap(), parse(SAMPLE).unwrap());
+    }
+
+    #[test]
+    fn test_strict_drawing_namespaces() {
+        let drawing = |namespaces: &str, a: &str, pic: &str| {
+            format!(
+                r#"<w:document {namespaces}><w:body><w:p><w:r><w:drawing><wp:inline>
+                    <wp:extent cx="914400" cy="914400"/>
+                    <{a}:graphic><{a}:graphicData><{pic}:pic><{pic}:blipFill>
+                        <{a}:blip r:embed="rId1"/>
+                    </{pic}:blipFill></{pic}:pic></{a}:graphicData></{a}:graphic>
+                </wp:inline></w:drawing></w:r></w:p></w:body></w:document>"#
+            )
+        };
+        let transitional = drawing(
+            r#"xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"
+                xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"
+                xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing"
+                xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"
+                xmlns:pic="http://schemas.openxmlformats.org/drawingml/2006/picture""#,
+            "a",
+            "pic",
+        );
+        let strict = drawing(
+            r#"xmlns:w="http://purl.oclc.org/ooxml/wordprocessingml/main"
+                xmlns:r="http://purl.oclc.org/ooxml/officeDocument/relationships"
+                xmlns:wp="http://purl.oclc.org/ooxml/drawingml/wordprocessingDrawing"
+                xmlns:dml="http://purl.oclc.org/ooxml/drawingml/main"
+                xmlns:dpic="http://purl.oclc.org/ooxml/drawingml/picture""#,
+            "dml",
+            "dpic",
+        );
+
+        let document = parse(&strict).unwrap();
+        assert!(matches!(
+            &document.paragraphs[0].children[0],
+            ParagraphChild::Drawing(d) if d.image_id == "rId1"
+        ));
+        assert_eq!(document, parse(&transitional).unwrap());
+    }
+}
@@ -1,43 +1,161 @@
 use crate::elements::*;
 use crate::errors::RudocxError;
+use crate::xml::prefixes::normalize_prefixes;
+use crate::xml::HeaderFooterKind;
 use quick_xml::events::attributes::Attributes;
 use quick_xml::events::Event;
+use quick_xml::name::QName;
 use quick_xml::Reader;
 
 /// Struct to contain the current status of
 struct CurrentData {
+    options: ParseOptions,
     document: Document,
     paragraph: Option<Paragraph>,
     hyperlink: Option<Hyperlink>,
+    field: Option<SimpleField>,
+    comment: Option<Comment>,
+    footnote: Option<Footnote>,
     run: Option<Run>,
     run_properties: Option<RunProperties>,
     in_run_properties: bool,
+    in_text: bool,
+    in_paragraph_properties: bool,
+    in_default_run_properties: bool,
+    paragraph_properties_count: usize,
+    orphaned: usize,
+    table_depth: usize,
+    picture_depth: usize,
+    drawing_depth: usize,
+    drawing: Option<Drawing>,
+    // The current run continues the run split by a drawing, and is dropped if nothing follows the drawing
+    run_after_drawing: bool,
+    header_footer_references: Vec<HeaderFooterReference>,
+    // Depth inside the body `w:sectPr`, whose unsupported children are kept as raw XML
+    section_depth: usize,
+    raw_element_start: Option<usize>,
 }
 impl CurrentData {
-    fn new() -> Self {
+    /// The run properties being read: the ones of the current run, or the default run properties of the paragraph
+    /// inside its first `w:pPr`.
+    fn run_properties_mut(&mut self) -> Option<&mut RunProperties> {
+        if self.in_run_properties {
+            self.run_properties.as_mut()
+        } else if self.in_default_run_properties && self.paragraph_properties_count == 1 {
+            self.paragraph
+                .as_mut()?
+                .properties
+                .default_run_properties
+                .as_mut()
+        } else {
+            None
+        }
+    }
+
+    fn new(options: ParseOptions) -> Self {
         Self {
+            options,
             document: Document::default(),
             paragraph: None,
             hyperlink: None,
+            field: None,
+            comment: None,
+            footnote: None,
             run: None,
             run_properties: None,
             in_run_properties: false,
+            in_text: false,
+            in_paragraph_properties: false,
+            in_default_run_properties: false,
+            paragraph_properties_count: 0,
+            orphaned: 0,
+            table_depth: 0,
+            picture_depth: 0,
+            drawing_depth: 0,
+            drawing: None,
+            run_after_drawing: false,
+            header_footer_references: Vec::new(),
+            section_depth: 0,
+            raw_element_start: None,
         }
     }
 }
 
-///Generate a Document struct from parsing the contents of an OOXML
+/// A `w:headerReference`/`w:footerReference` found in the body `w:sectPr`. The referenced
+/// part has to be resolved through the document relationships.
+pub(crate) struct HeaderFooterReference {
+    pub kind: HeaderFooterKind,
+    pub r#type: HeaderFooterType,
+    pub id: String,
+}
+
+/// Options to tweak how a document is read by [parse_with].
+///
+/// ### Fields
+/// > - **strict:** `bool` - Fail on nonconforming content (e.g. numbers that can't be parsed or are out of range) instead of
+/// >   falling back to the default value of the property.
+/// > - **check_integrity:** `bool` - Fail with `LoadContentMismatch` if content is found outside of a paragraph (e.g. a
+/// >   hyperlink or run misplaced by malformed nesting), instead of silently dropping it.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ParseOptions {
+    pub strict: bool,
+    pub check_integrity: bool,
+}
+
+///Generate a Document struct from parsing the contents of an OOXML. Nonconforming content is
+///read leniently, see [ParseOptions].
 pub fn parse(contents: &str) -> Result<Document, RudocxError> {
-    parse_ooxml(contents)
+    parse_with(contents, ParseOptions::default())
+}
+
+/// Same as [parse], using the given [ParseOptions].
+pub fn parse_with(contents: &str, options: ParseOptions) -> Result<Document, RudocxError> {
+    parse_ooxml(contents, options).map(|(document, _)| document)
+}
+
+/// Read the comments of a `word/comments.xml` part.
+pub(crate) fn parse_comments(
+    contents: &str,
+    options: ParseOptions,
+) -> Result<Vec<Comment>, RudocxError> {
+    parse_ooxml(contents, options).map(|(document, _)| document.comments)
+}
+
+/// Read the footnotes of a `word/footnotes.xml` part, without the separators.
+pub(crate) fn parse_footnotes(
+    contents: &str,
+    options: ParseOptions,
+) -> Result<Vec<Footnote>, RudocxError> {
+    parse_ooxml(contents, options).map(|(document, _)| document.footnotes)
+}
+
+/// Same as [parse], also returning the header and footer references that point to other parts.
+pub(crate) fn parse_with_references(
+    contents: &str,
+    options: ParseOptions,
+) -> Result<(Document, Vec<HeaderFooterReference>), RudocxError> {
+    parse_ooxml(contents, options)
 }
 
-fn parse_ooxml(content: &str) -> Result<Document, RudocxError> {
+fn parse_ooxml(
+    content: &str,
+    options: ParseOptions,
+) -> Result<(Document, Vec<HeaderFooterReference>), RudocxError> {
+    let content = &normalize_prefixes(content)?;
     let mut reader = Reader::from_str(content);
     let mut buf = Vec::new();
-    let mut current_data = CurrentData::new();
+    let mut current_data = CurrentData::new(options);
 
     loop {
-        match reader.read_event_into(&mut buf)? {
+        let position = reader.buffer_position() as usize;
+        let event = reader.read_event_into(&mut buf)?;
+        capture_raw_section_element(
+            &event,
+            content,
+            position..reader.buffer_position() as usize,
+            &mut current_data,
+        );
+        match event {
             //Tag opening. With or without attributes
             Event::Start(e) => handle_open_tag(
                 e.name().as_ref(),
@@ -65,38 +183,310 @@ fn parse_ooxml(content: &str) -> Result<Document, RudocxError> {
         }
         buf.clear();
     }
-    Ok(current_data.document)
+    Ok((current_data.document, current_data.header_footer_references))
 }
 
 fn handle_text(data: &mut CurrentData, text: String) -> Result<(), RudocxError> {
-    if let Some(ref mut r) = data.run {
+    if data.table_depth > 0 || data.picture_depth > 0 || data.drawing_depth > 0 {
+        return Ok(());
+    }
+    //Text outside of a run (e.g. a misplaced `w:t`) has nowhere to go and is dropped, as is the indentation between
+    //the elements of a run
+    if data.in_text
+        && let Some(ref mut r) = data.run
+    {
         r.text.push_str(&text);
     }
     Ok(())
 }
 
+/// Parse a numeric attribute value. Invalid numbers are an `Err` in strict mode and `None` otherwise.
+fn read_number<T: std::str::FromStr<Err = std::num::ParseIntError>>(
+    value: &str,
+    options: &ParseOptions,
+) -> Result<Option<T>, RudocxError> {
+    match value.parse::<T>() {
+        Ok(n) => Ok(Some(n)),
+        Err(e) if options.strict => Err(e.into()),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Read the `w:id` of a comment, its range or its reference, or of a footnote reference.
+fn read_comment_id(
+    attr: &mut Attributes,
+    reader: &Reader<&[u8]>,
+    options: &ParseOptions,
+) -> Result<Option<u32>, RudocxError> {
+    match attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:id") {
+        Some(Ok(a)) => read_number(&a.decode_and_unescape_value(reader.decoder())?, options),
+        _ => Ok(None),
+    }
+}
+
+/// Whether the reader is inside the first `w:pPr` of a paragraph and not in the run properties
+/// of its paragraph mark.
+fn reading_paragraph_properties(data: &CurrentData) -> bool {
+    data.in_paragraph_properties
+        && !data.in_default_run_properties
+        && data.paragraph_properties_count == 1
+}
+
+/// Read an on/off property. The element alone turns it on, `w:val` can turn it off with `off`, `0` or `false`.
+fn read_toggle(attr: &mut Attributes, reader: &Reader<&[u8]>) -> Result<bool, RudocxError> {
+    match attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val") {
+        Some(Ok(a)) => Ok(is_on(&a.decode_and_unescape_value(reader.decoder())?)),
+        _ => Ok(true),
+    }
+}
+
+/// Whether an on/off value is on.
+fn is_on(value: &str) -> bool {
+    !matches!(value, "off" | "0" | "false")
+}
+
+/// Read a `w:tab` of `w:tabs`. A tab stop without position is meaningless and is dropped in lenient mode.
+fn read_paragraph_tab(
+    attr: &mut Attributes,
+    reader: &Reader<&[u8]>,
+    options: &ParseOptions,
+) -> Result<Option<ParagraphTab>, RudocxError> {
+    let (mut val, mut position, mut leader) = (ParagraphTabType::Start, None, None);
+    for a in attr.flatten() {
+        let value = a.decode_and_unescape_value(reader.decoder())?;
+        match a.key.as_ref() {
+            b"w:val" => val = ParagraphTabType::from(value.as_ref()),
+            b"w:pos" => position = read_number(&value, options)?,
+            b"w:leader" => leader = Some(ParagraphTabLeaders::from(value.as_ref())),
+            _ => (),
+        }
+    }
+    match position {
+        Some(position) => Ok(Some(ParagraphTab::new(val, position, leader))),
+        None if options.strict => Err(RudocxError::LoadContentMismatch(String::from(
+            "w:tab without w:pos",
+        ))),
+        None => Ok(None),
+    }
+}
+
+fn read_paragraph_indentation(
+    attr: &mut Attributes,
+    reader: &Reader<&[u8]>,
+    options: &ParseOptions,
+) -> Result<ParagraphIndentation, RudocxError> {
+    let (mut left, mut right, mut first_line, mut hanging) = (None, None, None, None);
+    for a in attr.flatten() {
+        let value = a.decode_and_unescape_value(reader.decoder())?;
+        match a.key.as_ref() {
+            //`start`/`end` are the bidi aware names used by newer producers
+            b"w:left" | b"w:start" => left = read_number(&value, options)?,
+            b"w:right" | b"w:end" => right = read_number(&value, options)?,
+            b"w:firstLine" => first_line = read_number(&value, options)?,
+            b"w:hanging" => hanging = read_number(&value, options)?,
+            _ => (),
+        }
+    }
+    match ParagraphIndentation::new(left, right, first_line, hanging) {
+        Ok(indentation) => Ok(indentation),
+        Err(e) if options.strict => Err(e.into()),
+        //Consumers ignore `firstLine` when `hanging` is also set
+        Err(_) => ParagraphIndentation::new(left, right, None, hanging).map_err(Into::into),
+    }
+}
+
+fn read_frame_properties(
+    attr: &mut Attributes,
+    reader: &Reader<&[u8]>,
+    options: &ParseOptions,
+) -> Result<FrameProperties, RudocxError> {
+    let mut frame = FrameProperties::default();
+    for a in attr.flatten() {
+        let value = a.decode_and_unescape_value(reader.decoder())?;
+        match a.key.as_ref() {
+            b"w:w" => frame.width = read_number(&value, options)?,
+            b"w:h" => frame.height = read_number(&value, options)?,
+            b"w:hRule" => frame.height_rule = Some(FrameHeightRule::from(value.as_ref())),
+            b"w:wrap" => frame.wrap = Some(FrameWrap::from(value.as_ref())),
+            b"w:hAnchor" => frame.h_anchor = Some(FrameAnchor::from(value.as_ref())),
+            b"w:vAnchor" => frame.v_anchor = Some(FrameAnchor::from(value.as_ref())),
+            b"w:x" => frame.x = read_number(&value, options)?,
+            b"w:y" => frame.y = read_number(&value, options)?,
+            _ => (),
+        }
+    }
+    Ok(frame)
+}
+
+fn read_paragraph_spacing(
+    attr: &mut Attributes,
+    reader: &Reader<&[u8]>,
+    options: &ParseOptions,
+) -> Result<ParagraphSpacing, RudocxError> {
+    let mut spacing = ParagraphSpacing::default();
+    for a in attr.flatten() {
+        let value = a.decode_and_unescape_value(reader.decoder())?;
+        match a.key.as_ref() {
+            b"w:before" => spacing.before = read_number(&value, options)?,
+            b"w:after" => spacing.after = read_number(&value, options)?,
+            b"w:line" => spacing.line = read_number(&value, options)?,
+            b"w:lineRule" => spacing.line_rule = Some(LineRule::from(value.as_ref())),
+            b"w:beforeAutospacing" => spacing.before_autospacing = Some(is_on(&value)),
+            b"w:afterAutospacing" => spacing.after_autospacing = Some(is_on(&value)),
+            _ => (),
+        }
+    }
+    Ok(spacing)
+}
+
+fn read_paragraph_shading(
+    attr: &mut Attributes,
+    reader: &Reader<&[u8]>,
+) -> Result<ParagraphShading, RudocxError> {
+    let mut shading = ParagraphShading::default();
+    for a in attr.flatten() {
+        let value = a.decode_and_unescape_value(reader.decoder())?;
+        //`auto` lets the consumer pick the color, which is the same as not setting it
+        let color = match value.as_ref() {
+            "auto" => None,
+            v => Some(HexColor::new(v)),
+        };
+        match a.key.as_ref() {
+            b"w:val" => shading.val = ParagraphShadingValues::from(value.as_ref()),
+            b"w:color" => shading.color = color,
+            b"w:fill" => shading.fill = color,
+            _ => (),
+        }
+    }
+    //The pattern color is meaningless without a pattern, don't carry it around
+    if shading.val == ParagraphShadingValues::Clear {
+        shading.color = None;
+    }
+    Ok(shading)
+}
+
+/// The children of the body `w:sectPr` rudocx reads or regenerates on save.
+const SUPPORTED_SECTION_ELEMENTS: [&[u8]; 4] = [
+    b"w:headerReference",
+    b"w:footerReference",
+    b"w:vAlign",
+    b"w:titlePg",
+];
+
+/// Keep the XML of the unsupported children of the body `w:sectPr`, found at `range` of `content`.
+fn capture_raw_section_element(
+    event: &Event,
+    content: &str,
+    range: std::ops::Range<usize>,
+    data: &mut CurrentData,
+) {
+    let in_body = data.paragraph.is_none() && data.table_depth == 0;
+    //The printer settings point to a binary part through their `r:id`, which isn't kept, so they are dropped
+    //instead of being written back dangling
+    let unsupported = |name: QName| {
+        !SUPPORTED_SECTION_ELEMENTS.contains(&name.as_ref())
+            && name.as_ref() != b"w:printerSettings"
+    };
+    match event {
+        Event::Start(e) if data.section_depth > 0 => {
+            if data.section_depth == 1 && unsupported(e.name()) {
+                data.raw_element_start = Some(range.start);
+            }
+            data.section_depth += 1;
+        }
+        Event::Start(e) if in_body && e.name().as_ref() == b"w:sectPr" => data.section_depth = 1,
+        Event::Empty(e) if data.section_depth == 1 && unsupported(e.name()) => {
+            let raw = content[range].to_string();
+            data.document.section_properties.raw_elements.push(raw);
+        }
+        Event::End(_) if data.section_depth > 0 => {
+            data.section_depth -= 1;
+            if data.section_depth == 1
+                && let Some(start) = data.raw_element_start.take()
+            {
+                let raw = content[start..range.end].to_string();
+                data.document.section_properties.raw_elements.push(raw);
+            }
+        }
+        _ => (),
+    }
+}
+
 fn handle_open_tag(
     tag: &[u8],
     data: &mut CurrentData,
     attr: &mut Attributes,
     reader: &Reader<&[u8]>,
 ) -> Result<(), RudocxError> {
+    //Tables are not supported yet. Their whole content is skipped (nested tables included) so
+    //cell paragraphs don't leak into the body as top-level paragraphs.
+    if tag == b"w:tbl" {
+        data.table_depth += 1;
+        data.document.has_tables = true;
+    }
+    note_tracked_change(tag, data);
+    if data.table_depth > 0 {
+        return Ok(());
+    }
+    //Legacy VML drawings are not supported either. Their content (textboxes included) is skipped,
+    //only the relationship id of the image is kept on the run holding the drawing.
+    if tag == b"w:pict" {
+        data.picture_depth += 1;
+    }
+    if data.picture_depth > 0 {
+        read_picture_id(tag, data, attr, reader)?;
+        return Ok(());
+    }
+    //DrawingML pictures. Only the image and the size are kept, the rest of the content (textboxes
+    //included) is skipped.
+    if tag == b"w:drawing" {
+        if data.drawing_depth == 0 {
+            start_drawing(data);
+        }
+        data.drawing_depth += 1;
+    }
+    if data.drawing_depth > 0 {
+        read_drawing(tag, data, attr, reader)?;
+        return Ok(());
+    }
     match tag {
-        //Plain text
-        b"w:t" => Ok(()),
-        //RunProperties
+        //Plain text. Consumers may trim it unless its whitespace is marked as meaningful
+        b"w:t" => {
+            data.in_text = true;
+            if let Some(ref mut r) = data.run
+                && let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"xml:space")
+            {
+                r.space_preserve = a.decode_and_unescape_value(reader.decoder())? == "preserve";
+            }
+            Ok(())
+        }
+        //RunProperties. The ones nested in paragraph properties format the paragraph mark
+        //and must not reach the run state.
         b"w:rPr" => {
-            data.in_run_properties = true;
+            data.in_run_properties = !data.in_paragraph_properties;
+            data.in_default_run_properties = data.in_paragraph_properties;
+            if data.in_default_run_properties
+                && data.paragraph_properties_count == 1
+                && let Some(ref mut p) = data.paragraph
+            {
+                p.properties.default_run_properties = Some(RunProperties::default());
+            }
+            Ok(())
+        }
+        //ParagraphProperties. Only the first `w:pPr` of a paragraph is honoured, malformed
+        //duplicates are skipped as a whole.
+        b"w:pPr" => {
+            data.in_paragraph_properties = true;
+            data.paragraph_properties_count += 1;
             Ok(())
         }
         //Paragraph
         b"w:p" => {
-            //If current contains a paragraph, take it from the option
-            if let Some(p) = data.paragraph.take() {
-                data.document.paragraphs.push(p);
-            }
+            //If current contains an unclosed paragraph, flush it
+            flush_paragraph(data);
             //Put a default paragraph in the empty option
             data.paragraph = Some(Paragraph::default());
+            data.paragraph_properties_count = 0;
             Ok(())
         }
         //Hyperlink
@@ -104,36 +494,79 @@ fn handle_open_tag(
             //Since hyperlinks are at the same level in the hierarchy as runs, if we
             //encounter a run, we push it and take it out of current to start a hyperlink
             //Hyperlinks cannot be inside hyperlinks.
-            if let Some(r) = data.run.take() {
-                if let Some(ref mut p) = data.paragraph {
-                    p.children.push(ParagraphChild::Run(r));
-                }
+            if let Some(r) = take_run(data) {
+                push_child(data, ParagraphChild::Run(r));
             }
+            //Links to a bookmark of the document have an anchor instead of a relationship
             let mut link = Hyperlink::default();
-            if let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"r:id") {
-                if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
-                    link.id = String::from(v.as_ref())
+            for a in attr.flatten() {
+                let value = a.decode_and_unescape_value(reader.decoder())?;
+                match a.key.as_ref() {
+                    b"r:id" => link.id = Some(value.to_string()),
+                    b"w:anchor" => link.anchor = Some(value.to_string()),
+                    b"w:tooltip" => link.tooltip = Some(value.to_string()),
+                    b"w:history" => link.history = Some(is_on(&value)),
+                    _ => (),
                 }
             }
             data.hyperlink = Some(link);
             Ok(())
         }
-        //Run
-        b"w:r" => {
-            //Check if we have to push to hyperlink or to paragraph
-            if let Some(ref mut h) = data.hyperlink {
-                if let Some(r) = data.run.take() {
-                    h.runs.push(r);
+        //Comment of the comments part. Its paragraphs are read as the ones of the body and moved
+        //to the comment when it closes.
+        b"w:comment" => {
+            flush_paragraph(data);
+            let mut comment = Comment::default();
+            for a in attr.flatten() {
+                let value = a.decode_and_unescape_value(reader.decoder())?;
+                match a.key.as_ref() {
+                    b"w:id" => comment.id = read_number(&value, &data.options)?.unwrap_or_default(),
+                    b"w:author" => comment.author = value.to_string(),
+                    b"w:date" => comment.date = Some(value.to_string()),
+                    _ => (),
                 }
-            } else {
-                if let Some(r) = data.run.take() {
-                    if let Some(ref mut p) = data.paragraph {
-                        p.children.push(ParagraphChild::Run(r));
+            }
+            data.comment = Some(comment);
+            Ok(())
+        }
+        //Footnote of the footnotes part, read as comments. Separators are not footnotes and are dropped
+        //when they close.
+        b"w:footnote" => {
+            flush_paragraph(data);
+            let mut footnote = Some(Footnote::default());
+            for a in attr.flatten() {
+                let value = a.decode_and_unescape_value(reader.decoder())?;
+                match a.key.as_ref() {
+                    b"w:id" => {
+                        if let Some(ref mut f) = footnote {
+                            f.id = read_number(&value, &data.options)?.unwrap_or_default()
+                        }
                     }
+                    b"w:type" if value.as_ref() != "normal" => footnote = None,
+                    _ => (),
                 }
             }
+            data.footnote = footnote;
+            Ok(())
+        }
+        //Simple field
+        b"w:fldSimple" => {
+            //Same as hyperlinks, fields are at the same level as runs
+            if let Some(r) = take_run(data) {
+                push_child(data, ParagraphChild::Run(r));
+            }
+            data.field = Some(read_simple_field(attr, reader));
+            Ok(())
+        }
+        //Run
+        b"w:r" => {
+            //A run left open by malformed content is closed before starting the new one
+            if let Some(r) = take_run(data) {
+                push_run(data, r);
+            }
             data.run_properties = Some(RunProperties::default());
             data.run = Some(Run::default());
+            data.run_after_drawing = false;
             Ok(())
         }
         _ => Ok(()),
@@ -146,49 +579,343 @@ fn handle_empty_tag(
     attr: &mut Attributes,
     reader: &Reader<&[u8]>,
 ) -> Result<(), RudocxError> {
+    note_tracked_change(tag, data);
+    if data.table_depth > 0 {
+        return Ok(());
+    }
+    let options = data.options;
+    if data.picture_depth > 0 {
+        read_picture_id(tag, data, attr, reader)?;
+        return Ok(());
+    }
+    if data.drawing_depth > 0 {
+        read_drawing(tag, data, attr, reader)?;
+        return Ok(());
+    }
     match tag {
+        //Simple field without cached result
+        b"w:fldSimple" => {
+            push_child(
+                data,
+                ParagraphChild::SimpleField(read_simple_field(attr, reader)),
+            );
+            Ok(())
+        }
+        //Empty paragraph
+        b"w:p" => {
+            flush_paragraph(data);
+            data.document.paragraphs.push(Paragraph::default());
+            Ok(())
+        }
+        //Comment ranges. Only the ones at paragraph level are kept
+        b"w:commentRangeStart" | b"w:commentRangeEnd" => {
+            if data.hyperlink.is_none()
+                && data.field.is_none()
+                && let Some(id) = read_comment_id(attr, reader, &data.options)?
+                && let Some(ref mut p) = data.paragraph
+            {
+                p.children.push(match tag {
+                    b"w:commentRangeStart" => ParagraphChild::CommentRangeStart(id),
+                    _ => ParagraphChild::CommentRangeEnd(id),
+                });
+            }
+            Ok(())
+        }
+        //Break in a run. Breaks are placed before the text of a run, so content before the break ends the run and the
+        //rest goes on in a new run with the same properties
+        b"w:br" => {
+            if data.run.as_ref().is_some_and(|r| !is_blank(r)) {
+                split_run(data);
+            }
+            if let Some(ref mut r) = data.run {
+                r.break_type = Some(
+                    match attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:type") {
+                        Some(Ok(a)) => {
+                            BreakType::from(a.decode_and_unescape_value(reader.decoder())?.as_ref())
+                        }
+                        _ => BreakType::TextWrapping,
+                    },
+                );
+            }
+            Ok(())
+        }
+        //Mark numbering a footnote at the start of its content. It's written back with the footnote,
+        //so the run holding it is dropped
+        b"w:footnoteRef" => {
+            data.run = None;
+            data.run_properties = None;
+            Ok(())
+        }
+        //Comment and footnote marks
+        b"w:commentReference" | b"w:footnoteReference" => {
+            let id = read_comment_id(attr, reader, &data.options)?;
+            if let Some(ref mut r) = data.run {
+                match tag {
+                    b"w:commentReference" => r.comment_reference = id,
+                    _ => r.footnote_reference = id,
+                }
+            }
+            Ok(())
+        }
+        //Empty paragraph properties still count as the first `w:pPr` of the paragraph
+        b"w:pPr" => {
+            data.paragraph_properties_count += 1;
+            Ok(())
+        }
+        //Paragraph tab stop. Tabs inside runs are characters, not stops
+        b"w:tab" => {
+            if reading_paragraph_properties(data) {
+                let tab = read_paragraph_tab(attr, reader, &data.options)?;
+                if let Some(ref mut p) = data.paragraph
+                    && let Some(tab) = tab
+                {
+                    p.properties.tabs.push(tab);
+                }
+            }
+            Ok(())
+        }
+        //Paragraph indentation
+        b"w:ind" => {
+            if reading_paragraph_properties(data) {
+                let indentation = read_paragraph_indentation(attr, reader, &data.options)?;
+                if let Some(ref mut p) = data.paragraph {
+                    p.properties.indentation = Some(indentation);
+                }
+            }
+            Ok(())
+        }
+        //Paragraph kept on the page of the next one, or kept in a single page. Explicitly off is kept
+        //since it overrides the style
+        b"w:keepNext" | b"w:keepLines" => {
+            if reading_paragraph_properties(data) {
+                let value = read_toggle(attr, reader)?;
+                if let Some(ref mut p) = data.paragraph {
+                    match tag {
+                        b"w:keepNext" => p.properties.keep_next = Some(value),
+                        _ => p.properties.keep_lines = Some(value),
+                    }
+                }
+            }
+            Ok(())
+        }
+        //Paragraph starting on a new page
+        b"w:pageBreakBefore" => {
+            if reading_paragraph_properties(data) {
+                let value = read_toggle(attr, reader)?;
+                if let Some(ref mut p) = data.paragraph {
+                    p.properties.page_break_before = value;
+                }
+            }
+            Ok(())
+        }
+        //East Asian line breaking
+        b"w:kinsoku" | b"w:overflowPunct" => {
+            if reading_paragraph_properties(data) {
+                let value = read_toggle(attr, reader)?;
+                if let Some(ref mut p) = data.paragraph {
+                    match tag {
+                        b"w:kinsoku" => p.properties.kinsoku = value,
+                        _ => p.properties.overflow_punct = value,
+                    }
+                }
+            }
+            Ok(())
+        }
+        //Paragraph justification
+        b"w:jc" => {
+            if reading_paragraph_properties(data)
+                && let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
+            {
+                let v = a.decode_and_unescape_value(reader.decoder())?;
+                let justification = match check_justification(&v) {
+                    Ok(justification) => Some(justification),
+                    Err(e) if data.options.strict => return Err(e.into()),
+                    Err(_) => None,
+                };
+                if let Some(ref mut p) = data.paragraph {
+                    p.properties.justification = justification;
+                }
+            }
+            Ok(())
+        }
+        //Paragraph text flow
+        b"w:textDirection" | b"w:textAlignment" | b"w:textboxTightWrap" => {
+            if reading_paragraph_properties(data)
+                && let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
+                && let Some(ref mut p) = data.paragraph
+            {
+                let v = a.decode_and_unescape_value(reader.decoder())?;
+                match tag {
+                    b"w:textDirection" => {
+                        p.properties.text_direction = Some(ParagraphTextDirValues::from(v))
+                    }
+                    b"w:textAlignment" => {
+                        p.properties.text_alignment = Some(ParagraphTextAlignValues::from(v))
+                    }
+                    _ => {
+                        p.properties.textbox_tight_wrap =
+                            Some(ParagraphTBoxTightWrapValues::from(v))
+                    }
+                }
+            }
+            Ok(())
+        }
+        //Paragraph outline level
+        b"w:outlineLvl" => {
+            if reading_paragraph_properties(data)
+                && let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
+            {
+                let v = a.decode_and_unescape_value(reader.decoder())?;
+                let mut level = read_number::<u8>(&v, &data.options)?;
+                if let Some(l) = level
+                    && let Err(e) = check_outline_level(l)
+                {
+                    if data.options.strict {
+                        return Err(e.into());
+                    }
+                    level = None;
+                }
+                if let Some(ref mut p) = data.paragraph {
+                    p.properties.outline_level = level;
+                }
+            }
+            Ok(())
+        }
+        //Id of the HTML div of documents converted from web pages
+        b"w:divId" => {
+            if reading_paragraph_properties(data)
+                && let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
+            {
+                let v = a.decode_and_unescape_value(reader.decoder())?;
+                let div_id = read_number::<u32>(&v, &data.options)?;
+                if let Some(ref mut p) = data.paragraph {
+                    p.properties.div_id = div_id;
+                }
+            }
+            Ok(())
+        }
+        b"w:pStyle" => {
+            if reading_paragraph_properties(data)
+                && let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
+                && let Some(ref mut p) = data.paragraph
+            {
+                p.properties.style =
+                    Some(a.decode_and_unescape_value(reader.decoder())?.to_string());
+            }
+            Ok(())
+        }
+        //Table conditional formatting, kept as the raw bit string
+        b"w:cnfStyle" => {
+            if reading_paragraph_properties(data)
+                && let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
+                && let Some(ref mut p) = data.paragraph
+            {
+                p.properties.conditional_formatting =
+                    Some(a.decode_and_unescape_value(reader.decoder())?.to_string());
+            }
+            Ok(())
+        }
+        //Paragraph text frame
+        b"w:framePr" => {
+            if reading_paragraph_properties(data) {
+                let frame = read_frame_properties(attr, reader, &data.options)?;
+                if let Some(ref mut p) = data.paragraph {
+                    p.properties.frame = Some(frame);
+                }
+            }
+            Ok(())
+        }
+        //Paragraph shading
+        b"w:shd" => {
+            if reading_paragraph_properties(data) {
+                let shading = read_paragraph_shading(attr, reader)?;
+                if let Some(ref mut p) = data.paragraph {
+                    p.properties.shading = Some(shading);
+                }
+            }
+            Ok(())
+        }
+        //Vertical alignment of the pages of the section
+        b"w:vAlign" => {
+            if !data.in_paragraph_properties
+                && let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
+                && let Ok(v) = a.decode_and_unescape_value(reader.decoder())
+            {
+                data.document.section_properties.vertical_alignment =
+                    Some(PageVAlign::from(v.as_ref()));
+            }
+            Ok(())
+        }
+        //Header and footer references of the section
+        b"w:headerReference" | b"w:footerReference" => {
+            //References of the other sections, in a paragraph `w:sectPr`, are dropped with their section
+            if data.in_paragraph_properties {
+                return Ok(());
+            }
+            let kind = match tag {
+                b"w:headerReference" => HeaderFooterKind::Header,
+                _ => HeaderFooterKind::Footer,
+            };
+            let mut r#type = HeaderFooterType::Default;
+            let mut id = None;
+            for a in attr.flatten() {
+                match a.key.as_ref() {
+                    b"w:type" => {
+                        r#type = HeaderFooterType::from(
+                            a.decode_and_unescape_value(reader.decoder())?.as_ref(),
+                        )
+                    }
+                    b"r:id" => {
+                        id = Some(a.decode_and_unescape_value(reader.decoder())?.to_string())
+                    }
+                    _ => (),
+                }
+            }
+            if let Some(id) = id {
+                data.header_footer_references
+                    .push(HeaderFooterReference { kind, r#type, id });
+            }
+            Ok(())
+        }
         //TODO: Error for !data.in_run_properties
         //Bold
         b"w:b" => {
-            if data.in_run_properties {
-                if let Some(ref mut p) = data.run_properties {
-                    p.bold = true;
-                }
+            if let Some(p) = data.run_properties_mut() {
+                p.bold = true;
             }
             Ok(())
         }
         //Italic
         b"w:i" => {
-            if data.in_run_properties {
-                if let Some(ref mut p) = data.run_properties {
-                    p.italic = true;
-                }
+            if let Some(p) = data.run_properties_mut() {
+                p.italic = true;
             }
             Ok(())
         }
         //Underline
         b"w:u" => {
-            if data.in_run_properties {
-                if let Some(ref mut p) = data.run_properties {
-                    if let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
-                    {
-                        if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
-                            p.underline = Some(Underline::new(UnderlineStyle::from(v.as_ref())));
-                        }
-                    }
-                }
+            if let Some(p) = data.run_properties_mut()
+                && let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
+                && let Ok(v) = a.decode_and_unescape_value(reader.decoder())
+            {
+                p.underline = Some(Underline::new(UnderlineStyle::from(v.as_ref())));
             }
             Ok(())
         }
         //Font color
         b"w:color" => {
-            if data.in_run_properties {
-                if let Some(ref mut p) = data.run_properties {
-                    if let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
-                    {
-                        if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
-                            p.color = Some(HexColor::new(v.as_ref()));
+            if let Some(p) = data.run_properties_mut() {
+                for a in attr.flatten() {
+                    let v = a.decode_and_unescape_value(reader.decoder())?;
+                    match a.key.as_ref() {
+                        //`auto` lets the consumer pick the color
+                        b"w:val" if v.as_ref() != "auto" => {
+                            p.color = Some(HexColor::new(v.as_ref()))
                         }
+                        b"w:themeColor" => p.theme_color = Some(v.to_string()),
+                        b"w:themeTint" => p.theme_tint = Some(v.to_string()),
+                        b"w:themeShade" => p.theme_shade = Some(v.to_string()),
+                        _ => (),
                     }
                 }
             }
@@ -196,145 +923,150 @@ fn handle_empty_tag(
         }
         //Font size
         b"w:sz" => {
-            if data.in_run_properties {
-                if let Some(ref mut p) = data.run_properties {
-                    if let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
-                    {
-                        if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
-                            p.size = Some(v.parse::<u32>()?);
-                        }
-                    }
-                }
+            if let Some(p) = data.run_properties_mut()
+                && let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
+                && let Ok(v) = a.decode_and_unescape_value(reader.decoder())
+            {
+                p.size = read_number(&v, &options)?;
             }
             Ok(())
         }
         //Fonts (get ready this is a big one)
         b"w:rFonts" => {
-            if data.in_run_properties {
-                if let Some(ref mut p) = data.run_properties {
-                    for r in attr {
-                        if let Ok(a) = r {
-                            match a.key.as_ref() {
-                                b"w:hint" => {
-                                    if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
-                                        if p.font.is_some() {
-                                            let mut fonts = p.clone().font.unwrap();
-                                            fonts.hint = FontType::from(v.as_ref());
-                                            p.font = Some(fonts);
-                                        } else {
-                                            let mut fonts = FontSet::default();
-                                            fonts.hint = FontType::from(v.as_ref());
-                                            p.font = Some(fonts);
-                                        }
-                                    }
+            if let Some(p) = data.run_properties_mut() {
+                //An empty `w:rFonts` is still meaningful, it resets the fonts to the theme ones
+                if p.font.is_none() {
+                    p.font = Some(FontSet::theme_default());
+                }
+                for a in attr.flatten() {
+                    match a.key.as_ref() {
+                        b"w:hint" => {
+                            if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
+                                if p.font.is_some() {
+                                    let mut fonts = p.clone().font.unwrap();
+                                    fonts.hint = FontType::from(v.as_ref());
+                                    p.font = Some(fonts);
+                                } else {
+                                    p.font = Some(FontSet {
+                                        hint: FontType::from(v.as_ref()),
+                                        ..Default::default()
+                                    });
                                 }
-                                b"w:ascii" => {
-                                    if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
-                                        if p.font.is_some() {
-                                            let mut fonts = p.clone().font.unwrap();
-                                            fonts.ascii = Some(v.to_string());
-                                            p.font = Some(fonts);
-                                        } else {
-                                            let mut fonts = FontSet::default();
-                                            fonts.ascii = Some(v.to_string());
-                                            p.font = Some(fonts);
-                                        }
-                                    }
+                            }
+                        }
+                        b"w:ascii" => {
+                            if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
+                                if p.font.is_some() {
+                                    let mut fonts = p.clone().font.unwrap();
+                                    fonts.ascii = Some(v.to_string());
+                                    p.font = Some(fonts);
+                                } else {
+                                    p.font = Some(FontSet {
+                                        ascii: Some(v.to_string()),
+                                        ..Default::default()
+                                    });
                                 }
-                                b"w:hiAnsi" => {
-                                    if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
-                                        if p.font.is_some() {
-                                            let mut fonts = p.clone().font.unwrap();
-                                            fonts.hi_ansi = Some(v.to_string());
-                                            p.font = Some(fonts);
-                                        } else {
-                                            let mut fonts = FontSet::default();
-                                            fonts.hi_ansi = Some(v.to_string());
-                                            p.font = Some(fonts);
-                                        }
-                                    }
+                            }
+                        }
+                        b"w:hAnsi" | b"w:hiAnsi" => {
+                            if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
+                                if p.font.is_some() {
+                                    let mut fonts = p.clone().font.unwrap();
+                                    fonts.hi_ansi = Some(v.to_string());
+                                    p.font = Some(fonts);
+                                } else {
+                                    p.font = Some(FontSet {
+                                        hi_ansi: Some(v.to_string()),
+                                        ..Default::default()
+                                    });
                                 }
-                                b"w:eastAsia" => {
-                                    if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
-                                        if p.font.is_some() {
-                                            let mut fonts = p.clone().font.unwrap();
-                                            fonts.east_asia = Some(v.to_string());
-                                            p.font = Some(fonts);
-                                        } else {
-                                            let mut fonts = FontSet::default();
-                                            fonts.east_asia = Some(v.to_string());
-                                            p.font = Some(fonts);
-                                        }
-                                    }
+                            }
+                        }
+                        b"w:eastAsia" => {
+                            if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
+                                if p.font.is_some() {
+                                    let mut fonts = p.clone().font.unwrap();
+                                    fonts.east_asia = Some(v.to_string());
+                                    p.font = Some(fonts);
+                                } else {
+                                    p.font = Some(FontSet {
+                                        east_asia: Some(v.to_string()),
+                                        ..Default::default()
+                                    });
                                 }
-                                b"w:cs" => {
-                                    if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
-                                        if p.font.is_some() {
-                                            let mut fonts = p.clone().font.unwrap();
-                                            fonts.cs = Some(v.to_string());
-                                            p.font = Some(fonts);
-                                        } else {
-                                            let mut fonts = FontSet::default();
-                                            fonts.cs = Some(v.to_string());
-                                            p.font = Some(fonts);
-                                        }
-                                    }
+                            }
+                        }
+                        b"w:cs" => {
+                            if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
+                                if p.font.is_some() {
+                                    let mut fonts = p.clone().font.unwrap();
+                                    fonts.cs = Some(v.to_string());
+                                    p.font = Some(fonts);
+                                } else {
+                                    p.font = Some(FontSet {
+                                        cs: Some(v.to_string()),
+                                        ..Default::default()
+                                    });
                                 }
-                                b"w:asciiTheme" => {
-                                    if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
-                                        if p.font.is_some() {
-                                            let mut fonts = p.clone().font.unwrap();
-                                            fonts.ascii_theme = Some(v.to_string());
-                                            p.font = Some(fonts);
-                                        } else {
-                                            let mut fonts = FontSet::default();
-                                            fonts.ascii_theme = Some(v.to_string());
-                                            p.font = Some(fonts);
-                                        }
-                                    }
+                            }
+                        }
+                        b"w:asciiTheme" => {
+                            if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
+                                if p.font.is_some() {
+                                    let mut fonts = p.clone().font.unwrap();
+                                    fonts.ascii_theme = Some(v.to_string());
+                                    p.font = Some(fonts);
+                                } else {
+                                    p.font = Some(FontSet {
+                                        ascii_theme: Some(v.to_string()),
+                                        ..Default::default()
+                                    });
                                 }
-                                b"w:hiAnsiTheme" => {
-                                    if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
-                                        if p.font.is_some() {
-                                            let mut fonts = p.clone().font.unwrap();
-                                            fonts.hi_ansi_theme = Some(v.to_string());
-                                            p.font = Some(fonts);
-                                        } else {
-                                            let mut fonts = FontSet::default();
-                                            fonts.hi_ansi_theme = Some(v.to_string());
-                                            p.font = Some(fonts);
-                                        }
-                                    }
+                            }
+                        }
+                        b"w:hAnsiTheme" | b"w:hiAnsiTheme" => {
+                            if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
+                                if p.font.is_some() {
+                                    let mut fonts = p.clone().font.unwrap();
+                                    fonts.hi_ansi_theme = Some(v.to_string());
+                                    p.font = Some(fonts);
+                                } else {
+                                    p.font = Some(FontSet {
+                                        hi_ansi_theme: Some(v.to_string()),
+                                        ..Default::default()
+                                    });
                                 }
-                                b"w:eastAsiaTheme" => {
-                                    if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
-                                        if p.font.is_some() {
-                                            let mut fonts = p.clone().font.unwrap();
-                                            fonts.east_asia_theme = Some(v.to_string());
-                                            p.font = Some(fonts);
-                                        } else {
-                                            let mut fonts = FontSet::default();
-                                            fonts.east_asia_theme = Some(v.to_string());
-                                            p.font = Some(fonts);
-                                        }
-                                    }
+                            }
+                        }
+                        b"w:eastAsiaTheme" => {
+                            if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
+                                if p.font.is_some() {
+                                    let mut fonts = p.clone().font.unwrap();
+                                    fonts.east_asia_theme = Some(v.to_string());
+                                    p.font = Some(fonts);
+                                } else {
+                                    p.font = Some(FontSet {
+                                        east_asia_theme: Some(v.to_string()),
+                                        ..Default::default()
+                                    });
                                 }
-                                b"w:csTheme" => {
-                                    if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
-                                        if p.font.is_some() {
-                                            let mut fonts = p.clone().font.unwrap();
-                                            fonts.cs_theme = Some(v.to_string());
-                                            p.font = Some(fonts);
-                                        } else {
-                                            let mut fonts = FontSet::default();
-                                            fonts.cs_theme = Some(v.to_string());
-                                            p.font = Some(fonts);
-                                        }
-                                    }
+                            }
+                        }
+                        b"w:cstheme" | b"w:csTheme" => {
+                            if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
+                                if p.font.is_some() {
+                                    let mut fonts = p.clone().font.unwrap();
+                                    fonts.cs_theme = Some(v.to_string());
+                                    p.font = Some(fonts);
+                                } else {
+                                    p.font = Some(FontSet {
+                                        cs_theme: Some(v.to_string()),
+                                        ..Default::default()
+                                    });
                                 }
-                                _ => (),
                             }
                         }
+                        _ => (),
                     }
                 }
             }
@@ -342,155 +1074,567 @@ fn handle_empty_tag(
         }
         //Highlighting
         b"w:highlight" => {
-            if data.in_run_properties {
-                if let Some(ref mut p) = data.run_properties {
-                    if let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
-                    {
-                        if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
-                            p.highlight = Some(HLColor::new(HighlightPalette::from(v.as_ref())));
-                        }
-                    }
+            if let Some(p) = data.run_properties_mut()
+                && let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
+                && let Ok(v) = a.decode_and_unescape_value(reader.decoder())
+            {
+                p.highlight = Some(match v.as_ref() {
+                    //Explicitly cleared highlight, not a color
+                    "none" => HLColor { value: None },
+                    v => HLColor::new(HighlightPalette::from(v)),
+                });
+            }
+            Ok(())
+        }
+        //Striked text
+        b"w:strike" => {
+            if let Some(p) = data.run_properties_mut() {
+                p.strike = true;
+            }
+            Ok(())
+        }
+        //Double striked text
+        b"w:dstrike" => {
+            if let Some(p) = data.run_properties_mut() {
+                p.dstrike = true;
+            }
+            Ok(())
+        }
+        //No spelling or grammar checking
+        b"w:noProof" => {
+            let value = read_toggle(attr, reader)?;
+            if let Some(p) = data.run_properties_mut() {
+                p.no_proof = value;
+            }
+            Ok(())
+        }
+        //Right to left text
+        b"w:rtl" => {
+            let value = read_toggle(attr, reader)?;
+            if let Some(p) = data.run_properties_mut() {
+                p.rtl = value;
+            }
+            Ok(())
+        }
+        //Character style
+        b"w:rStyle" => {
+            if let Some(p) = data.run_properties_mut()
+                && let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
+            {
+                p.style = Some(a.decode_and_unescape_value(reader.decoder())?.to_string());
+            }
+            Ok(())
+        }
+        //Proofing language
+        b"w:lang" => {
+            if let Some(p) = data.run_properties_mut()
+                && let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
+            {
+                p.lang = Some(a.decode_and_unescape_value(reader.decoder())?.to_string());
+            }
+            Ok(())
+        }
+        //Vertical alignment
+        b"w:vertAlign" => {
+            if let Some(p) = data.run_properties_mut()
+                && let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
+                && let Ok(v) = a.decode_and_unescape_value(reader.decoder())
+            {
+                p.valign = Some(VerticalAlign::new(AlignValues::from(v.as_ref())));
+            }
+            Ok(())
+        }
+        //Paragraph spacing
+        b"w:spacing" if reading_paragraph_properties(data) => {
+            let spacing = read_paragraph_spacing(attr, reader, &options)?;
+            if let Some(ref mut p) = data.paragraph {
+                p.properties.spacing = Some(spacing);
+            }
+            Ok(())
+        }
+        //Spacing
+        b"w:spacing" => {
+            if let Some(p) = data.run_properties_mut()
+                && let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
+                && let Ok(v) = a.decode_and_unescape_value(reader.decoder())
+            {
+                p.spacing = read_number(&v, &options)?;
+            }
+            Ok(())
+        }
+        //Baseline position
+        b"w:position" => {
+            if let Some(p) = data.run_properties_mut()
+                && let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
+                && let Ok(v) = a.decode_and_unescape_value(reader.decoder())
+            {
+                p.position = read_number(&v, &options)?;
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn handle_close_tag(tag: &[u8], data: &mut CurrentData) -> Result<(), RudocxError> {
+    if data.table_depth > 0 {
+        if tag == b"w:tbl" {
+            data.table_depth -= 1;
+        }
+        return Ok(());
+    }
+    if data.picture_depth > 0 {
+        if tag == b"w:pict" {
+            data.picture_depth -= 1;
+        }
+        return Ok(());
+    }
+    if data.drawing_depth > 0 {
+        if tag == b"w:drawing" {
+            data.drawing_depth -= 1;
+            if data.drawing_depth == 0
+                && let Some(d) = data.drawing.take()
+                && !d.image_id.is_empty()
+            {
+                push_drawing(data, d);
+            }
+        }
+        return Ok(());
+    }
+    match tag {
+        //Text
+        b"w:t" => {
+            data.in_text = false;
+            Ok(())
+        }
+        //Run Properties
+        b"w:rPr" => {
+            data.in_run_properties = false;
+            data.in_default_run_properties = false;
+            Ok(())
+        }
+        //Paragraph Properties
+        b"w:pPr" => {
+            data.in_paragraph_properties = false;
+            Ok(())
+        }
+        //Paragraph
+        b"w:p" => {
+            flush_paragraph(data);
+            Ok(())
+        }
+        //Comment
+        b"w:comment" => {
+            flush_paragraph(data);
+            if let Some(mut c) = data.comment.take() {
+                c.paragraphs = std::mem::take(&mut data.document.paragraphs);
+                data.document.comments.push(c);
+            }
+            Ok(())
+        }
+        //Footnote
+        b"w:footnote" => {
+            flush_paragraph(data);
+            let paragraphs = std::mem::take(&mut data.document.paragraphs);
+            if let Some(mut f) = data.footnote.take() {
+                f.paragraphs = paragraphs;
+                data.document.footnotes.push(f);
+            }
+            Ok(())
+        }
+        //Hyperlink
+        b"w:hyperlink" => {
+            if let Some(mut h) = data.hyperlink.take() {
+                if let Some(r) = take_run(data) {
+                    h.runs.push(r);
+                }
+                push_child(data, ParagraphChild::Hyperlink(h));
+            }
+            data.hyperlink = None;
+            Ok(())
+        }
+        //Simple field
+        b"w:fldSimple" => {
+            if let Some(mut f) = data.field.take() {
+                if let Some(r) = take_run(data) {
+                    f.runs.push(r);
+                }
+                push_child(data, ParagraphChild::SimpleField(f));
+            }
+            Ok(())
+        }
+        //Run
+        b"w:r" => {
+            let after_drawing = std::mem::take(&mut data.run_after_drawing);
+            if let Some(r) = take_run(data)
+                && !(after_drawing && is_blank(&r))
+            {
+                push_run(data, r);
+            }
+            data.run = None;
+            data.run_properties = None;
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn read_simple_field(attr: &mut Attributes, reader: &Reader<&[u8]>) -> SimpleField {
+    let mut field = SimpleField::default();
+    if let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:instr")
+        && let Ok(v) = a.decode_and_unescape_value(reader.decoder())
+    {
+        field.instruction = v.to_string();
+    }
+    field
+}
+
+/// Note that the document has tracked changes. Revisions are not part of the model: inserted content is read as
+/// regular content and deleted content is dropped.
+fn note_tracked_change(tag: &[u8], data: &mut CurrentData) {
+    if matches!(tag, b"w:ins" | b"w:del" | b"w:moveFrom" | b"w:moveTo") {
+        data.document.has_tracked_changes = true;
+    }
+}
+
+/// Keep the relationship id of the image of a legacy VML drawing (`v:imagedata r:id="..."`) on
+/// the current run.
+fn read_picture_id(
+    tag: &[u8],
+    data: &mut CurrentData,
+    attr: &mut Attributes,
+    reader: &Reader<&[u8]>,
+) -> Result<(), RudocxError> {
+    if tag == b"v:imagedata"
+        && let Some(ref mut r) = data.run
+        && r.picture_id.is_none()
+        && let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"r:id")
+    {
+        r.picture_id = Some(a.decode_and_unescape_value(reader.decoder())?.to_string());
+    }
+    Ok(())
+}
+
+/// Start reading a drawing. Drawings inside hyperlinks and fields are not supported and are skipped.
+fn start_drawing(data: &mut CurrentData) {
+    if data.hyperlink.is_some() || data.field.is_some() {
+        return;
+    }
+    data.drawing = Some(Drawing::default());
+}
+
+/// Push a drawing showing an image. Drawings are paragraph children, so the run holding it is split around it: the
+/// content before the drawing is pushed first, unless it's blank, and the content after it goes on in a new run with
+/// the same properties.
+fn push_drawing(data: &mut CurrentData, drawing: Drawing) {
+    let properties = data.run_properties.clone();
+    let in_run = data.run.is_some();
+    if let Some(r) = take_run(data)
+        && !is_blank(&r)
+    {
+        push_child(data, ParagraphChild::Run(r));
+    }
+    push_child(data, ParagraphChild::Drawing(drawing));
+    if in_run {
+        data.run = Some(Run::default());
+        data.run_properties = properties;
+        data.run_after_drawing = true;
+    }
+}
+
+/// Push the current run, continuing its content in a new run with the same properties.
+fn split_run(data: &mut CurrentData) {
+    let properties = data.run_properties.clone();
+    if let Some(r) = take_run(data) {
+        push_run(data, r);
+    }
+    data.run = Some(Run::default());
+    data.run_properties = properties;
+}
+
+/// Whether a run shows nothing: no text, break, mark or picture.
+fn is_blank(run: &Run) -> bool {
+    run.text.is_empty()
+        && run.break_type.is_none()
+        && run.comment_reference.is_none()
+        && run.footnote_reference.is_none()
+        && run.picture_id.is_none()
+}
+
+/// Keep the size (`wp:extent cx="..." cy="..."`) and the image relationship id (`a:blip r:embed="..."`) of the
+/// current drawing.
+fn read_drawing(
+    tag: &[u8],
+    data: &mut CurrentData,
+    attr: &mut Attributes,
+    reader: &Reader<&[u8]>,
+) -> Result<(), RudocxError> {
+    let Some(ref mut d) = data.drawing else {
+        return Ok(());
+    };
+    for a in attr.flatten() {
+        let value = a.decode_and_unescape_value(reader.decoder())?;
+        match (tag, a.key.as_ref()) {
+            (b"wp:extent", b"cx") => {
+                d.width = read_number(&value, &data.options)?.unwrap_or_default()
+            }
+            (b"wp:extent", b"cy") => {
+                d.height = read_number(&value, &data.options)?.unwrap_or_default()
+            }
+            (b"a:blip", b"r:embed") => d.image_id = value.to_string(),
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+/// Push a child to the current paragraph. Content found outside of a paragraph can't be kept and is counted as
+/// orphaned, see [ParseOptions::check_integrity].
+fn push_child(data: &mut CurrentData, child: ParagraphChild) {
+    match data.paragraph {
+        Some(ref mut p) => p.children.push(child),
+        None => data.orphaned += 1,
+    }
+}
+
+/// Push a run to the hyperlink or field it is in, or to the current paragraph otherwise, so that the children
+/// of the paragraph keep the order of the source.
+fn push_run(data: &mut CurrentData, run: Run) {
+    if let Some(ref mut h) = data.hyperlink {
+        h.runs.push(run);
+    } else if let Some(ref mut f) = data.field {
+        f.runs.push(run);
+    } else {
+        push_child(data, ParagraphChild::Run(run));
+    }
+}
+
+/// Take the pending run out of the current data, moving the pending run properties onto it so
+/// they can never be inherited by the next run.
+fn take_run(data: &mut CurrentData) -> Option<Run> {
+    let mut run = data.run.take()?;
+    if let Some(rp) = data.run_properties.take() {
+        run.properties = rp;
+    }
+    //Footnote marks are written with the footnote reference style when they have none
+    if run.footnote_reference.is_some()
+        && run.properties.style.as_deref() == Some(Style::FOOTNOTE_REFERENCE)
+    {
+        run.properties.style = None;
+    }
+    Some(run)
+}
+
+/// Push the current paragraph to the document, along with the hyperlink, field and run still pending in it.
+fn flush_paragraph(data: &mut CurrentData) {
+    let Some(mut p) = data.paragraph.take() else {
+        return;
+    };
+    let run = take_run(data);
+    if let Some(mut h) = data.hyperlink.take() {
+        h.runs.extend(run);
+        p.children.push(ParagraphChild::Hyperlink(h));
+    } else if let Some(mut f) = data.field.take() {
+        f.runs.extend(run);
+        p.children.push(ParagraphChild::SimpleField(f));
+    } else if let Some(r) = run {
+        p.children.push(ParagraphChild::Run(r));
+    }
+    data.document.paragraphs.push(p);
+}
+
+fn handle_eof(data: &mut CurrentData) -> Result<(), RudocxError> {
+    //Well-formed documents close every paragraph, anything left is truncated content
+    flush_paragraph(data);
+    if data.options.check_integrity && data.orphaned > 0 {
+        return Err(RudocxError::LoadContentMismatch(format!(
+            "{} elements found outside of a paragraph",
+            data.orphaned
+        )));
+    }
+    Ok(())
+}
+
+/// Contents of a `word/styles.xml` part.
+#[derive(Debug, Default)]
+pub(crate) struct StylesPart {
+    pub default_font: Option<String>,
+    pub default_size: Option<u32>,
+    pub styles: Vec<Style>,
+}
+
+/// Read a `word/styles.xml` part: the font (its `w:ascii`, or `w:hAnsi` if there is none) and the size of
+/// `w:rPrDefault`, and the style definitions. The conditional formatting of table styles (`w:tblStylePr`) is skipped.
+pub(crate) fn parse_styles(
+    contents: &str,
+    options: ParseOptions,
+) -> Result<StylesPart, RudocxError> {
+    let contents = &normalize_prefixes(contents)?;
+    let mut reader = Reader::from_str(contents);
+    let mut buf = Vec::new();
+    let mut part = StylesPart::default();
+    let mut in_run_properties_default = false;
+    let mut style: Option<Style> = None;
+    //The formatting of the current style is read as the one of a paragraph and a run, by the document reader
+    let mut formatting = CurrentData::new(options);
+    //Depth inside the `w:pPr` or `w:rPr` of the current style, and inside a `w:tblStylePr`
+    let (mut formatting_depth, mut skipped_depth) = (0usize, 0usize);
+
+    loop {
+        let event = reader.read_event_into(&mut buf)?;
+        if style.is_some() {
+            match &event {
+                Event::Start(e) if skipped_depth > 0 || e.name().as_ref() == b"w:tblStylePr" => {
+                    skipped_depth += 1
                 }
+                Event::End(_) if skipped_depth > 0 => skipped_depth -= 1,
+                _ => (),
             }
-            Ok(())
-        }
-        //Striked text
-        b"w:strike" => {
-            if data.in_run_properties {
-                if let Some(ref mut p) = data.run_properties {
-                    p.strike = true;
-                }
+            if skipped_depth > 0
+                || matches!(&event, Event::End(e) if e.name().as_ref() == b"w:tblStylePr")
+            {
+                buf.clear();
+                continue;
             }
-            Ok(())
-        }
-        //Double striked text
-        b"w:dstrike" => {
-            if data.in_run_properties {
-                if let Some(ref mut p) = data.run_properties {
-                    p.dstrike = true;
+            match event {
+                Event::Start(e)
+                    if formatting_depth > 0 || matches!(e.name().as_ref(), b"w:pPr" | b"w:rPr") =>
+                {
+                    formatting_depth += 1;
+                    handle_open_tag(
+                        e.name().as_ref(),
+                        &mut formatting,
+                        &mut e.attributes(),
+                        &reader,
+                    )?;
                 }
-            }
-            Ok(())
-        }
-        //Vertical alignment
-        b"w:valign" => {
-            if data.in_run_properties {
-                if let Some(ref mut p) = data.run_properties {
-                    if let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
+                Event::Empty(e) if formatting_depth > 0 => handle_empty_tag(
+                    e.name().as_ref(),
+                    &mut formatting,
+                    &mut e.attributes(),
+                    &reader,
+                )?,
+                Event::End(e) if formatting_depth > 0 => {
+                    formatting_depth -= 1;
+                    handle_close_tag(e.name().as_ref(), &mut formatting)?;
+                }
+                Event::Start(e) | Event::Empty(e)
+                    if matches!(e.name().as_ref(), b"w:name" | b"w:basedOn") =>
+                {
+                    if let Some(Ok(a)) = e
+                        .attributes()
+                        .find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
+                        && let Some(ref mut s) = style
                     {
-                        if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
-                            p.valign = Some(VerticalAlign::new(AlignValues::from(v.as_ref())));
+                        let v = a.decode_and_unescape_value(reader.decoder())?.to_string();
+                        match e.name().as_ref() {
+                            b"w:name" => s.name = v,
+                            _ => s.based_on = Some(v),
                         }
                     }
                 }
-            }
-            Ok(())
-        }
-        //Spacing
-        b"w:spacing" => {
-            if data.in_run_properties {
-                if let Some(ref mut p) = data.run_properties {
-                    if let Some(Ok(a)) = attr.find(|x| x.clone().unwrap().key.as_ref() == b"w:val")
-                    {
-                        if let Ok(v) = a.decode_and_unescape_value(reader.decoder()) {
-                            p.spacing = Some(v.parse::<u32>()?);
+                Event::End(e) if e.name().as_ref() == b"w:style" => {
+                    if let Some(mut s) = style.take() {
+                        s.paragraph_properties = formatting
+                            .paragraph
+                            .take()
+                            .map(|p| p.properties)
+                            .unwrap_or_default();
+                        s.run_properties = formatting.run_properties.take().unwrap_or_default();
+                        //The default `Normal` and footnote reference styles are the ones written when the
+                        //document doesn't define them
+                        if s != Style::normal() && s != Style::footnote_reference() {
+                            part.styles.push(s);
                         }
                     }
                 }
+                Event::Eof => break,
+                _ => (),
             }
-            Ok(())
-        }
-        _ => Ok(()),
-    }
-}
-
-fn handle_close_tag(tag: &[u8], data: &mut CurrentData) -> Result<(), RudocxError> {
-    match tag {
-        //Text
-        b"w:t" => Ok(()),
-        //Run Properties
-        b"w:rPr" => {
-            data.in_run_properties = false;
-            Ok(())
+            buf.clear();
+            continue;
         }
-        //Paragraph
-        b"w:p" => {
-            if let Some(mut p) = data.paragraph.take() {
-                if let Some(mut h) = data.hyperlink.take() {
-                    if let Some(r) = data.run.take() {
-                        h.runs.push(r);
-                    }
-                    p.children.push(ParagraphChild::Hyperlink(h));
-                    data.document.paragraphs.push(p);
-                } else {
-                    if let Some(r) = data.run.take() {
-                        p.children.push(ParagraphChild::Run(r));
-                        data.document.paragraphs.push(p);
-                    } else {
-                        data.document.paragraphs.push(p);
-                    }
-                }
+        match event {
+            Event::Start(e) if e.name().as_ref() == b"w:rPrDefault" => {
+                in_run_properties_default = true
             }
-            data.paragraph = None;
-            Ok(())
-        }
-        //Hyperlink
-        b"w:hyperlink" => {
-            if let Some(mut h) = data.hyperlink.take() {
-                if let Some(mut r) = data.run.take() {
-                    if let Some(rp) = data.run_properties.take() {
-                        r.properties = rp;
+            Event::End(e) if e.name().as_ref() == b"w:rPrDefault" => {
+                in_run_properties_default = false
+            }
+            Event::Start(e) | Event::Empty(e) if in_run_properties_default => {
+                for a in e.attributes().flatten() {
+                    let v = a.decode_and_unescape_value(reader.decoder())?;
+                    match (e.name().as_ref(), a.key.as_ref()) {
+                        (b"w:rFonts", b"w:ascii") => part.default_font = Some(v.to_string()),
+                        (b"w:rFonts", b"w:hAnsi") if part.default_font.is_none() => {
+                            part.default_font = Some(v.to_string())
+                        }
+                        (b"w:sz", b"w:val") => part.default_size = v.parse().ok(),
+                        _ => (),
                     }
-                    h.runs.push(r);
-                }
-                if let Some(ref mut p) = data.paragraph {
-                    p.children.push(ParagraphChild::Hyperlink(h));
                 }
             }
-            data.hyperlink = None;
-            Ok(())
-        }
-        //Run
-        b"w:r" => {
-            if let Some(mut r) = data.run.take() {
-                if let Some(rp) = data.run_properties.take() {
-                    r.properties = rp;
-                }
-                if let Some(ref mut h) = data.hyperlink {
-                    h.runs.push(r);
-                } else {
-                    if let Some(ref mut p) = data.paragraph {
-                        p.children.push(ParagraphChild::Run(r));
+            Event::Start(e) if e.name().as_ref() == b"w:style" => {
+                let mut new = Style::new("", "", StyleType::Paragraph);
+                for a in e.attributes().flatten() {
+                    let v = a.decode_and_unescape_value(reader.decoder())?;
+                    match a.key.as_ref() {
+                        b"w:styleId" => new.id = v.to_string(),
+                        b"w:type" => new.r#type = StyleType::from(v.as_ref()),
+                        b"w:default" => new.default = matches!(v.as_ref(), "1" | "true" | "on"),
+                        _ => (),
                     }
                 }
+                style = Some(new);
+                formatting = CurrentData::new(options);
+                formatting.paragraph = Some(Paragraph::default());
+                formatting.run = Some(Run::default());
+                formatting.run_properties = Some(RunProperties::default());
             }
-            data.run = None;
-            Ok(())
+            Event::Eof => break,
+            _ => (),
         }
-        _ => Ok(()),
+        buf.clear();
     }
+    Ok(part)
 }
 
-fn handle_eof(data: &mut CurrentData) -> Result<(), RudocxError> {
-    if let Some(p) = data.paragraph.take() {
-        if let Some(mut h) = data.hyperlink.take() {
-            if let Some(mut p) = data.paragraph.take() {
-                if let Some(r) = data.run.take() {
-                    h.runs.push(r);
-                    p.children.push(ParagraphChild::Hyperlink(h));
-                }
+/// Read a table row `w:tr`. Only its properties are kept, its cells are skipped along with nested tables.
+pub fn parse_table_row(contents: &str, options: ParseOptions) -> Result<TableRow, RudocxError> {
+    let contents = &normalize_prefixes(contents)?;
+    let mut reader = Reader::from_str(contents);
+    let mut buf = Vec::new();
+    let mut row = TableRow::default();
+    //Depth inside the nested tables of the cells, whose rows have their own properties
+    let mut table_depth = 0usize;
+
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(e) if e.name().as_ref() == b"w:tbl" => table_depth += 1,
+            Event::End(e) if e.name().as_ref() == b"w:tbl" => {
+                table_depth = table_depth.saturating_sub(1)
             }
-        }
-        if let Some(r) = data.run.take() {
-            if let Some(mut p) = Some(p) {
-                p.children.push(ParagraphChild::Run(r));
-                data.document.paragraphs.push(p);
+            Event::Start(e) | Event::Empty(e)
+                if table_depth == 0 && e.name().as_ref() == b"w:trHeight" =>
+            {
+                let mut value = None;
+                let mut rule = None;
+                for a in e.attributes().flatten() {
+                    let v = a.decode_and_unescape_value(reader.decoder())?;
+                    match a.key.as_ref() {
+                        b"w:val" => value = read_number(&v, &options)?,
+                        b"w:hRule" => rule = Some(HeightRule::from(v.as_ref())),
+                        _ => (),
+                    }
+                }
+                row.height = value.map(|value| RowHeight { value, rule });
             }
-        } else {
-            data.document.paragraphs.push(p);
+            Event::Eof => break,
+            _ => (),
         }
+        buf.clear();
     }
-    Ok(())
+    Ok(row)
 }
 
 ///This function server as a boilerplate parser and thus it is not completed.
@@ -519,16 +1663,20 @@ pub fn parse_document_xml(xml_content: &str) -> Result<Document, RudocxError> {
                 }
                 //Runs
                 b"w:r" => {
-                    if let Some(r) = current_run.take() {
-                        if let Some(ref mut p) = current_paragraph {
-                            p.children.push(ParagraphChild::Run(r))
-                        }
+                    if let Some(r) = current_run.take()
+                        && let Some(ref mut p) = current_paragraph
+                    {
+                        p.children.push(ParagraphChild::Run(r))
                     }
                     current_run_properties = Some(RunProperties::default());
                     current_run = Some(Run {
                         properties: RunProperties::default(),
                         text: String::new(),
                         space_preserve: false,
+                        picture_id: None,
+                        comment_reference: None,
+                        footnote_reference: None,
+                        break_type: None,
                     });
                 }
                 //RunProperties
@@ -544,27 +1692,20 @@ pub fn parse_document_xml(xml_content: &str) -> Result<Document, RudocxError> {
             Ok(Event::Empty(ref e)) => match e.name().as_ref() {
                 //Bold
                 b"w:b" => {
-                    if is_in_run_properties {
-                        if let Some(ref mut props) = current_run_properties {
-                            props.bold = true;
-                        }
+                    if is_in_run_properties && let Some(ref mut props) = current_run_properties {
+                        props.bold = true;
                     }
                 }
                 //Color
                 b"w:color" => {
-                    if is_in_run_properties {
-                        if let Some(ref mut props) = current_run_properties {
-                            for attr_result in e.attributes() {
-                                if let Ok(attr) = attr_result {
-                                    if attr.key.as_ref() == b"w:val" {
-                                        if let Ok(val) =
-                                            attr.decode_and_unescape_value(reader.decoder())
-                                        {
-                                            props.color = Some(HexColor::new(val.as_ref()));
-                                            break;
-                                        }
-                                    }
-                                }
+                    if is_in_run_properties && let Some(ref mut props) = current_run_properties {
+                        for attr_result in e.attributes() {
+                            if let Ok(attr) = attr_result
+                                && attr.key.as_ref() == b"w:val"
+                                && let Ok(val) = attr.decode_and_unescape_value(reader.decoder())
+                            {
+                                props.color = Some(HexColor::new(val.as_ref()));
+                                break;
                             }
                         }
                     }
@@ -575,7 +1716,7 @@ pub fn parse_document_xml(xml_content: &str) -> Result<Document, RudocxError> {
             //Plain text contained between two tags
             Ok(Event::Text(e)) => {
                 if let Some(ref mut run) = current_run {
-                    run.text.push_str(&e.unescape()?.to_string());
+                    run.text.push_str(e.unescape()?.as_ref());
                 }
             }
             //End of a tag. Without attributes
@@ -640,6 +1781,7 @@ pub fn parse_document_xml(xml_content: &str) -> Result<Document, RudocxError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::errors::RudocxParagraphStyleError;
 
     //TODO: Extend example XML to include current defined properties and structs
     #[test]
@@ -676,78 +1818,642 @@ mod tests {
 
         // Paragraph 1: Plain text
         assert_eq!(doc.paragraphs[0].children.len(), 1);
-        if let Some(p) = doc.paragraphs.iter().nth(0) {
-            if let Some(ParagraphChild::Run(r)) = p.children.iter().nth(0) {
+        if let Some(p) = doc.paragraphs.first() {
+            if let Some(ParagraphChild::Run(r)) = p.children.first() {
                 assert_eq!(r.text, "This is plain text.");
                 assert!(!r.properties.bold);
                 assert!(!r.properties.italic);
             } else {
-                assert!(false);
+                panic!();
             }
         }
 
         // Paragraph 2: Bold, space, Italic
         assert_eq!(doc.paragraphs[1].children.len(), 3);
-        if let Some(p) = doc.paragraphs.iter().nth(1) {
+        if let Some(p) = doc.paragraphs.get(1) {
             // Run 1: Bold
-            if let Some(ParagraphChild::Run(r)) = p.children.iter().nth(0) {
+            if let Some(ParagraphChild::Run(r)) = p.children.first() {
                 assert_eq!(r.text, "This is bold.");
                 assert!(r.properties.bold);
                 assert!(!r.properties.italic);
             } else {
-                assert!(false);
+                panic!();
             }
             // Run 2: Space (should be preserved)
-            if let Some(ParagraphChild::Run(r)) = p.children.iter().nth(1) {
+            if let Some(ParagraphChild::Run(r)) = p.children.get(1) {
                 assert_eq!(r.text, " ");
                 assert!(!r.properties.bold);
                 assert!(!r.properties.italic);
             } else {
-                assert!(false);
+                panic!();
             }
             // Run 3: Italic
-            if let Some(ParagraphChild::Run(r)) = p.children.iter().nth(2) {
+            if let Some(ParagraphChild::Run(r)) = p.children.get(2) {
                 assert_eq!(r.text, "This is italic.");
                 assert!(!r.properties.bold);
                 assert!(r.properties.italic);
             } else {
-                assert!(false);
+                panic!();
             }
         }
 
         // Paragraph 3: Bold and Italic
         assert_eq!(doc.paragraphs[2].children.len(), 1);
-        if let Some(p) = doc.paragraphs.iter().nth(2) {
-            if let Some(ParagraphChild::Run(r)) = p.children.iter().nth(0) {
+        if let Some(p) = doc.paragraphs.get(2) {
+            if let Some(ParagraphChild::Run(r)) = p.children.first() {
                 assert_eq!(r.text, "Bold and Italic.");
                 assert!(r.properties.bold);
                 assert!(r.properties.italic);
             } else {
-                assert!(false);
+                panic!();
             }
         }
 
         // Paragraph 3: Hyperlink and Plain
         assert_eq!(doc.paragraphs[3].children.len(), 2);
-        if let Some(p) = doc.paragraphs.iter().nth(3) {
+        if let Some(p) = doc.paragraphs.get(3) {
             // Child 1 (hyperlink)
-            if let Some(ParagraphChild::Hyperlink(h)) = p.children.iter().nth(0) {
-                assert_eq!(h.id, "rId1");
+            if let Some(ParagraphChild::Hyperlink(h)) = p.children.first() {
+                assert_eq!(h.id.as_deref(), Some("rId1"));
                 assert_eq!(h.runs.len(), 1);
                 assert_eq!(h.runs[0].text, "www.github.com/cmgsk/rudocx");
                 assert!(!h.runs[0].properties.bold);
                 assert!(h.runs[0].properties.italic);
             } else {
-                assert!(false);
+                panic!();
             }
             // Child 2 (run)
-            if let Some(ParagraphChild::Run(r)) = p.children.iter().nth(1) {
+            if let Some(ParagraphChild::Run(r)) = p.children.get(1) {
                 assert_eq!(r.text, " That was hyperlink.");
                 assert!(!r.properties.bold);
                 assert!(!r.properties.italic);
             } else {
-                assert!(false);
+                panic!();
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_duplicated_paragraph_properties() {
+        let xml_input = r#"
+            <w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+                <w:body>
+                    <w:p>
+                        <w:pPr><w:rPr><w:b/></w:rPr></w:pPr>
+                        <w:pPr><w:rPr><w:i/><w:color w:val="FF0000"/></w:rPr></w:pPr>
+                        <w:r><w:t>First</w:t></w:r>
+                        <w:pPr><w:rPr><w:strike/></w:rPr></w:pPr>
+                        <w:r><w:t>Second</w:t></w:r>
+                    </w:p>
+                    <w:p>
+                        <w:r><w:rPr><w:b/></w:rPr><w:t>Next paragraph</w:t></w:r>
+                    </w:p>
+                </w:body>
+            </w:document>
+        "#;
+
+        let doc = parse(xml_input).unwrap();
+        assert_eq!(doc, parse(xml_input).unwrap());
+        assert_eq!(doc.paragraphs.len(), 2);
+        assert_eq!(
+            doc.paragraphs[0].properties.default_run_properties,
+            Some(RunProperties {
+                bold: true,
+                ..Default::default()
+            })
+        );
+        assert_eq!(doc.paragraphs[1].properties.default_run_properties, None);
+
+        assert_eq!(doc.paragraphs[0].children.len(), 2);
+        for (child, text) in doc.paragraphs[0].children.iter().zip(["First", "Second"]) {
+            if let ParagraphChild::Run(r) = child {
+                assert_eq!(r.text, text);
+                assert!(!r.properties.has_formatting());
+            } else {
+                panic!();
             }
         }
+
+        if let Some(ParagraphChild::Run(r)) = doc.paragraphs[1].children.first() {
+            assert_eq!(r.text, "Next paragraph");
+            assert!(r.properties.bold);
+            assert!(!r.properties.italic);
+            assert!(r.properties.color.is_none());
+        } else {
+            panic!();
+        }
+    }
+
+    #[test]
+    fn test_parse_default_run_properties() {
+        let doc = parse(
+            r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>
+                <w:p>
+                    <w:pPr><w:outlineLvl w:val="1"/><w:rPr><w:b/><w:i/><w:color w:val="FF0000"/><w:sz w:val="28"/></w:rPr></w:pPr>
+                    <w:r><w:t>Heading</w:t></w:r>
+                </w:p>
+            </w:body></w:document>"#,
+        )
+        .unwrap();
+
+        let properties = &doc.paragraphs[0].properties;
+        assert_eq!(properties.outline_level, Some(1));
+        assert_eq!(
+            properties.default_run_properties,
+            Some(RunProperties {
+                bold: true,
+                italic: true,
+                color: Some(HexColor::new("FF0000")),
+                size: Some(28),
+                ..Default::default()
+            })
+        );
+        assert!(!doc.runs().next().unwrap().properties.has_formatting());
+    }
+
+    #[test]
+    fn test_parse_skips_legacy_pictures() {
+        let xml_input = r#"
+            <w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"
+                        xmlns:v="urn:schemas-microsoft-com:vml"
+                        xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+                <w:body>
+                    <w:p>
+                        <w:r><w:rPr><w:b/></w:rPr><w:t>Before</w:t></w:r>
+                        <w:r><w:pict>
+                                <v:shape style="width:100pt;height:50pt">
+                                    <v:imagedata r:id="rId7" o:title=""/>
+                                    <v:textbox>
+                                        <w:txbxContent>
+                                            <w:p><w:r><w:rPr><w:i/></w:rPr><w:t>Inside</w:t></w:r></w:p>
+                                        </w:txbxContent>
+                                    </v:textbox>
+                                </v:shape>
+                            </w:pict></w:r>
+                        <w:r><w:t>After</w:t></w:r>
+                    </w:p>
+                </w:body>
+            </w:document>
+        "#;
+
+        let doc = parse(xml_input).unwrap();
+        assert_eq!(doc.paragraphs.len(), 1);
+
+        let runs = doc.paragraphs[0].runs().collect::<Vec<&Run>>();
+        assert_eq!(runs.len(), 3);
+        assert_eq!(runs[0].text, "Before");
+        assert!(runs[0].properties.bold);
+        assert_eq!(runs[1].text, "");
+        assert_eq!(runs[1].picture_id, Some(String::from("rId7")));
+        assert!(!runs[1].properties.has_formatting());
+        assert_eq!(runs[2].text, "After");
+        assert!(runs[2].picture_id.is_none());
+        assert!(!runs[2].properties.has_formatting());
+    }
+
+    #[test]
+    fn test_parse_strict_and_lenient() {
+        let document = |outline_level: &str| {
+            format!(
+                r#"
+                <w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+                    <w:body>
+                        <w:p>
+                            <w:pPr><w:outlineLvl w:val="{outline_level}"/></w:pPr>
+                            <w:r><w:t>Heading</w:t></w:r>
+                        </w:p>
+                    </w:body>
+                </w:document>
+            "#
+            )
+        };
+        let strict = ParseOptions {
+            strict: true,
+            ..Default::default()
+        };
+
+        let doc = parse_with(&document("1"), strict).unwrap();
+        assert_eq!(doc.paragraphs[0].properties.outline_level, Some(1));
+
+        for bad in ["one", "12"] {
+            let doc = parse(&document(bad)).unwrap();
+            assert_eq!(doc.paragraphs[0].properties.outline_level, None);
+            assert_eq!(doc.paragraphs[0].runs().next().unwrap().text, "Heading");
+        }
+
+        assert!(matches!(
+            parse_with(&document("one"), strict),
+            Err(RudocxError::NumParseError(_))
+        ));
+        assert!(matches!(
+            parse_with(&document("12"), strict),
+            Err(RudocxError::ParagraphPropertyError(
+                RudocxParagraphStyleError::InvalidOutlineLevel(12)
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_parse_justification() {
+        let document = |justification: &str| {
+            format!(
+                r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>
+                    <w:p><w:pPr><w:jc w:val="{justification}"/></w:pPr><w:r><w:t>Text</w:t></w:r></w:p>
+                </w:body></w:document>"#
+            )
+        };
+        let justification = |xml: &str| {
+            parse(xml).unwrap().paragraphs[0]
+                .properties
+                .justification
+                .clone()
+        };
+
+        assert_eq!(
+            justification(&document("both")),
+            Some(ParagraphJustificationValues::Both)
+        );
+        assert_eq!(
+            justification(&document("distribute")),
+            Some(ParagraphJustificationValues::Distribute)
+        );
+        assert_eq!(justification(&document("justify")), None);
+        let strict = ParseOptions {
+            strict: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            parse_with(&document("justify"), strict),
+            Err(RudocxError::ParagraphPropertyError(
+                RudocxParagraphStyleError::InvalidJustification(_)
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_parse_indentation_first_line_and_hanging() {
+        let xml_input = r#"
+            <w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+                <w:body>
+                    <w:p>
+                        <w:pPr><w:ind w:left="720" w:hanging="360" w:firstLine="720"/></w:pPr>
+                        <w:r><w:t>Indented</w:t></w:r>
+                    </w:p>
+                </w:body>
+            </w:document>
+        "#;
+
+        let doc = parse(xml_input).unwrap();
+        assert_eq!(
+            doc.paragraphs[0].properties.indentation,
+            Some(ParagraphIndentation::new(Some(720), None, None, Some(360)).unwrap())
+        );
+
+        assert!(matches!(
+            parse_with(
+                xml_input,
+                ParseOptions {
+                    strict: true,
+                    ..Default::default()
+                }
+            ),
+            Err(RudocxError::ParagraphPropertyError(
+                RudocxParagraphStyleError::MutuallyExclusive(_, _)
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_empty_paragraphs_round_trip() {
+        let document = Document {
+            paragraphs: vec![Paragraph::default(); 3],
+            ..Default::default()
+        };
+
+        let xml = crate::xml::generate(&document).unwrap();
+        assert_eq!(parse(&xml).unwrap(), document);
+
+        let xml_input = r#"
+            <w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+                <w:body><w:p/><w:p></w:p><w:p/></w:body>
+            </w:document>
+        "#;
+        assert_eq!(parse(xml_input).unwrap(), document);
+    }
+
+    #[test]
+    fn test_parse_truncated_document() {
+        let xml_input = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body><w:p><w:r><w:t>Complete</w:t></w:r></w:p><w:p><w:r><w:t>Visit </w:t></w:r><w:hyperlink r:id="rId1"><w:r><w:rPr><w:b/></w:rPr><w:t>the site"#;
+
+        let doc = parse(xml_input).unwrap();
+        assert_eq!(doc.paragraphs.len(), 2);
+        assert_eq!(doc.text(), "Complete\nVisit the site");
+        if let Some(ParagraphChild::Hyperlink(h)) = doc.paragraphs[1].children.get(1) {
+            assert_eq!(h.id.as_deref(), Some("rId1"));
+            assert_eq!(h.runs.len(), 1);
+            assert!(h.runs[0].properties.bold);
+        } else {
+            panic!();
+        }
+
+        let xml_input = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body><w:p><w:r><w:t>Page </w:t></w:r><w:fldSimple w:instr="PAGE"><w:r><w:t>1"#;
+        let doc = parse(xml_input).unwrap();
+        assert_eq!(doc.text(), "Page 1");
+        assert!(matches!(
+            doc.paragraphs[0].children.get(1),
+            Some(ParagraphChild::SimpleField(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_run_without_properties() {
+        let doc = parse(
+            r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body><w:p><w:r><w:t>x</w:t></w:r></w:p></w:body></w:document>"#,
+        )
+        .unwrap();
+
+        let runs = doc.runs().collect::<Vec<&Run>>();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].text, "x");
+        assert_eq!(runs[0].properties, RunProperties::default());
+    }
+
+    #[test]
+    fn test_parse_text_outside_of_run() {
+        let mut data = CurrentData::new(ParseOptions::default());
+        assert!(handle_text(&mut data, String::from("x")).is_ok());
+        assert!(data.run.is_none());
+
+        // Text before the run is set up is dropped, text after it is kept
+        let doc = parse(
+            r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body><w:p><w:t>stray</w:t><w:r><w:t>x</w:t></w:r></w:p></w:body></w:document>"#,
+        )
+        .unwrap();
+        assert_eq!(doc.text(), "x");
+
+        // Properties after the text still apply to the run
+        let doc = parse(
+            r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body><w:p><w:r><w:t>x</w:t><w:rPr><w:b/></w:rPr></w:r></w:p></w:body></w:document>"#,
+        )
+        .unwrap();
+        let runs = doc.runs().collect::<Vec<&Run>>();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].text, "x");
+        assert!(runs[0].properties.bold);
+    }
+
+    #[test]
+    fn test_parse_runs_after_hyperlink() {
+        let doc = parse(
+            r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>
+                <w:p>
+                    <w:r><w:t>Before</w:t></w:r>
+                    <w:hyperlink r:id="rId1">
+                        <w:r><w:rPr><w:i/></w:rPr><w:t>www.github.com/cmgsk/rudocx</w:t></w:r>
+                    </w:hyperlink>
+                    <w:r><w:t xml:space="preserve"> That was hyperlink.</w:t></w:r>
+                    <w:r><w:rPr><w:b/></w:rPr><w:t xml:space="preserve"> And more.</w:t></w:r>
+                </w:p>
+            </w:body></w:document>"#,
+        )
+        .unwrap();
+
+        let children = &doc.paragraphs[0].children;
+        assert_eq!(children.len(), 4);
+        assert!(matches!(&children[0], ParagraphChild::Run(r) if r.text == "Before"));
+        assert!(matches!(&children[1], ParagraphChild::Hyperlink(h)
+            if h.runs.len() == 1 && h.runs[0].text == "www.github.com/cmgsk/rudocx"));
+        assert!(matches!(&children[2], ParagraphChild::Run(r)
+            if r.text == " That was hyperlink." && !r.properties.bold));
+        assert!(matches!(&children[3], ParagraphChild::Run(r)
+            if r.text == " And more." && r.properties.bold));
+    }
+
+    #[test]
+    fn test_parse_orphaned_hyperlink() {
+        // The hyperlink was closed after its paragraph instead of inside it
+        let xml_input = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><w:body><w:p><w:r><w:t>Visit </w:t></w:r></w:p><w:hyperlink r:id="rId1"><w:r><w:t>the site</w:t></w:r></w:hyperlink></w:body></w:document>"#;
+
+        let doc = parse(xml_input).unwrap();
+        assert_eq!(doc.text(), "Visit ");
+
+        let checked = ParseOptions {
+            check_integrity: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            parse_with(xml_input, checked),
+            Err(RudocxError::LoadContentMismatch(_))
+        ));
+
+        let xml_input = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body><w:p><w:r><w:t>Visit </w:t></w:r></w:p></w:body></w:document>"#;
+        assert!(parse_with(xml_input, checked).is_ok());
+    }
+
+    #[test]
+    fn test_parse_clear_shading_drops_color() {
+        let xml_input = r#"
+            <w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+                <w:body>
+                    <w:p>
+                        <w:pPr><w:shd w:val="clear" w:color="FF0000" w:fill="00FF00"/></w:pPr>
+                        <w:r><w:t>Clear</w:t></w:r>
+                    </w:p>
+                    <w:p>
+                        <w:pPr><w:shd w:val="pct10" w:color="FF0000" w:fill="auto"/></w:pPr>
+                        <w:r><w:t>Pattern</w:t></w:r>
+                    </w:p>
+                </w:body>
+            </w:document>
+        "#;
+
+        let doc = parse(xml_input).unwrap();
+        assert_eq!(
+            doc.paragraphs[0].properties.shading,
+            Some(ParagraphShading::fill(HexColor::new("00FF00")))
+        );
+        assert_eq!(
+            doc.paragraphs[1].properties.shading,
+            Some(ParagraphShading {
+                val: ParagraphShadingValues::Pct10,
+                color: Some(HexColor::new("FF0000")),
+                fill: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_run_properties_not_inherited() {
+        let xml_input = r#"
+            <w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+                <w:body>
+                    <w:p>
+                        <w:r><w:rPr><w:b/><w:color w:val="FF0000"/></w:rPr><w:t>Bold red</w:t></w:r>
+                        <w:r><w:t>Plain</w:t></w:r>
+                    </w:p>
+                </w:body>
+            </w:document>
+        "#;
+
+        let doc = parse(xml_input).unwrap();
+        assert_eq!(doc.paragraphs[0].children.len(), 2);
+
+        if let Some(ParagraphChild::Run(r)) = doc.paragraphs[0].children.first() {
+            assert_eq!(r.text, "Bold red");
+            assert!(r.properties.bold);
+            assert_eq!(r.properties.color, Some(HexColor::new("FF0000")));
+        } else {
+            panic!();
+        }
+
+        if let Some(ParagraphChild::Run(r)) = doc.paragraphs[0].children.get(1) {
+            assert_eq!(r.text, "Plain");
+            assert_eq!(r.properties, RunProperties::default());
+            assert!(!r.properties.has_formatting());
+        } else {
+            panic!();
+        }
+    }
+
+    #[test]
+    fn test_parse_skips_table_content() {
+        let xml_input = r#"
+            <w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+                <w:body>
+                    <w:p><w:r><w:t>Before table</w:t></w:r></w:p>
+                    <w:tbl>
+                        <w:tblPr><w:tblW w:w="0" w:type="auto"/></w:tblPr>
+                        <w:tr>
+                            <w:tc>
+                                <w:p><w:r><w:rPr><w:b/></w:rPr><w:t>Cell</w:t></w:r></w:p>
+                                <w:tbl>
+                                    <w:tr><w:tc><w:p><w:r><w:t>Nested</w:t></w:r></w:p></w:tc></w:tr>
+                                </w:tbl>
+                                <w:p><w:r><w:t>After nested</w:t></w:r></w:p>
+                            </w:tc>
+                        </w:tr>
+                    </w:tbl>
+                    <w:p><w:r><w:t>After table</w:t></w:r></w:p>
+                </w:body>
+            </w:document>
+        "#;
+
+        let doc = parse(xml_input).unwrap();
+        assert_eq!(doc.paragraphs.len(), 2);
+        let texts = doc
+            .runs()
+            .map(|r| (r.text.as_str(), r.properties.bold))
+            .collect::<Vec<(&str, bool)>>();
+        assert_eq!(texts, vec![("Before table", false), ("After table", false)]);
+    }
+
+    #[test]
+    fn test_parse_references_of_body_section() {
+        let xml_input = r#"
+            <w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"
+                xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+                <w:body>
+                    <w:p>
+                        <w:pPr>
+                            <w:sectPr>
+                                <w:headerReference w:type="default" r:id="rId1"/>
+                                <w:footerReference w:type="default" r:id="rId2"/>
+                                <w:vAlign w:val="bottom"/>
+                            </w:sectPr>
+                        </w:pPr>
+                        <w:r><w:t>First section</w:t></w:r>
+                    </w:p>
+                    <w:p><w:r><w:t>Second section</w:t></w:r></w:p>
+                    <w:sectPr>
+                        <w:headerReference w:type="first" r:id="rId3"/>
+                        <w:vAlign w:val="center"/>
+                    </w:sectPr>
+                </w:body>
+            </w:document>
+        "#;
+
+        let (doc, references) = parse_with_references(xml_input, ParseOptions::default()).unwrap();
+        assert_eq!(doc.paragraphs.len(), 2);
+        assert_eq!(
+            doc.section_properties.vertical_alignment,
+            Some(PageVAlign::Center)
+        );
+        assert_eq!(references.len(), 1);
+        assert_eq!(references[0].id, "rId3");
+        assert_eq!(references[0].kind, HeaderFooterKind::Header);
+        assert_eq!(references[0].r#type, HeaderFooterType::First);
+    }
+
+    #[test]
+    fn test_parse_styles() {
+        let xml_input = r#"
+            <w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+                <w:style w:type="paragraph" w:default="1" w:styleId="Normal">
+                    <w:name w:val="Normal"/>
+                    <w:pPr><w:jc w:val="both"/></w:pPr>
+                    <w:rPr><w:sz w:val="22"/></w:rPr>
+                </w:style>
+                <w:style w:type="table" w:styleId="Grid">
+                    <w:name w:val="Grid"/>
+                    <w:rPr><w:i/></w:rPr>
+                    <w:tblStylePr w:type="firstRow">
+                        <w:pPr><w:jc w:val="center"/></w:pPr>
+                        <w:rPr><w:b/><w:i w:val="0"/></w:rPr>
+                    </w:tblStylePr>
+                </w:style>
+            </w:styles>
+        "#;
+
+        let styles = parse_styles(xml_input, ParseOptions::default())
+            .unwrap()
+            .styles;
+        assert_eq!(styles.len(), 2);
+        let normal = &styles[0];
+        assert!(normal.default);
+        assert_eq!(
+            normal.paragraph_properties.justification,
+            Some(ParagraphJustificationValues::Both)
+        );
+        assert_eq!(normal.run_properties.size, Some(22));
+
+        let grid = &styles[1];
+        assert!(!grid.default);
+        assert_eq!(grid.paragraph_properties, ParagraphProperties::default());
+        assert!(grid.run_properties.italic);
+        assert!(!grid.run_properties.bold);
+    }
+
+    #[test]
+    fn test_parse_breaks_split_runs() {
+        let xml_input = r#"
+            <w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+                <w:body>
+                    <w:p>
+                        <w:r>
+                            <w:rPr><w:b/></w:rPr>
+                            <w:t>A</w:t><w:br/><w:t>B</w:t><w:br w:type="page"/><w:br/>
+                        </w:r>
+                    </w:p>
+                </w:body>
+            </w:document>
+        "#;
+
+        let doc = parse(xml_input).unwrap();
+        let runs = doc
+            .runs()
+            .map(|r| (r.text.as_str(), r.break_type.clone(), r.properties.bold))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            runs,
+            vec![
+                ("A", None, true),
+                ("B", Some(BreakType::TextWrapping), true),
+                ("", Some(BreakType::Page), true),
+                ("", Some(BreakType::TextWrapping), true),
+            ]
+        );
+
+        let saved = parse(&crate::xml::generate(&doc).unwrap()).unwrap();
+        assert_eq!(saved.paragraphs, doc.paragraphs);
     }
 }
@@ -1,10 +1,13 @@
 use crate::elements::{
-    Document, FontType, Hyperlink, Paragraph, ParagraphChild, Run, RunProperties,
+    BreakType, Comment, Document, Drawing, FontSet, FontType, Footnote, HeaderFooterType,
+    Hyperlink, Paragraph, ParagraphChild, ParagraphProperties, ParagraphShadingValues, Run,
+    RunProperties, SimpleField, Style, TableRow,
 };
 use crate::errors::RudocxError;
 
-use quick_xml::events::BytesText;
+use quick_xml::events::{BytesText, Event};
 use quick_xml::Writer;
+use std::borrow::Cow;
 use std::io::Cursor;
 
 type XmlWriter = Writer<Cursor<Vec<u8>>>;
@@ -13,6 +16,10 @@ type XmlResult = std::io::Result<()>;
 enum XmlNs {
     W,
     R,
+    Wp,
+    A,
+    Pic,
+    V,
 }
 
 impl XmlNs {
@@ -20,6 +27,10 @@ impl XmlNs {
         match self {
             XmlNs::W => "xmlns:w",
             XmlNs::R => "xmlns:r",
+            XmlNs::Wp => "xmlns:wp",
+            XmlNs::A => "xmlns:a",
+            XmlNs::Pic => "xmlns:pic",
+            XmlNs::V => "xmlns:v",
         }
     }
 
@@ -27,6 +38,10 @@ impl XmlNs {
         match self {
             XmlNs::W => "http://schemas.openxmlformats.org/wordprocessingml/2006/main",
             XmlNs::R => "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
+            XmlNs::Wp => "http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing",
+            XmlNs::A => "http://schemas.openxmlformats.org/drawingml/2006/main",
+            XmlNs::Pic => "http://schemas.openxmlformats.org/drawingml/2006/picture",
+            XmlNs::V => "urn:schemas-microsoft-com:vml",
         }
     }
 }
@@ -34,22 +49,101 @@ impl XmlNs {
 enum XmlElement {
     Document,
     Body,
+    Header,
+    Footer,
+    Comments,
+    Comment,
+    Footnotes,
+    Footnote,
+    Separator,
+    ContinuationSeparator,
+    Styles,
+    DocDefaults,
+    RunPropsDefault,
+    ParagraphPropsDefault,
+    Style,
+    StyleName,
+    BasedOn,
+    ParagraphStyle,
+    KeepNext,
+    KeepLines,
+    RunStyle,
+    SectionProps,
+    HeaderReference,
+    FooterReference,
+    PageVAlign,
+    TitlePage,
     Paragraph,
+    ParagraphProps,
+    PageBreakBefore,
+    FramePr,
+    Shading,
+    Tabs,
+    Tab,
+    Kinsoku,
+    OverflowPunct,
+    ParagraphSpacing,
+    Indentation,
+    Justification,
+    TextDirection,
+    TextAlignment,
+    TextboxTightWrap,
+    OutlineLvl,
+    DivId,
+    CnfStyle,
     Hyperlink,
+    SimpleField,
+    CommentRangeStart,
+    CommentRangeEnd,
+    CommentReference,
+    FootnoteReference,
+    FootnoteRef,
+    Drawing,
+    Inline,
+    Extent,
+    DocPr,
+    Graphic,
+    GraphicData,
+    Pic,
+    NvPicPr,
+    CNvPr,
+    CNvPicPr,
+    BlipFill,
+    Blip,
+    Stretch,
+    FillRect,
+    SpPr,
+    Xfrm,
+    Off,
+    Ext,
+    PrstGeom,
+    AvLst,
+    Pict,
+    Shape,
+    ImageData,
     Run,
     RunProps,
+    Break,
     Text,
     Bold,
     Italic,
     Strike,
     DStrike,
+    NoProof,
+    Rtl,
     Underline,
     Color,
     Size,
+    SizeCs,
     Fonts,
     Highlight,
     VertAlign,
     Spacing,
+    Position,
+    Lang,
+    TableRow,
+    TableRowProps,
+    TableRowHeight,
 }
 
 impl XmlElement {
@@ -57,38 +151,223 @@ impl XmlElement {
         match self {
             XmlElement::Document => "w:document",
             XmlElement::Body => "w:body",
+            XmlElement::Header => "w:hdr",
+            XmlElement::Footer => "w:ftr",
+            XmlElement::Comments => "w:comments",
+            XmlElement::Comment => "w:comment",
+            XmlElement::Footnotes => "w:footnotes",
+            XmlElement::Footnote => "w:footnote",
+            XmlElement::Separator => "w:separator",
+            XmlElement::ContinuationSeparator => "w:continuationSeparator",
+            XmlElement::Styles => "w:styles",
+            XmlElement::DocDefaults => "w:docDefaults",
+            XmlElement::RunPropsDefault => "w:rPrDefault",
+            XmlElement::ParagraphPropsDefault => "w:pPrDefault",
+            XmlElement::Style => "w:style",
+            XmlElement::StyleName => "w:name",
+            XmlElement::BasedOn => "w:basedOn",
+            XmlElement::ParagraphStyle => "w:pStyle",
+            XmlElement::KeepNext => "w:keepNext",
+            XmlElement::KeepLines => "w:keepLines",
+            XmlElement::RunStyle => "w:rStyle",
+            XmlElement::SectionProps => "w:sectPr",
+            XmlElement::HeaderReference => "w:headerReference",
+            XmlElement::FooterReference => "w:footerReference",
+            XmlElement::PageVAlign => "w:vAlign",
+            XmlElement::TitlePage => "w:titlePg",
             XmlElement::Paragraph => "w:p",
+            XmlElement::ParagraphProps => "w:pPr",
+            XmlElement::PageBreakBefore => "w:pageBreakBefore",
+            XmlElement::FramePr => "w:framePr",
+            XmlElement::Shading => "w:shd",
+            XmlElement::Tabs => "w:tabs",
+            XmlElement::Tab => "w:tab",
+            XmlElement::Indentation => "w:ind",
+            XmlElement::Kinsoku => "w:kinsoku",
+            XmlElement::OverflowPunct => "w:overflowPunct",
+            XmlElement::ParagraphSpacing => "w:spacing",
+            XmlElement::Justification => "w:jc",
+            XmlElement::TextDirection => "w:textDirection",
+            XmlElement::TextAlignment => "w:textAlignment",
+            XmlElement::TextboxTightWrap => "w:textboxTightWrap",
+            XmlElement::OutlineLvl => "w:outlineLvl",
+            XmlElement::DivId => "w:divId",
+            XmlElement::CnfStyle => "w:cnfStyle",
             XmlElement::Hyperlink => "w:hyperlink",
+            XmlElement::SimpleField => "w:fldSimple",
+            XmlElement::CommentRangeStart => "w:commentRangeStart",
+            XmlElement::CommentRangeEnd => "w:commentRangeEnd",
+            XmlElement::CommentReference => "w:commentReference",
+            XmlElement::FootnoteReference => "w:footnoteReference",
+            XmlElement::FootnoteRef => "w:footnoteRef",
+            XmlElement::Drawing => "w:drawing",
+            XmlElement::Inline => "wp:inline",
+            XmlElement::Extent => "wp:extent",
+            XmlElement::DocPr => "wp:docPr",
+            XmlElement::Graphic => "a:graphic",
+            XmlElement::GraphicData => "a:graphicData",
+            XmlElement::Pic => "pic:pic",
+            XmlElement::NvPicPr => "pic:nvPicPr",
+            XmlElement::CNvPr => "pic:cNvPr",
+            XmlElement::CNvPicPr => "pic:cNvPicPr",
+            XmlElement::BlipFill => "pic:blipFill",
+            XmlElement::Blip => "a:blip",
+            XmlElement::Stretch => "a:stretch",
+            XmlElement::FillRect => "a:fillRect",
+            XmlElement::SpPr => "pic:spPr",
+            XmlElement::Xfrm => "a:xfrm",
+            XmlElement::Off => "a:off",
+            XmlElement::Ext => "a:ext",
+            XmlElement::PrstGeom => "a:prstGeom",
+            XmlElement::AvLst => "a:avLst",
+            XmlElement::Pict => "w:pict",
+            XmlElement::Shape => "v:shape",
+            XmlElement::ImageData => "v:imagedata",
             XmlElement::Run => "w:r",
             XmlElement::RunProps => "w:rPr",
+            XmlElement::Break => "w:br",
             XmlElement::Text => "w:t",
             XmlElement::Bold => "w:b",
             XmlElement::Italic => "w:i",
             XmlElement::Strike => "w:strike",
             XmlElement::DStrike => "w:dstrike",
+            XmlElement::NoProof => "w:noProof",
+            XmlElement::Rtl => "w:rtl",
             XmlElement::Underline => "w:u",
             XmlElement::Color => "w:color",
             XmlElement::Size => "w:sz",
+            XmlElement::SizeCs => "w:szCs",
             XmlElement::Fonts => "w:rFonts",
             XmlElement::Highlight => "w:highlight",
             XmlElement::VertAlign => "w:vertAlign",
             XmlElement::Spacing => "w:spacing",
+            XmlElement::Position => "w:position",
+            XmlElement::Lang => "w:lang",
+            XmlElement::TableRow => "w:tr",
+            XmlElement::TableRowProps => "w:trPr",
+            XmlElement::TableRowHeight => "w:trHeight",
         }
     }
 }
 
 enum XmlAttr {
     Val,
+    Id,
+    Author,
+    Date,
     Rid,
+    Anchor,
+    Tooltip,
+    History,
     Space,
+    Type,
+    Instr,
+    Default,
+    StyleId,
+    Color,
+    Fill,
+    ThemeColor,
+    ThemeTint,
+    ThemeShade,
+    Before,
+    After,
+    Line,
+    LineRule,
+    BeforeAutospacing,
+    AfterAutospacing,
+    Left,
+    Right,
+    FirstLine,
+    Hanging,
+    W,
+    H,
+    HRule,
+    Wrap,
+    HAnchor,
+    VAnchor,
+    FrameX,
+    FrameY,
+    Pos,
+    Leader,
+    Hint,
+    Ascii,
+    HAnsi,
+    EastAsia,
+    Cs,
+    AsciiTheme,
+    HAnsiTheme,
+    EastAsiaTheme,
+    CsTheme,
+    Cx,
+    Cy,
+    X,
+    Y,
+    DrawingId,
+    Name,
+    Uri,
+    Embed,
+    Prst,
 }
 
 impl XmlAttr {
     fn as_str(&self) -> &'static str {
         match self {
             XmlAttr::Val => "w:val",
+            XmlAttr::Id => "w:id",
+            XmlAttr::Author => "w:author",
+            XmlAttr::Date => "w:date",
             XmlAttr::Rid => "r:id",
+            XmlAttr::Anchor => "w:anchor",
+            XmlAttr::Tooltip => "w:tooltip",
+            XmlAttr::History => "w:history",
             XmlAttr::Space => "xml:space",
+            XmlAttr::Type => "w:type",
+            XmlAttr::Instr => "w:instr",
+            XmlAttr::Default => "w:default",
+            XmlAttr::StyleId => "w:styleId",
+            XmlAttr::Color => "w:color",
+            XmlAttr::Fill => "w:fill",
+            XmlAttr::ThemeColor => "w:themeColor",
+            XmlAttr::ThemeTint => "w:themeTint",
+            XmlAttr::ThemeShade => "w:themeShade",
+            XmlAttr::Before => "w:before",
+            XmlAttr::After => "w:after",
+            XmlAttr::Line => "w:line",
+            XmlAttr::LineRule => "w:lineRule",
+            XmlAttr::BeforeAutospacing => "w:beforeAutospacing",
+            XmlAttr::AfterAutospacing => "w:afterAutospacing",
+            XmlAttr::Left => "w:left",
+            XmlAttr::Right => "w:right",
+            XmlAttr::FirstLine => "w:firstLine",
+            XmlAttr::Hanging => "w:hanging",
+            XmlAttr::W => "w:w",
+            XmlAttr::H => "w:h",
+            XmlAttr::HRule => "w:hRule",
+            XmlAttr::Wrap => "w:wrap",
+            XmlAttr::HAnchor => "w:hAnchor",
+            XmlAttr::VAnchor => "w:vAnchor",
+            XmlAttr::FrameX => "w:x",
+            XmlAttr::FrameY => "w:y",
+            XmlAttr::Pos => "w:pos",
+            XmlAttr::Leader => "w:leader",
+            XmlAttr::Hint => "w:hint",
+            XmlAttr::Ascii => "w:ascii",
+            XmlAttr::HAnsi => "w:hAnsi",
+            XmlAttr::EastAsia => "w:eastAsia",
+            XmlAttr::Cs => "w:cs",
+            XmlAttr::AsciiTheme => "w:asciiTheme",
+            XmlAttr::HAnsiTheme => "w:hAnsiTheme",
+            XmlAttr::EastAsiaTheme => "w:eastAsiaTheme",
+            XmlAttr::CsTheme => "w:cstheme",
+            XmlAttr::Cx => "cx",
+            XmlAttr::Cy => "cy",
+            XmlAttr::X => "x",
+            XmlAttr::Y => "y",
+            XmlAttr::DrawingId => "id",
+            XmlAttr::Name => "name",
+            XmlAttr::Uri => "uri",
+            XmlAttr::Embed => "r:embed",
+            XmlAttr::Prst => "prst",
         }
     }
 }
@@ -107,76 +386,1006 @@ impl<'a> XmlAttrValue<'a> {
     }
 }
 
+/// Whether a header/footer part holds a header or a footer
+#[derive(Debug, Clone, PartialEq)]
+pub enum HeaderFooterKind {
+    Header,
+    Footer,
+}
+
+/// A header or footer of the document saved as its own part and referenced from the body `w:sectPr`.
+pub struct HeaderFooterPart<'a> {
+    pub id: String,
+    pub file_name: String,
+    pub kind: HeaderFooterKind,
+    pub r#type: HeaderFooterType,
+    pub paragraphs: &'a [Paragraph],
+}
+
+/// List the header and footer parts of a document. Relationship ids are numbered after the ones
+/// held by the document relationship manager, so they never collide with hyperlinks.
+pub fn header_footer_parts(document: &Document) -> Vec<HeaderFooterPart<'_>> {
+    let section = &document.section_properties;
+    let mut parts = Vec::new();
+    let mut next_id = document.relationship_manager.last_id();
+
+    for kind in [HeaderFooterKind::Header, HeaderFooterKind::Footer] {
+        let mut number = 0;
+        for r#type in HeaderFooterType::ALL {
+            let paragraphs = match kind {
+                HeaderFooterKind::Header => section.header(&r#type).map(|h| &h.paragraphs),
+                HeaderFooterKind::Footer => section.footer(&r#type).map(|f| &f.paragraphs),
+            };
+            if let Some(paragraphs) = paragraphs {
+                number += 1;
+                next_id += 1;
+                let file_name = match kind {
+                    HeaderFooterKind::Header => format!("header{number}.xml"),
+                    HeaderFooterKind::Footer => format!("footer{number}.xml"),
+                };
+                parts.push(HeaderFooterPart {
+                    id: format!("rId{next_id}"),
+                    file_name,
+                    kind: kind.clone(),
+                    r#type,
+                    paragraphs,
+                });
+            }
+        }
+    }
+
+    parts
+}
+
+/// Options to tweak how a document is written by [generate_with].
+///
+/// ### Fields
+/// > - **reject_invalid_chars:** `bool` - Return `Err` if the text of a run contains a character not allowed in XML 1.0
+/// >   (e.g. `\u{0001}`), instead of silently removing it. Headers, footers, comments and footnotes are checked as well.
+/// > - **always_emit_rpr:** `bool` - Write a `w:rPr` in every run of the body, even an empty one, for tooling that expects
+/// >   uniform runs.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GenerateOptions {
+    pub reject_invalid_chars: bool,
+    pub always_emit_rpr: bool,
+}
+
 pub fn generate(document: &Document) -> Result<String, RudocxError> {
-    let mut writer = Writer::new(Cursor::new(Vec::new()));
+    generate_with(document, &GenerateOptions::default())
+}
+
+/// Same as [generate], using the given [GenerateOptions].
+pub fn generate_with(
+    document: &Document,
+    options: &GenerateOptions,
+) -> Result<String, RudocxError> {
+    if options.reject_invalid_chars
+        && let Some(c) = document
+            .all_paragraphs()
+            .flat_map(Paragraph::runs)
+            .flat_map(|r| r.text.chars())
+            .find(|c| !is_xml_char(*c))
+    {
+        return Err(RudocxError::InvalidXmlChar(c));
+    }
+    check_writable(&document.paragraphs)?;
+
+    let mut writer = Writer::new(Cursor::new(Vec::with_capacity(estimate_len(document))));
 
     let element = writer.create_element(XmlElement::Document.as_str());
     element
         .with_attribute((XmlNs::W.as_str(), XmlNs::W.url()))
         .with_attribute((XmlNs::R.as_str(), XmlNs::R.url()))
-        .write_inner_content(|writer| write_body(writer, document))
+        .write_inner_content(|writer| write_body(writer, document, options))
+        .map_err(|e| RudocxError::XmlError(e.into()))?;
+
+    let xml_bytes = writer.into_inner().into_inner();
+    String::from_utf8(xml_bytes).map_err(RudocxError::Utf8Error)
+}
+
+/// Rough size of the `document.xml` of the document, to allocate it at once instead of growing it run by run.
+/// Runs take from 20 bytes of markup besides their text when plain to about 100 when formatted.
+fn estimate_len(document: &Document) -> usize {
+    let runs = document.runs().map(|r| r.text.len() + 64).sum::<usize>();
+    1024 + document.paragraphs.len() * 32 + runs
+}
+
+/// Generate the contents of a `word/headerN.xml` or `word/footerN.xml` part.
+pub fn generate_header_footer(part: &HeaderFooterPart) -> Result<String, RudocxError> {
+    check_writable(part.paragraphs)?;
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+
+    let root = match part.kind {
+        HeaderFooterKind::Header => XmlElement::Header,
+        HeaderFooterKind::Footer => XmlElement::Footer,
+    };
+    let element = writer.create_element(root.as_str());
+    element
+        .with_attribute((XmlNs::W.as_str(), XmlNs::W.url()))
+        .with_attribute((XmlNs::R.as_str(), XmlNs::R.url()))
+        .write_inner_content(|writer| {
+            for paragraph in part.paragraphs {
+                write_paragraph(writer, paragraph, &GenerateOptions::default())?;
+            }
+            Ok(())
+        })
+        .map_err(|e| RudocxError::XmlError(e.into()))?;
+
+    let xml_bytes = writer.into_inner().into_inner();
+    String::from_utf8(xml_bytes).map_err(RudocxError::Utf8Error)
+}
+
+/// Generate the contents of the `word/comments.xml` part.
+pub fn generate_comments(comments: &[Comment]) -> Result<String, RudocxError> {
+    for comment in comments {
+        check_writable(&comment.paragraphs)?;
+    }
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+
+    let element = writer.create_element(XmlElement::Comments.as_str());
+    element
+        .with_attribute((XmlNs::W.as_str(), XmlNs::W.url()))
+        .with_attribute((XmlNs::R.as_str(), XmlNs::R.url()))
+        .write_inner_content(|writer| {
+            for comment in comments {
+                write_comment(writer, comment)?;
+            }
+            Ok(())
+        })
         .map_err(|e| RudocxError::XmlError(e.into()))?;
 
     let xml_bytes = writer.into_inner().into_inner();
     String::from_utf8(xml_bytes).map_err(RudocxError::Utf8Error)
 }
 
-fn write_body(writer: &mut XmlWriter, document: &Document) -> XmlResult {
+fn write_comment(writer: &mut XmlWriter, comment: &Comment) -> XmlResult {
+    let mut buffer = itoa::Buffer::new();
+    let id = buffer.format(comment.id);
+    let mut element = writer
+        .create_element(XmlElement::Comment.as_str())
+        .with_attribute((XmlAttr::Id.as_str(), XmlAttrValue::Custom(id).as_str()))
+        .with_attribute((
+            XmlAttr::Author.as_str(),
+            XmlAttrValue::Custom(&comment.author).as_str(),
+        ));
+    if let Some(date) = &comment.date {
+        element =
+            element.with_attribute((XmlAttr::Date.as_str(), XmlAttrValue::Custom(date).as_str()));
+    }
+    element.write_inner_content(|writer| {
+        for paragraph in &comment.paragraphs {
+            write_paragraph(writer, paragraph, &GenerateOptions::default())?;
+        }
+        Ok(())
+    })?;
+    Ok(())
+}
+
+/// Generate the contents of the `word/footnotes.xml` part. The separator lines Word requires before the footnotes
+/// of each page are written as footnotes `-1` and `0`, so footnote ids must start at `1`. Each footnote starts with
+/// the mark showing its number.
+pub fn generate_footnotes(footnotes: &[Footnote]) -> Result<String, RudocxError> {
+    if footnotes.iter().any(|f| f.id == 0) {
+        return Err(RudocxError::Unsupported(String::from(
+            "footnote with reserved id 0",
+        )));
+    }
+    for footnote in footnotes {
+        check_writable(&footnote.paragraphs)?;
+    }
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+
+    let element = writer.create_element(XmlElement::Footnotes.as_str());
+    element
+        .with_attribute((XmlNs::W.as_str(), XmlNs::W.url()))
+        .with_attribute((XmlNs::R.as_str(), XmlNs::R.url()))
+        .write_inner_content(|writer| {
+            for (id, r#type, separator) in [
+                ("-1", "separator", XmlElement::Separator),
+                (
+                    "0",
+                    "continuationSeparator",
+                    XmlElement::ContinuationSeparator,
+                ),
+            ] {
+                writer
+                    .create_element(XmlElement::Footnote.as_str())
+                    .with_attribute((
+                        XmlAttr::Type.as_str(),
+                        XmlAttrValue::Custom(r#type).as_str(),
+                    ))
+                    .with_attribute((XmlAttr::Id.as_str(), XmlAttrValue::Custom(id).as_str()))
+                    .write_inner_content(|writer| {
+                        writer
+                            .create_element(XmlElement::Paragraph.as_str())
+                            .write_inner_content(|writer| {
+                                writer
+                                    .create_element(XmlElement::Run.as_str())
+                                    .write_inner_content(|writer| {
+                                        writer.create_element(separator.as_str()).write_empty()?;
+                                        Ok(())
+                                    })?;
+                                Ok(())
+                            })?;
+                        Ok(())
+                    })?;
+            }
+            let mut buffer = itoa::Buffer::new();
+            for footnote in footnotes {
+                writer
+                    .create_element(XmlElement::Footnote.as_str())
+                    .with_attribute((
+                        XmlAttr::Id.as_str(),
+                        XmlAttrValue::Custom(buffer.format(footnote.id)).as_str(),
+                    ))
+                    .write_inner_content(|writer| {
+                        for (index, paragraph) in footnote.paragraphs.iter().enumerate() {
+                            let options = GenerateOptions::default();
+                            write_marked_paragraph(writer, paragraph, &options, index == 0)?;
+                        }
+                        Ok(())
+                    })?;
+            }
+            Ok(())
+        })
+        .map_err(|e| RudocxError::XmlError(e.into()))?;
+
+    let xml_bytes = writer.into_inner().into_inner();
+    String::from_utf8(xml_bytes).map_err(RudocxError::Utf8Error)
+}
+
+/// Generate a table row `w:tr` with its properties. Tables are not part of [Document] yet, so the row is written on its
+/// own, without cells.
+pub fn generate_table_row(row: &TableRow) -> Result<String, RudocxError> {
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+
+    let element = writer.create_element(XmlElement::TableRow.as_str());
+    element
+        .with_attribute((XmlNs::W.as_str(), XmlNs::W.url()))
+        .write_inner_content(|writer| {
+            if let Some(height) = &row.height {
+                writer
+                    .create_element(XmlElement::TableRowProps.as_str())
+                    .write_inner_content(|writer| {
+                        let mut buffer = itoa::Buffer::new();
+                        let values = [
+                            (XmlAttr::Val, format_number(&mut buffer, Some(height.value))),
+                            (XmlAttr::HRule, format_value(height.rule.as_ref())),
+                        ];
+                        let mut element =
+                            writer.create_element(XmlElement::TableRowHeight.as_str());
+                        for (attr, value) in &values {
+                            if let Some(value) = value {
+                                element = element.with_attribute((attr.as_str(), &**value));
+                            }
+                        }
+                        element.write_empty()?;
+                        Ok(())
+                    })?;
+            }
+            Ok(())
+        })
+        .map_err(|e| RudocxError::XmlError(e.into()))?;
+
+    let xml_bytes = writer.into_inner().into_inner();
+    String::from_utf8(xml_bytes).map_err(RudocxError::Utf8Error)
+}
+
+/// Generate the contents of the `word/styles.xml` part, with the document defaults and the styles of the document. A
+/// `Normal` default paragraph style is added if the document doesn't define one, as is the `FootnoteReference`
+/// character style of the footnote marks when the document has footnotes.
+pub fn generate_styles(document: &Document) -> Result<String, RudocxError> {
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+
+    let defaults = RunProperties {
+        font: document.default_font.as_ref().map(|font| FontSet {
+            ascii: Some(font.clone()),
+            hi_ansi: Some(font.clone()),
+            cs: Some(font.clone()),
+            ..Default::default()
+        }),
+        size: document.default_size,
+        ..Default::default()
+    };
+    let element = writer.create_element(XmlElement::Styles.as_str());
+    element
+        .with_attribute((XmlNs::W.as_str(), XmlNs::W.url()))
+        .write_inner_content(|writer| {
+            writer
+                .create_element(XmlElement::DocDefaults.as_str())
+                .write_inner_content(|writer| {
+                    writer
+                        .create_element(XmlElement::RunPropsDefault.as_str())
+                        .write_inner_content(|writer| write_run_properties(writer, &defaults))?;
+                    writer
+                        .create_element(XmlElement::ParagraphPropsDefault.as_str())
+                        .write_inner_content(|writer| {
+                            writer
+                                .create_element(XmlElement::ParagraphProps.as_str())
+                                .write_empty()?;
+                            Ok(())
+                        })?;
+                    Ok(())
+                })?;
+            if document.style("Normal").is_none() {
+                write_style(writer, &Style::normal())?;
+            }
+            if !document.footnotes.is_empty() && document.style(Style::FOOTNOTE_REFERENCE).is_none()
+            {
+                write_style(writer, &Style::footnote_reference())?;
+            }
+            for style in &document.styles {
+                write_style(writer, style)?;
+            }
+            Ok(())
+        })
+        .map_err(|e| RudocxError::XmlError(e.into()))?;
+
+    let xml_bytes = writer.into_inner().into_inner();
+    String::from_utf8(xml_bytes).map_err(RudocxError::Utf8Error)
+}
+
+fn write_style(writer: &mut XmlWriter, style: &Style) -> XmlResult {
+    let mut element = writer
+        .create_element(XmlElement::Style.as_str())
+        .with_attribute((XmlAttr::Type.as_str(), style.r#type.to_string().as_str()));
+    if style.default {
+        element = element.with_attribute((XmlAttr::Default.as_str(), "1"));
+    }
+    element
+        .with_attribute((XmlAttr::StyleId.as_str(), style.id.as_str()))
+        .write_inner_content(|writer| {
+            write_attribute_element(
+                writer,
+                &XmlElement::StyleName,
+                &XmlAttr::Val,
+                &XmlAttrValue::Custom(&style.name),
+            )?;
+            if let Some(based_on) = &style.based_on {
+                write_attribute_element(
+                    writer,
+                    &XmlElement::BasedOn,
+                    &XmlAttr::Val,
+                    &XmlAttrValue::Custom(based_on),
+                )?;
+            }
+            if style.paragraph_properties.has_formatting() {
+                write_paragraph_properties(writer, &style.paragraph_properties)?;
+            }
+            if style.run_properties.has_formatting() {
+                write_run_properties(writer, &style.run_properties)?;
+            }
+            Ok(())
+        })?;
+    Ok(())
+}
+
+fn write_body(writer: &mut XmlWriter, document: &Document, options: &GenerateOptions) -> XmlResult {
     let element = writer.create_element(XmlElement::Body.as_str());
     element.write_inner_content(|writer| {
         for paragraph in &document.paragraphs {
-            write_paragraph(writer, paragraph)?;
+            write_paragraph(writer, paragraph, options)?;
+        }
+        if document.section_properties.has_formatting() {
+            write_section_properties(writer, document)?;
+        }
+        Ok(())
+    })?;
+    Ok(())
+}
+
+fn write_section_properties(writer: &mut XmlWriter, document: &Document) -> XmlResult {
+    let element = writer.create_element(XmlElement::SectionProps.as_str());
+    element.write_inner_content(|writer| {
+        for part in header_footer_parts(document) {
+            let reference = match part.kind {
+                HeaderFooterKind::Header => XmlElement::HeaderReference,
+                HeaderFooterKind::Footer => XmlElement::FooterReference,
+            };
+            writer
+                .create_element(reference.as_str())
+                .with_attribute((
+                    XmlAttr::Type.as_str(),
+                    XmlAttrValue::Custom(&part.r#type.to_string()).as_str(),
+                ))
+                .with_attribute((
+                    XmlAttr::Rid.as_str(),
+                    XmlAttrValue::Custom(&part.id).as_str(),
+                ))
+                .write_empty()?;
+        }
+
+        let section = &document.section_properties;
+        // The raw elements go around the supported ones following the order of the schema
+        let (trailing, leading): (Vec<&String>, Vec<&String>) = section
+            .raw_elements
+            .iter()
+            .partition(|raw| SECTION_ELEMENTS_AFTER_V_ALIGN.contains(&raw_element_name(raw)));
+        for raw in leading {
+            write_raw(writer, raw)?;
+        }
+
+        if let Some(alignment) = &section.vertical_alignment {
+            write_attribute_element(
+                writer,
+                &XmlElement::PageVAlign,
+                &XmlAttr::Val,
+                &XmlAttrValue::Custom(&alignment.to_string()),
+            )?;
+        }
+
+        for name in SECTION_ELEMENTS_AFTER_V_ALIGN {
+            if name == XmlElement::TitlePage.as_str() {
+                if section.first_header.is_some() || section.first_footer.is_some() {
+                    writer
+                        .create_element(XmlElement::TitlePage.as_str())
+                        .write_empty()?;
+                }
+                continue;
+            }
+            for raw in trailing.iter().filter(|raw| raw_element_name(raw) == name) {
+                write_raw(writer, raw)?;
+            }
         }
         Ok(())
     })?;
     Ok(())
 }
 
-fn write_paragraph(writer: &mut XmlWriter, paragraph: &Paragraph) -> XmlResult {
+/// Children of `w:sectPr` that follow `w:vAlign` in the schema, in order.
+const SECTION_ELEMENTS_AFTER_V_ALIGN: [&str; 8] = [
+    "w:noEndnote",
+    "w:titlePg",
+    "w:textDirection",
+    "w:bidi",
+    "w:rtlGutter",
+    "w:docGrid",
+    "w:printerSettings",
+    "w:sectPrChange",
+];
+
+/// Qualified name of the root element of raw XML (e.g. `w:pgMar` for `<w:pgMar w:top="1440"/>`).
+fn raw_element_name(raw: &str) -> &str {
+    raw.trim_start()
+        .trim_start_matches('<')
+        .split(|c: char| c.is_whitespace() || c == '/' || c == '>')
+        .next()
+        .unwrap_or_default()
+}
+
+/// Write already serialized XML as is.
+fn write_raw(writer: &mut XmlWriter, raw: &str) -> XmlResult {
+    writer.write_event(Event::Text(BytesText::from_escaped(raw)))?;
+    Ok(())
+}
+
+fn write_paragraph(
+    writer: &mut XmlWriter,
+    paragraph: &Paragraph,
+    options: &GenerateOptions,
+) -> XmlResult {
+    write_marked_paragraph(writer, paragraph, options, false)
+}
+
+/// Write a paragraph, starting with the footnote mark when `footnote_mark` is set. Word shows the number of a
+/// footnote through the mark at the start of its first paragraph.
+fn write_marked_paragraph(
+    writer: &mut XmlWriter,
+    paragraph: &Paragraph,
+    options: &GenerateOptions,
+    footnote_mark: bool,
+) -> XmlResult {
     let element = writer.create_element(XmlElement::Paragraph.as_str());
     element.write_inner_content(|writer| {
+        if paragraph.properties.has_formatting() {
+            write_paragraph_properties(writer, &paragraph.properties)?;
+        }
+
+        if footnote_mark {
+            writer
+                .create_element(XmlElement::Run.as_str())
+                .write_inner_content(|writer| {
+                    write_run_properties(writer, &footnote_reference_properties())?;
+                    writer
+                        .create_element(XmlElement::FootnoteRef.as_str())
+                        .write_empty()?;
+                    Ok(())
+                })?;
+        }
+
         for child in &paragraph.children {
             match child {
-                ParagraphChild::Run(run) => write_run(writer, run)?,
-                ParagraphChild::Hyperlink(hyperlink) => write_hyperlink(writer, hyperlink)?,
+                ParagraphChild::Run(run) => write_run(writer, run, options)?,
+                ParagraphChild::Hyperlink(hyperlink) => {
+                    write_hyperlink(writer, hyperlink, options)?
+                }
+                ParagraphChild::SimpleField(field) => write_simple_field(writer, field, options)?,
+                ParagraphChild::CommentRangeStart(id) => {
+                    write_number_element(writer, &XmlElement::CommentRangeStart, &XmlAttr::Id, *id)?
+                }
+                ParagraphChild::CommentRangeEnd(id) => {
+                    write_number_element(writer, &XmlElement::CommentRangeEnd, &XmlAttr::Id, *id)?
+                }
+                ParagraphChild::Drawing(drawing) => write_drawing(writer, drawing)?,
+            }
+        }
+        Ok(())
+    })?;
+    Ok(())
+}
+
+fn write_paragraph_properties(
+    writer: &mut XmlWriter,
+    properties: &ParagraphProperties,
+) -> XmlResult {
+    let element = writer.create_element(XmlElement::ParagraphProps.as_str());
+    element.write_inner_content(|writer| {
+        if let Some(style) = &properties.style {
+            write_attribute_element(
+                writer,
+                &XmlElement::ParagraphStyle,
+                &XmlAttr::Val,
+                &XmlAttrValue::Custom(style),
+            )?;
+        }
+
+        //Off is only written when set explicitly, to override the style of the paragraph
+        for (value, element) in [
+            (properties.keep_next, XmlElement::KeepNext),
+            (properties.keep_lines, XmlElement::KeepLines),
+        ] {
+            match value {
+                Some(true) => {
+                    writer.create_element(element.as_str()).write_empty()?;
+                }
+                Some(false) => write_attribute_element(
+                    writer,
+                    &element,
+                    &XmlAttr::Val,
+                    &XmlAttrValue::Custom("0"),
+                )?,
+                None => (),
             }
         }
+
+        if properties.page_break_before {
+            writer
+                .create_element(XmlElement::PageBreakBefore.as_str())
+                .write_empty()?;
+        }
+
+        if let Some(frame) = &properties.frame {
+            let mut buffers = [itoa::Buffer::new(); 4];
+            let [w, h, x, y] = &mut buffers;
+            let values = [
+                (XmlAttr::W, format_number(w, frame.width)),
+                (XmlAttr::H, format_number(h, frame.height)),
+                (XmlAttr::HRule, format_value(frame.height_rule.as_ref())),
+                (XmlAttr::Wrap, format_value(frame.wrap.as_ref())),
+                (XmlAttr::HAnchor, format_value(frame.h_anchor.as_ref())),
+                (XmlAttr::VAnchor, format_value(frame.v_anchor.as_ref())),
+                (XmlAttr::FrameX, format_number(x, frame.x)),
+                (XmlAttr::FrameY, format_number(y, frame.y)),
+            ];
+            let mut element = writer.create_element(XmlElement::FramePr.as_str());
+            for (attr, value) in &values {
+                if let Some(value) = value {
+                    element = element.with_attribute((attr.as_str(), &**value));
+                }
+            }
+            element.write_empty()?;
+        }
+
+        if let Some(shading) = &properties.shading {
+            let val = shading.val.to_string();
+            let mut element = writer
+                .create_element(XmlElement::Shading.as_str())
+                .with_attribute((XmlAttr::Val.as_str(), XmlAttrValue::Custom(&val).as_str()));
+            // The pattern color means nothing without a pattern, Word drops it as well
+            let color = shading
+                .color
+                .as_ref()
+                .filter(|_| shading.val != ParagraphShadingValues::Clear)
+                .map(|c| c.value());
+            if let Some(color) = &color {
+                element = element.with_attribute((XmlAttr::Color.as_str(), color.as_str()));
+            }
+            let fill = shading.fill.as_ref().map(|c| c.value());
+            if let Some(fill) = &fill {
+                element = element.with_attribute((XmlAttr::Fill.as_str(), fill.as_str()));
+            }
+            element.write_empty()?;
+        }
+
+        if !properties.tabs.is_empty() {
+            let element = writer.create_element(XmlElement::Tabs.as_str());
+            element.write_inner_content(|writer| {
+                let mut buffer = itoa::Buffer::new();
+                for tab in &properties.tabs {
+                    let val = tab.val.to_string();
+                    let position = buffer.format(tab.position);
+                    let leader = tab.leader.as_ref().map(|l| l.to_string());
+                    let mut element = writer
+                        .create_element(XmlElement::Tab.as_str())
+                        .with_attribute((XmlAttr::Val.as_str(), val.as_str()));
+                    if let Some(leader) = &leader {
+                        element =
+                            element.with_attribute((XmlAttr::Leader.as_str(), leader.as_str()));
+                    }
+                    element
+                        .with_attribute((XmlAttr::Pos.as_str(), position))
+                        .write_empty()?;
+                }
+                Ok(())
+            })?;
+        }
+
+        for (condition, element) in [
+            (properties.kinsoku, XmlElement::Kinsoku),
+            (properties.overflow_punct, XmlElement::OverflowPunct),
+        ] {
+            if condition {
+                writer.create_element(element.as_str()).write_empty()?;
+            }
+        }
+
+        if let Some(spacing) = &properties.spacing {
+            let on_off = |v: bool| Cow::Borrowed(if v { "1" } else { "0" });
+            let mut buffers = [itoa::Buffer::new(); 3];
+            let [before, after, line] = &mut buffers;
+            let values = [
+                (XmlAttr::Before, format_number(before, spacing.before)),
+                (
+                    XmlAttr::BeforeAutospacing,
+                    spacing.before_autospacing.map(on_off),
+                ),
+                (XmlAttr::After, format_number(after, spacing.after)),
+                (
+                    XmlAttr::AfterAutospacing,
+                    spacing.after_autospacing.map(on_off),
+                ),
+                (XmlAttr::Line, format_number(line, spacing.line)),
+                (XmlAttr::LineRule, format_value(spacing.line_rule.as_ref())),
+            ];
+            let mut element = writer.create_element(XmlElement::ParagraphSpacing.as_str());
+            for (attr, value) in &values {
+                if let Some(value) = value {
+                    element = element.with_attribute((attr.as_str(), &**value));
+                }
+            }
+            element.write_empty()?;
+        }
+
+        if let Some(indentation) = &properties.indentation {
+            let mut buffers = [itoa::Buffer::new(); 4];
+            let [left, right, first_line, hanging] = &mut buffers;
+            let values = [
+                (XmlAttr::Left, format_number(left, indentation.left)),
+                (XmlAttr::Right, format_number(right, indentation.right)),
+                (
+                    XmlAttr::FirstLine,
+                    format_number(first_line, indentation.first_line),
+                ),
+                (
+                    XmlAttr::Hanging,
+                    format_number(hanging, indentation.hanging),
+                ),
+            ];
+            let mut element = writer.create_element(XmlElement::Indentation.as_str());
+            for (attr, value) in &values {
+                if let Some(value) = value {
+                    element = element.with_attribute((attr.as_str(), &**value));
+                }
+            }
+            element.write_empty()?;
+        }
+
+        if let Some(justification) = &properties.justification {
+            write_attribute_element(
+                writer,
+                &XmlElement::Justification,
+                &XmlAttr::Val,
+                &XmlAttrValue::Custom(&justification.to_string()),
+            )?;
+        }
+
+        if let Some(direction) = &properties.text_direction {
+            write_attribute_element(
+                writer,
+                &XmlElement::TextDirection,
+                &XmlAttr::Val,
+                &XmlAttrValue::Custom(&direction.to_string()),
+            )?;
+        }
+
+        if let Some(alignment) = &properties.text_alignment {
+            write_attribute_element(
+                writer,
+                &XmlElement::TextAlignment,
+                &XmlAttr::Val,
+                &XmlAttrValue::Custom(&alignment.to_string()),
+            )?;
+        }
+
+        if let Some(wrap) = &properties.textbox_tight_wrap {
+            write_attribute_element(
+                writer,
+                &XmlElement::TextboxTightWrap,
+                &XmlAttr::Val,
+                &XmlAttrValue::Custom(&wrap.to_string()),
+            )?;
+        }
+
+        if let Some(level) = properties.outline_level {
+            write_number_element(writer, &XmlElement::OutlineLvl, &XmlAttr::Val, level)?;
+        }
+
+        if let Some(div_id) = properties.div_id {
+            write_number_element(writer, &XmlElement::DivId, &XmlAttr::Val, div_id)?;
+        }
+
+        if let Some(bits) = &properties.conditional_formatting {
+            write_attribute_element(
+                writer,
+                &XmlElement::CnfStyle,
+                &XmlAttr::Val,
+                &XmlAttrValue::Custom(bits),
+            )?;
+        }
+
+        if let Some(run_properties) = &properties.default_run_properties
+            && run_properties.has_formatting()
+        {
+            write_run_properties(writer, run_properties)?;
+        }
         Ok(())
     })?;
     Ok(())
 }
 
-fn write_hyperlink(writer: &mut XmlWriter, hyperlink: &Hyperlink) -> XmlResult {
-    let _element = writer
-        .create_element(XmlElement::Hyperlink.as_str())
+fn write_simple_field(
+    writer: &mut XmlWriter,
+    field: &SimpleField,
+    options: &GenerateOptions,
+) -> XmlResult {
+    writer
+        .create_element(XmlElement::SimpleField.as_str())
         .with_attribute((
-            XmlAttr::Rid.as_str(),
-            XmlAttrValue::Custom(&hyperlink.id).as_str(),
+            XmlAttr::Instr.as_str(),
+            XmlAttrValue::Custom(&field.instruction).as_str(),
         ))
         .write_inner_content(|writer| {
-            for run in &hyperlink.runs {
-                write_run(writer, run)?;
+            for run in &field.runs {
+                write_run(writer, run, options)?;
             }
             Ok(())
         })?;
     Ok(())
 }
 
-fn write_run(writer: &mut XmlWriter, run: &Run) -> XmlResult {
+fn write_hyperlink(
+    writer: &mut XmlWriter,
+    hyperlink: &Hyperlink,
+    options: &GenerateOptions,
+) -> XmlResult {
+    let mut element = writer.create_element(XmlElement::Hyperlink.as_str());
+    let history = hyperlink
+        .history
+        .map(|h| String::from(if h { "1" } else { "0" }));
+    for (attr, value) in [
+        (XmlAttr::Rid, &hyperlink.id),
+        (XmlAttr::Anchor, &hyperlink.anchor),
+        (XmlAttr::Tooltip, &hyperlink.tooltip),
+        (XmlAttr::History, &history),
+    ] {
+        if let Some(value) = value {
+            element = element.with_attribute((attr.as_str(), value.as_str()));
+        }
+    }
+    let _element = element.write_inner_content(|writer| {
+        for run in &hyperlink.runs {
+            write_run(writer, run, options)?;
+        }
+        Ok(())
+    })?;
+    Ok(())
+}
+
+/// Write an inline picture in a run of its own, with the minimal DrawingML markup consumers require.
+fn write_drawing(writer: &mut XmlWriter, drawing: &Drawing) -> XmlResult {
+    // Drawing ids must be unique in the part. The position of the drawing in the output is, without
+    // having to number the drawings of every paragraph beforehand.
+    let id = (writer.get_ref().get_ref().len() + 1).to_string();
+    let name = format!("Picture {id}");
+    let mut buffers = [itoa::Buffer::new(); 2];
+    let [cx, cy] = &mut buffers;
+    let extent = [
+        (XmlAttr::Cx, cx.format(drawing.width)),
+        (XmlAttr::Cy, cy.format(drawing.height)),
+    ];
+
+    let run = writer.create_element(XmlElement::Run.as_str());
+    run.write_inner_content(|writer| {
+        let element = writer.create_element(XmlElement::Drawing.as_str());
+        element.write_inner_content(|writer| {
+            let inline = writer
+                .create_element(XmlElement::Inline.as_str())
+                .with_attribute((XmlNs::Wp.as_str(), XmlNs::Wp.url()));
+            inline.write_inner_content(|writer| {
+                write_empty_element(writer, &XmlElement::Extent, &extent)?;
+                write_empty_element(
+                    writer,
+                    &XmlElement::DocPr,
+                    &[
+                        (XmlAttr::DrawingId, id.as_str()),
+                        (XmlAttr::Name, name.as_str()),
+                    ],
+                )?;
+                let graphic = writer
+                    .create_element(XmlElement::Graphic.as_str())
+                    .with_attribute((XmlNs::A.as_str(), XmlNs::A.url()));
+                graphic.write_inner_content(|writer| {
+                    let data = writer
+                        .create_element(XmlElement::GraphicData.as_str())
+                        .with_attribute((XmlAttr::Uri.as_str(), XmlNs::Pic.url()));
+                    data.write_inner_content(|writer| {
+                        let pic = writer
+                            .create_element(XmlElement::Pic.as_str())
+                            .with_attribute((XmlNs::Pic.as_str(), XmlNs::Pic.url()));
+                        pic.write_inner_content(|writer| {
+                            write_picture(writer, drawing, &id, &name, &extent)
+                        })?;
+                        Ok(())
+                    })?;
+                    Ok(())
+                })?;
+                Ok(())
+            })?;
+            Ok(())
+        })?;
+        Ok(())
+    })?;
+    Ok(())
+}
+
+fn write_picture(
+    writer: &mut XmlWriter,
+    drawing: &Drawing,
+    id: &str,
+    name: &str,
+    extent: &[(XmlAttr, &str)],
+) -> XmlResult {
+    writer
+        .create_element(XmlElement::NvPicPr.as_str())
+        .write_inner_content(|writer| {
+            write_empty_element(
+                writer,
+                &XmlElement::CNvPr,
+                &[(XmlAttr::DrawingId, id), (XmlAttr::Name, name)],
+            )?;
+            write_empty_element(writer, &XmlElement::CNvPicPr, &[])
+        })?;
+    writer
+        .create_element(XmlElement::BlipFill.as_str())
+        .write_inner_content(|writer| {
+            write_empty_element(
+                writer,
+                &XmlElement::Blip,
+                &[(XmlAttr::Embed, drawing.image_id.as_str())],
+            )?;
+            writer
+                .create_element(XmlElement::Stretch.as_str())
+                .write_inner_content(|writer| {
+                    write_empty_element(writer, &XmlElement::FillRect, &[])
+                })?;
+            Ok(())
+        })?;
+    writer
+        .create_element(XmlElement::SpPr.as_str())
+        .write_inner_content(|writer| {
+            writer
+                .create_element(XmlElement::Xfrm.as_str())
+                .write_inner_content(|writer| {
+                    write_empty_element(
+                        writer,
+                        &XmlElement::Off,
+                        &[(XmlAttr::X, "0"), (XmlAttr::Y, "0")],
+                    )?;
+                    write_empty_element(writer, &XmlElement::Ext, extent)
+                })?;
+            writer
+                .create_element(XmlElement::PrstGeom.as_str())
+                .with_attribute((XmlAttr::Prst.as_str(), "rect"))
+                .write_inner_content(|writer| {
+                    write_empty_element(writer, &XmlElement::AvLst, &[])
+                })?;
+            Ok(())
+        })?;
+    Ok(())
+}
+
+fn footnote_reference_properties() -> RunProperties {
+    RunProperties {
+        style: Some(String::from(Style::FOOTNOTE_REFERENCE)),
+        ..Default::default()
+    }
+}
+
+/// Write the image of a legacy VML drawing, as the bare shape pointing to it. The rest of the drawing is not kept when
+/// reading.
+fn write_legacy_picture(writer: &mut XmlWriter, id: &str) -> XmlResult {
+    let element = writer.create_element(XmlElement::Pict.as_str());
+    element.write_inner_content(|writer| {
+        writer
+            .create_element(XmlElement::Shape.as_str())
+            .with_attribute((XmlNs::V.as_str(), XmlNs::V.url()))
+            .write_inner_content(|writer| {
+                write_empty_element(writer, &XmlElement::ImageData, &[(XmlAttr::Rid, id)])
+            })?;
+        Ok(())
+    })?;
+    Ok(())
+}
+
+fn write_run(writer: &mut XmlWriter, run: &Run, options: &GenerateOptions) -> XmlResult {
+    // Footnote marks without a style of their own are shown as footnote marks
+    let properties = match run.footnote_reference.is_some() && run.properties.style.is_none() {
+        true => Cow::Owned(RunProperties {
+            style: footnote_reference_properties().style,
+            ..run.properties.clone()
+        }),
+        false => Cow::Borrowed(&run.properties),
+    };
     let element = writer.create_element(XmlElement::Run.as_str());
     element.write_inner_content(|writer| {
-        if run.properties.has_formatting() {
-            write_run_properties(writer, &run.properties)?;
+        if options.always_emit_rpr || properties.has_formatting() {
+            write_run_properties(writer, &properties)?;
         }
 
-        if run.space_preserve {
+        match &run.break_type {
+            Some(BreakType::TextWrapping) => {
+                writer
+                    .create_element(XmlElement::Break.as_str())
+                    .write_empty()?;
+            }
+            Some(break_type) => write_attribute_element(
+                writer,
+                &XmlElement::Break,
+                &XmlAttr::Type,
+                &XmlAttrValue::Custom(&break_type.to_string()),
+            )?,
+            None => (),
+        }
+
+        let references = [
+            (run.comment_reference, XmlElement::CommentReference),
+            (run.footnote_reference, XmlElement::FootnoteReference),
+        ];
+        for (id, element) in &references {
+            if let Some(id) = id {
+                write_number_element(writer, element, &XmlAttr::Id, *id)?;
+            }
+        }
+        if let Some(id) = &run.picture_id {
+            write_legacy_picture(writer, id)?;
+        }
+        if run.text.is_empty()
+            && (run.break_type.is_some()
+                || run.picture_id.is_some()
+                || references.iter().any(|(id, _)| id.is_some()))
+        {
+            return Ok(());
+        }
+
+        let text = sanitize_text(&run.text);
+        if run.space_preserve || Run::has_surrounding_whitespace(&text) {
             let element = writer.create_element(XmlElement::Text.as_str());
             element
                 .with_attribute((XmlAttr::Space.as_str(), XmlAttrValue::Preserve.as_str()))
-                .write_text_content(BytesText::new(&run.text))?;
+                .write_text_content(BytesText::new(&text))?;
         } else {
             let element = writer.create_element(XmlElement::Text.as_str());
-            element.write_text_content(BytesText::new(&run.text))?;
+            element.write_text_content(BytesText::new(&text))?;
         }
 
         Ok(())
@@ -184,14 +1393,67 @@ fn write_run(writer: &mut XmlWriter, run: &Run) -> XmlResult {
     Ok(())
 }
 
+/// Check that every element of the paragraphs can be written completely. Elements missing required data
+/// would otherwise result in invalid XML.
+fn check_writable(paragraphs: &[Paragraph]) -> Result<(), RudocxError> {
+    for child in paragraphs.iter().flat_map(|p| &p.children) {
+        match child {
+            ParagraphChild::Hyperlink(hyperlink)
+                if hyperlink.id.is_none() && hyperlink.anchor.is_none() =>
+            {
+                return Err(RudocxError::Unsupported(String::from(
+                    "hyperlink without relationship id or anchor",
+                )));
+            }
+            ParagraphChild::SimpleField(field) if field.instruction.trim().is_empty() => {
+                return Err(RudocxError::Unsupported(String::from(
+                    "field without instruction",
+                )));
+            }
+            ParagraphChild::Drawing(drawing) if drawing.image_id.is_empty() => {
+                return Err(RudocxError::Unsupported(String::from(
+                    "drawing without image relationship id",
+                )));
+            }
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+/// Whether the character is allowed in XML 1.0. Control characters other than tab, line feed and
+/// carriage return are not, not even escaped.
+fn is_xml_char(c: char) -> bool {
+    matches!(c, '\t' | '\n' | '\r' | '\u{20}'..='\u{D7FF}' | '\u{E000}'..='\u{FFFD}' | '\u{10000}'..)
+}
+
+/// Remove the characters not allowed in XML 1.0, which would make the part unreadable.
+fn sanitize_text(text: &str) -> Cow<'_, str> {
+    match text.chars().all(is_xml_char) {
+        true => Cow::Borrowed(text),
+        false => Cow::Owned(text.chars().filter(|c| is_xml_char(*c)).collect()),
+    }
+}
+
 fn write_run_properties(writer: &mut XmlWriter, properties: &RunProperties) -> XmlResult {
     let element = writer.create_element(XmlElement::RunProps.as_str());
     element.write_inner_content(|writer| {
+        if let Some(style) = &properties.style {
+            write_attribute_element(
+                writer,
+                &XmlElement::RunStyle,
+                &XmlAttr::Val,
+                &XmlAttrValue::Custom(style),
+            )?;
+        }
+
         for (condition, element) in [
             (properties.bold, XmlElement::Bold),
             (properties.italic, XmlElement::Italic),
             (properties.strike, XmlElement::Strike),
             (properties.dstrike, XmlElement::DStrike),
+            (properties.no_proof, XmlElement::NoProof),
+            (properties.rtl, XmlElement::Rtl),
         ] {
             if condition {
                 writer.create_element(element.as_str()).write_empty()?;
@@ -207,37 +1469,19 @@ fn write_run_properties(writer: &mut XmlWriter, properties: &RunProperties) -> X
             )?;
         }
 
-        if let Some(color) = &properties.color {
-            write_attribute_element(
-                writer,
-                &XmlElement::Color,
-                &XmlAttr::Val,
-                &XmlAttrValue::Custom(&color.value()),
-            )?;
+        if properties.color.is_some() || properties.theme_color.is_some() {
+            write_color(writer, properties)?;
         }
 
-        if let Some(size) = &properties.size {
-            let size_str = size.to_string();
-            write_attribute_element(
-                writer,
-                &XmlElement::Size,
-                &XmlAttr::Val,
-                &XmlAttrValue::Custom(&size_str),
-            )?;
+        //Complex script text (e.g. Arabic) is sized by `w:szCs`, mirror the size so every run of the text matches
+        if let Some(size) = properties.size {
+            for element in [XmlElement::Size, XmlElement::SizeCs] {
+                write_number_element(writer, &element, &XmlAttr::Val, size)?;
+            }
         }
 
         if let Some(font_set) = &properties.font {
-            match font_set.get_hint_value() {
-                FontType::Default => (),
-                _ => {
-                    if let Ok(val) = font_set.get_hint() {
-                        let attr = format!("w:{}", font_set.get_hint_value());
-                        let mut font_element = writer.create_element(XmlElement::Fonts.as_str());
-                        font_element = font_element.with_attribute((attr.as_str(), val.as_str()));
-                        font_element.write_empty()?;
-                    }
-                }
-            }
+            write_fonts(writer, font_set)?;
         }
 
         if let Some(highlight) = &properties.highlight {
@@ -258,13 +1502,20 @@ fn write_run_properties(writer: &mut XmlWriter, properties: &RunProperties) -> X
             )?;
         }
 
-        if let Some(spacing) = &properties.spacing {
-            let spacing_str = spacing.to_string();
+        if let Some(spacing) = properties.spacing {
+            write_number_element(writer, &XmlElement::Spacing, &XmlAttr::Val, spacing)?;
+        }
+
+        if let Some(position) = properties.position {
+            write_number_element(writer, &XmlElement::Position, &XmlAttr::Val, position)?;
+        }
+
+        if let Some(lang) = &properties.lang {
             write_attribute_element(
                 writer,
-                &XmlElement::Spacing,
+                &XmlElement::Lang,
                 &XmlAttr::Val,
-                &XmlAttrValue::Custom(&spacing_str),
+                &XmlAttrValue::Custom(lang),
             )?;
         }
 
@@ -273,6 +1524,69 @@ fn write_run_properties(writer: &mut XmlWriter, properties: &RunProperties) -> X
     Ok(())
 }
 
+fn write_fonts(writer: &mut XmlWriter, font_set: &FontSet) -> XmlResult {
+    let mut element = writer.create_element(XmlElement::Fonts.as_str());
+    //`w:hint` only accepts the scripts that can share characters with others
+    let hint = font_set.get_hint_value();
+    if matches!(hint, FontType::EastAsia | FontType::Cs) {
+        element = element.with_attribute((XmlAttr::Hint.as_str(), hint.to_string().as_str()));
+    }
+    for (attr, value) in [
+        (XmlAttr::Ascii, &font_set.ascii),
+        (XmlAttr::HAnsi, &font_set.hi_ansi),
+        (XmlAttr::EastAsia, &font_set.east_asia),
+        (XmlAttr::Cs, &font_set.cs),
+        (XmlAttr::AsciiTheme, &font_set.ascii_theme),
+        (XmlAttr::HAnsiTheme, &font_set.hi_ansi_theme),
+        (XmlAttr::EastAsiaTheme, &font_set.east_asia_theme),
+        (XmlAttr::CsTheme, &font_set.cs_theme),
+    ] {
+        if let Some(value) = value {
+            element = element.with_attribute((attr.as_str(), value.as_str()));
+        }
+    }
+    element.write_empty()?;
+    Ok(())
+}
+
+fn write_color(writer: &mut XmlWriter, properties: &RunProperties) -> XmlResult {
+    //`w:val` is required, let the consumer resolve it from the theme if there is no HEX color
+    let val = properties
+        .color
+        .as_ref()
+        .map_or(String::from("auto"), |c| c.value());
+    let mut element = writer
+        .create_element(XmlElement::Color.as_str())
+        .with_attribute((XmlAttr::Val.as_str(), XmlAttrValue::Custom(&val).as_str()));
+    for (attr, value) in [
+        (XmlAttr::ThemeColor, &properties.theme_color),
+        (XmlAttr::ThemeTint, &properties.theme_tint),
+        (XmlAttr::ThemeShade, &properties.theme_shade),
+    ] {
+        if let Some(value) = value {
+            element = element.with_attribute((attr.as_str(), value.as_str()));
+        }
+    }
+    element.write_empty()?;
+    Ok(())
+}
+
+fn write_empty_element(
+    writer: &mut XmlWriter,
+    element: &XmlElement,
+    attributes: &[(XmlAttr, &str)],
+) -> XmlResult {
+    writer
+        .create_element(element.as_str())
+        .with_attributes(
+            attributes
+                .iter()
+                .map(|(name, value)| (name.as_str(), *value)),
+        )
+        .write_empty()?;
+    Ok(())
+}
+
 fn write_attribute_element(
     writer: &mut XmlWriter,
     element: &XmlElement,
@@ -285,3 +1599,716 @@ fn write_attribute_element(
         .write_empty()?;
     Ok(())
 }
+
+/// Same as [write_attribute_element] for a number, formatted on the stack. Runs are written by the tens of
+/// thousands in large documents, so their numbers are not allocated.
+fn write_number_element(
+    writer: &mut XmlWriter,
+    element: &XmlElement,
+    attr_name: &XmlAttr,
+    value: impl itoa::Integer,
+) -> XmlResult {
+    let mut buffer = itoa::Buffer::new();
+    write_attribute_element(
+        writer,
+        element,
+        attr_name,
+        &XmlAttrValue::Custom(buffer.format(value)),
+    )
+}
+
+/// Format an optional number into `buffer`, for elements with several numeric attributes.
+fn format_number(
+    buffer: &mut itoa::Buffer,
+    value: Option<impl itoa::Integer>,
+) -> Option<Cow<'_, str>> {
+    Some(Cow::Borrowed(buffer.format(value?)))
+}
+
+/// Format an optional enum value of an element with several attributes.
+fn format_value(value: Option<&impl std::fmt::Display>) -> Option<Cow<'static, str>> {
+    value.map(|v| Cow::Owned(v.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::{
+        FrameAnchor, FrameHeightRule, FrameProperties, FrameWrap, HLColor, HexColor, PageVAlign,
+        ParagraphShading, ParagraphSpacing, ParagraphTBoxTightWrapValues, ParagraphTab,
+        ParagraphTabLeaders, ParagraphTabType, ParagraphTextAlignValues, ParagraphTextDirValues,
+        RowHeight,
+    };
+
+    #[test]
+    fn test_clear_shading_omits_color() {
+        let document = Document {
+            paragraphs: vec![Paragraph {
+                properties: ParagraphProperties {
+                    shading: Some(ParagraphShading {
+                        val: ParagraphShadingValues::Clear,
+                        color: Some(HexColor::new("FF0000")),
+                        fill: Some(HexColor::new("00FF00")),
+                    }),
+                    ..Default::default()
+                },
+                children: vec![ParagraphChild::Run(Run::from("Shaded".to_string()))],
+            }],
+            ..Default::default()
+        };
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains(r#"<w:shd w:val="clear" w:fill="00FF00"/>"#));
+        assert!(!xml.contains("w:color"));
+    }
+
+    #[test]
+    fn test_theme_color_round_trip() {
+        let document = Document {
+            paragraphs: vec![Paragraph {
+                children: vec![ParagraphChild::Run(Run {
+                    properties: RunProperties {
+                        theme_color: Some(String::from("accent1")),
+                        theme_shade: Some(String::from("BF")),
+                        ..Default::default()
+                    },
+                    text: String::from("Themed"),
+                    space_preserve: false,
+                    picture_id: None,
+                    comment_reference: None,
+                    footnote_reference: None,
+                    break_type: None,
+                })],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains(r#"<w:color w:val="auto" w:themeColor="accent1" w:themeShade="BF"/>"#));
+        assert_eq!(crate::xml::parse(&xml).unwrap(), document);
+    }
+
+    #[test]
+    fn test_empty_fonts_round_trip() {
+        let document = Document {
+            paragraphs: vec![Paragraph {
+                children: vec![ParagraphChild::Run(Run {
+                    properties: RunProperties {
+                        font: Some(FontSet::theme_default()),
+                        ..Default::default()
+                    },
+                    text: String::from("Theme font"),
+                    ..Default::default()
+                })],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains("<w:rPr><w:rFonts/></w:rPr>"));
+
+        let parsed = crate::xml::parse(&xml).unwrap();
+        assert_eq!(parsed, document);
+        assert!(parsed
+            .runs()
+            .next()
+            .unwrap()
+            .properties
+            .font
+            .as_ref()
+            .unwrap()
+            .is_theme_default());
+    }
+
+    #[test]
+    fn test_page_vertical_alignment_round_trip() {
+        let mut document = Document::from_text("Centered title page");
+        document.section_properties.vertical_alignment = Some(PageVAlign::Center);
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains(r#"<w:sectPr><w:vAlign w:val="center"/></w:sectPr>"#));
+        assert_eq!(crate::xml::parse(&xml).unwrap(), document);
+    }
+
+    #[test]
+    fn test_row_height_round_trip() {
+        let row = TableRow {
+            height: Some(RowHeight::exact(400)),
+        };
+
+        let xml = generate_table_row(&row).unwrap();
+        assert!(xml.contains(r#"<w:trPr><w:trHeight w:val="400" w:hRule="exact"/></w:trPr>"#));
+        assert_eq!(
+            crate::xml::parse_table_row(&xml, Default::default()).unwrap(),
+            row
+        );
+    }
+
+    #[test]
+    fn test_invalid_xml_chars() {
+        let document = Document::from_text("Bell\u{0007}and\u{0001}tab\t");
+
+        let xml = generate(&document).unwrap();
+        assert!(!xml.contains('\u{0001}'));
+        assert_eq!(crate::xml::parse(&xml).unwrap().text(), "Bellandtab\t");
+
+        let options = GenerateOptions {
+            reject_invalid_chars: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            generate_with(&document, &options),
+            Err(RudocxError::InvalidXmlChar('\u{0007}'))
+        ));
+        assert!(generate_with(&Document::from_text("Valid\ttext"), &options).is_ok());
+
+        let mut document = Document::from_text("Valid");
+        let footnote = Document::from_text("Bell\u{0007}").paragraphs;
+        document.footnotes.push(Footnote::new(1, footnote));
+        assert!(matches!(
+            generate_with(&document, &options),
+            Err(RudocxError::InvalidXmlChar('\u{0007}'))
+        ));
+    }
+
+    #[test]
+    fn test_always_emit_rpr() {
+        let mut document = Document::from_text("Plain");
+        document.paragraphs[0]
+            .children
+            .push(ParagraphChild::Hyperlink(Hyperlink {
+                id: Some(String::from("rId1")),
+                runs: vec![Run {
+                    text: String::from("link"),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }));
+
+        let xml = generate(&document).unwrap();
+        assert!(!xml.contains("<w:rPr"));
+
+        let options = GenerateOptions {
+            always_emit_rpr: true,
+            ..Default::default()
+        };
+        let xml = generate_with(&document, &options).unwrap();
+        assert_eq!(xml.matches("<w:r>").count(), 2);
+        assert_eq!(xml.matches("<w:r><w:rPr").count(), 2);
+        assert_eq!(crate::xml::parse(&xml).unwrap(), document);
+    }
+
+    #[test]
+    fn test_no_proof_round_trip() {
+        let document = Document {
+            paragraphs: vec![Paragraph {
+                children: vec![ParagraphChild::Run(Run {
+                    properties: RunProperties {
+                        no_proof: true,
+                        ..Default::default()
+                    },
+                    text: String::from("fn main()"),
+                    ..Default::default()
+                })],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains("<w:rPr><w:noProof/></w:rPr>"));
+        assert_eq!(crate::xml::parse(&xml).unwrap(), document);
+
+        let disabled = crate::xml::parse(
+            r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>
+                <w:p><w:r><w:rPr><w:noProof w:val="0"/></w:rPr><w:t>Checked</w:t></w:r></w:p>
+            </w:body></w:document>"#,
+        )
+        .unwrap();
+        assert!(!disabled.runs().next().unwrap().properties.no_proof);
+    }
+
+    #[test]
+    fn test_east_asian_line_breaking_round_trip() {
+        let document = Document {
+            paragraphs: vec![Paragraph {
+                properties: ParagraphProperties::default()
+                    .with_kinsoku(true)
+                    .with_overflow_punct(true),
+                children: vec![ParagraphChild::Run(Run {
+                    text: String::from("「日本語」"),
+                    ..Default::default()
+                })],
+            }],
+            ..Default::default()
+        };
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains("<w:pPr><w:kinsoku/><w:overflowPunct/></w:pPr>"));
+        assert_eq!(crate::xml::parse(&xml).unwrap(), document);
+
+        let disabled = xml.replace(
+            "<w:kinsoku/><w:overflowPunct/>",
+            r#"<w:kinsoku w:val="0"/><w:overflowPunct w:val="false"/>"#,
+        );
+        let properties = &crate::xml::parse(&disabled).unwrap().paragraphs[0].properties;
+        assert!(!properties.kinsoku);
+        assert!(!properties.overflow_punct);
+    }
+
+    #[test]
+    fn test_conditional_formatting_round_trip() {
+        let mut document = Document::from_text("Header cell");
+        document.paragraphs[0].properties.conditional_formatting =
+            Some(String::from("100000000000"));
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains(r#"<w:pPr><w:cnfStyle w:val="100000000000"/></w:pPr>"#));
+        assert_eq!(crate::xml::parse(&xml).unwrap(), document);
+    }
+
+    #[test]
+    fn test_frame_round_trip() {
+        let mut document = Document::from_text("Pull quote");
+        document.paragraphs[0].properties.frame = Some(FrameProperties {
+            width: Some(2880),
+            height: Some(1440),
+            height_rule: Some(FrameHeightRule::AtLeast),
+            wrap: Some(FrameWrap::Around),
+            h_anchor: Some(FrameAnchor::Page),
+            v_anchor: Some(FrameAnchor::Text),
+            x: Some(1440),
+            y: Some(-360),
+        });
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains(
+            r#"<w:framePr w:w="2880" w:h="1440" w:hRule="atLeast" w:wrap="around" w:hAnchor="page" w:vAnchor="text" w:x="1440" w:y="-360"/>"#
+        ));
+        assert_eq!(crate::xml::parse(&xml).unwrap(), document);
+    }
+
+    #[test]
+    fn test_highlight_none_round_trip() {
+        let mut document = Document::from_text("Not highlighted");
+        document.runs_mut().for_each(|r| {
+            r.properties.highlight = Some(HLColor { value: None });
+        });
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains(r#"<w:highlight w:val="none"/>"#));
+        assert_eq!(crate::xml::parse(&xml).unwrap(), document);
+    }
+
+    #[test]
+    fn test_default_run_properties_round_trip() {
+        let mut document = Document::from_text("Heading");
+        document.paragraphs[0].properties.default_run_properties = Some(RunProperties {
+            bold: true,
+            ..Default::default()
+        });
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains("<w:pPr><w:rPr><w:b/></w:rPr></w:pPr><w:r><w:t>Heading</w:t></w:r>"));
+        assert_eq!(crate::xml::parse(&xml).unwrap(), document);
+    }
+
+    #[test]
+    fn test_page_breaks() {
+        let mut document = Document::from_text("Before");
+        document.paragraphs[0]
+            .children
+            .push(ParagraphChild::Run(Run::page_break()));
+        document.paragraphs.push(Paragraph::page_break());
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains(r#"<w:r><w:t>Before</w:t></w:r><w:r><w:br w:type="page"/></w:r>"#));
+        assert!(xml.contains("<w:p><w:pPr><w:pageBreakBefore/></w:pPr></w:p>"));
+        assert_eq!(crate::xml::parse(&xml).unwrap(), document);
+    }
+
+    #[test]
+    fn test_empty_default_run_properties_omitted() {
+        let mut document = Document::from_text("Plain");
+        document.paragraphs[0].properties.default_run_properties = Some(RunProperties::default());
+        assert!(!document.paragraphs[0].properties.has_formatting());
+
+        let xml = generate(&document).unwrap();
+        assert!(!xml.contains("<w:pPr"));
+
+        document.paragraphs[0].properties.outline_level = Some(2);
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains(r#"<w:pPr><w:outlineLvl w:val="2"/></w:pPr>"#));
+    }
+
+    #[test]
+    fn test_tab_leaders_round_trip() {
+        let document = Document {
+            paragraphs: vec![Paragraph {
+                properties: ParagraphProperties {
+                    tabs: vec![
+                        ParagraphTab::new(
+                            ParagraphTabType::Right,
+                            9350,
+                            Some(ParagraphTabLeaders::MiddleDot),
+                        ),
+                        ParagraphTab::new(ParagraphTabType::Center, 4680, None),
+                    ],
+                    ..Default::default()
+                },
+                children: vec![ParagraphChild::Run(Run::from("Chapter".to_string()))],
+            }],
+            ..Default::default()
+        };
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains(r#"<w:tab w:val="right" w:leader="middleDot" w:pos="9350"/>"#));
+        assert!(xml.contains(r#"<w:tab w:val="center" w:pos="4680"/>"#));
+        assert_eq!(crate::xml::parse(&xml).unwrap(), document);
+    }
+
+    #[test]
+    fn test_underscore_tab_leader_round_trip() {
+        let mut document = Document::from_text("Signature");
+        document.paragraphs[0].properties.tabs = vec![ParagraphTab::new(
+            ParagraphTabType::Right,
+            8640,
+            Some(ParagraphTabLeaders::from("underscore")),
+        )];
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains(r#"<w:tab w:val="right" w:leader="underscore" w:pos="8640"/>"#));
+        let parsed = crate::xml::parse(&xml).unwrap();
+        assert_eq!(
+            parsed.paragraphs[0].properties.tabs[0].leader,
+            Some(ParagraphTabLeaders::Underscore)
+        );
+        assert_eq!(parsed, document);
+    }
+
+    #[test]
+    fn test_raw_section_elements_round_trip() {
+        let xml = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"
+            xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><w:body>
+            <w:p><w:r><w:t>Text</w:t></w:r></w:p>
+            <w:sectPr>
+                <w:pgSz w:w="11906" w:h="16838"/>
+                <w:pgMar w:top="1134" w:right="851" w:bottom="1134" w:left="1418" w:header="708" w:footer="708" w:gutter="0"/>
+                <w:cols w:space="708"></w:cols>
+                <w:vAlign w:val="center"/>
+                <w:docGrid w:linePitch="360"/>
+                <w:printerSettings r:id="rId9"/>
+                <w:sectPrChange w:id="1" w:author="A"><w:sectPr/></w:sectPrChange>
+            </w:sectPr>
+        </w:body></w:document>"#;
+        let document = crate::xml::parse(xml).unwrap();
+        let section = &document.section_properties;
+        assert_eq!(section.vertical_alignment, Some(PageVAlign::Center));
+        assert_eq!(section.raw_elements.len(), 5);
+
+        let generated = generate(&document).unwrap();
+        assert!(generated.contains(
+            r#"<w:sectPr><w:pgSz w:w="11906" w:h="16838"/><w:pgMar w:top="1134" w:right="851" w:bottom="1134" w:left="1418" w:header="708" w:footer="708" w:gutter="0"/><w:cols w:space="708"></w:cols><w:vAlign w:val="center"/><w:docGrid w:linePitch="360"/><w:sectPrChange w:id="1" w:author="A"><w:sectPr/></w:sectPrChange></w:sectPr>"#
+        ));
+        assert_eq!(crate::xml::parse(&generated).unwrap(), document);
+    }
+
+    #[test]
+    fn test_line_spacing_round_trip() {
+        let mut document = Document::from_text("One and a half");
+        document.paragraphs[0].properties.spacing = Some(ParagraphSpacing {
+            after: Some(120),
+            ..ParagraphSpacing::lines(1.5)
+        });
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains(r#"<w:spacing w:after="120" w:line="360" w:lineRule="auto"/>"#));
+        assert_eq!(crate::xml::parse(&xml).unwrap(), document);
+    }
+
+    #[test]
+    fn test_autospacing_on_off_values() {
+        let mut document = Document::from_text("Autospaced");
+        document.paragraphs[0].properties.spacing = Some(ParagraphSpacing {
+            before_autospacing: Some(true),
+            after_autospacing: Some(false),
+            ..Default::default()
+        });
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains(r#"<w:spacing w:beforeAutospacing="1" w:afterAutospacing="0"/>"#));
+        assert_eq!(crate::xml::parse(&xml).unwrap(), document);
+    }
+
+    #[test]
+    fn test_text_flow_round_trip() {
+        let mut document = Document::from_text("Vertical");
+        let properties = &mut document.paragraphs[0].properties;
+        properties.text_direction = Some(ParagraphTextDirValues::TbRl);
+        properties.text_alignment = Some(ParagraphTextAlignValues::Baseline);
+        properties.textbox_tight_wrap = Some(ParagraphTBoxTightWrapValues::AllLines);
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains(
+            r#"<w:textDirection w:val="tbRl"/><w:textAlignment w:val="baseline"/><w:textboxTightWrap w:val="allLines"/>"#
+        ));
+        assert_eq!(crate::xml::parse(&xml).unwrap(), document);
+    }
+
+    #[test]
+    fn test_automatic_space_preserve() {
+        let mut document = Document::default();
+        for text in [" hello ", "hello"] {
+            document.paragraphs.push(Paragraph {
+                children: vec![ParagraphChild::Run(Run {
+                    text: String::from(text),
+                    ..Default::default()
+                })],
+                ..Default::default()
+            });
+        }
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains(r#"<w:t xml:space="preserve"> hello </w:t>"#));
+        assert!(xml.contains("<w:t>hello</w:t>"));
+        assert!(document.runs().all(|r| !r.space_preserve));
+    }
+
+    #[test]
+    fn test_unsupported_incomplete_elements() {
+        let mut document = Document::from_text("Incomplete");
+        document.paragraphs[0]
+            .children
+            .push(ParagraphChild::Hyperlink(Hyperlink::default()));
+        assert!(matches!(
+            generate(&document),
+            Err(RudocxError::Unsupported(_))
+        ));
+
+        let mut document = Document::from_text("Incomplete");
+        document.paragraphs[0]
+            .children
+            .push(ParagraphChild::SimpleField(SimpleField::new(
+                "",
+                Vec::new(),
+            )));
+        assert!(matches!(
+            generate(&document),
+            Err(RudocxError::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn test_all_fonts_round_trip() {
+        let font = FontSet {
+            ascii: Some(String::from("Arial")),
+            east_asia: Some(String::from("MS Mincho")),
+            cs: Some(String::from("Arial")),
+            hi_ansi_theme: Some(String::from("minorHAnsi")),
+            hint: FontType::EastAsia,
+            ..Default::default()
+        };
+        let document = Document {
+            paragraphs: vec![Paragraph {
+                children: vec![ParagraphChild::Run(Run {
+                    properties: RunProperties {
+                        font: Some(font),
+                        ..Default::default()
+                    },
+                    text: String::from("Mixed scripts"),
+                    ..Default::default()
+                })],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains(
+            r#"<w:rFonts w:hint="eastAsia" w:ascii="Arial" w:eastAsia="MS Mincho" w:cs="Arial" w:hAnsiTheme="minorHAnsi"/>"#
+        ));
+        assert_eq!(crate::xml::parse(&xml).unwrap(), document);
+    }
+
+    #[test]
+    fn test_property_element_names() {
+        let elements = [
+            (XmlElement::ParagraphStyle, "w:pStyle"),
+            (XmlElement::KeepNext, "w:keepNext"),
+            (XmlElement::KeepLines, "w:keepLines"),
+            (XmlElement::PageBreakBefore, "w:pageBreakBefore"),
+            (XmlElement::FramePr, "w:framePr"),
+            (XmlElement::Shading, "w:shd"),
+            (XmlElement::Tabs, "w:tabs"),
+            (XmlElement::Tab, "w:tab"),
+            (XmlElement::Kinsoku, "w:kinsoku"),
+            (XmlElement::OverflowPunct, "w:overflowPunct"),
+            (XmlElement::ParagraphSpacing, "w:spacing"),
+            (XmlElement::Indentation, "w:ind"),
+            (XmlElement::Justification, "w:jc"),
+            (XmlElement::TextDirection, "w:textDirection"),
+            (XmlElement::TextAlignment, "w:textAlignment"),
+            (XmlElement::TextboxTightWrap, "w:textboxTightWrap"),
+            (XmlElement::OutlineLvl, "w:outlineLvl"),
+            (XmlElement::DivId, "w:divId"),
+            (XmlElement::CnfStyle, "w:cnfStyle"),
+            (XmlElement::RunStyle, "w:rStyle"),
+            (XmlElement::Bold, "w:b"),
+            (XmlElement::Italic, "w:i"),
+            (XmlElement::Strike, "w:strike"),
+            (XmlElement::DStrike, "w:dstrike"),
+            (XmlElement::NoProof, "w:noProof"),
+            (XmlElement::Rtl, "w:rtl"),
+            (XmlElement::Underline, "w:u"),
+            (XmlElement::Color, "w:color"),
+            (XmlElement::Size, "w:sz"),
+            (XmlElement::SizeCs, "w:szCs"),
+            (XmlElement::Fonts, "w:rFonts"),
+            (XmlElement::Highlight, "w:highlight"),
+            (XmlElement::VertAlign, "w:vertAlign"),
+            (XmlElement::Spacing, "w:spacing"),
+            (XmlElement::Position, "w:position"),
+            (XmlElement::Lang, "w:lang"),
+        ];
+        for (element, name) in elements {
+            assert_eq!(element.as_str(), name);
+        }
+    }
+
+    #[test]
+    fn test_size_writes_complex_script_size() {
+        let mut document = Document::from_text("sized");
+        document
+            .runs_mut()
+            .for_each(|r| r.properties.size = Some(24));
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains(r#"<w:sz w:val="24"/><w:szCs w:val="24"/>"#));
+        assert_eq!(
+            crate::xml::parse(&xml).unwrap().paragraphs,
+            document.paragraphs
+        );
+    }
+
+    #[test]
+    fn test_div_id_round_trip() {
+        let mut document = Document::from_text("From the web");
+        document.paragraphs[0].properties.outline_level = Some(9);
+        document.paragraphs[0].properties.div_id = Some(1_325_512_041);
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains(r#"<w:outlineLvl w:val="9"/><w:divId w:val="1325512041"/>"#));
+        assert_eq!(crate::xml::parse(&xml).unwrap(), document);
+    }
+
+    #[test]
+    fn test_rtl_run_round_trip() {
+        // The paragraph direction isn't modeled, only the direction of its runs
+        let xml = r#"
+            <w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+                <w:body>
+                    <w:p>
+                        <w:pPr><w:bidi/></w:pPr>
+                        <w:r><w:rPr><w:rtl/></w:rPr><w:t>שלום</w:t></w:r>
+                        <w:r><w:rPr><w:rtl w:val="0"/></w:rPr><w:t xml:space="preserve"> world</w:t></w:r>
+                    </w:p>
+                </w:body>
+            </w:document>
+        "#;
+        let document = crate::xml::parse(xml).unwrap();
+        let rtl = document
+            .runs()
+            .map(|r| r.properties.rtl)
+            .collect::<Vec<bool>>();
+        assert_eq!(rtl, [true, false]);
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains("<w:rPr><w:rtl/></w:rPr><w:t>שלום</w:t>"));
+        assert_eq!(crate::xml::parse(&xml).unwrap(), document);
+    }
+
+    #[test]
+    fn test_special_characters_round_trip() {
+        let document = Document::from_text(r#"a < b & c > "d" 'e' &amp;"#);
+
+        let xml = generate(&document).unwrap();
+        assert!(
+            xml.contains("<w:t>a &lt; b &amp; c &gt; &quot;d&quot; &apos;e&apos; &amp;amp;</w:t>")
+        );
+        assert_eq!(crate::xml::parse(&xml).unwrap(), document);
+    }
+
+    #[test]
+    fn test_anchor_hyperlink_round_trip() {
+        let mut document = Document::default();
+        document.paragraphs.push(Paragraph {
+            children: vec![ParagraphChild::Hyperlink(Hyperlink::new_anchor(
+                "section2",
+                "See below",
+            ))],
+            ..Default::default()
+        });
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains(r#"<w:hyperlink w:anchor="section2">"#));
+        assert!(!xml.contains("r:id"));
+        let parsed = crate::xml::parse(&xml).unwrap();
+        assert_eq!(parsed, document);
+        assert_eq!(parsed.to_markdown(), "[See below](#section2)");
+
+        document.paragraphs[0].children[0] = ParagraphChild::Hyperlink(Hyperlink::default());
+        assert!(generate(&document).is_err());
+    }
+
+    #[test]
+    fn test_hyperlink_tooltip_round_trip() {
+        let mut document = Document::default();
+        let mut link = Hyperlink::new("https://example.com", &mut document.relationship_manager);
+        link.tooltip = Some(String::from("Opens \"example\" & more"));
+        link.history = Some(true);
+        document.paragraphs.push(Paragraph {
+            children: vec![ParagraphChild::Hyperlink(link)],
+            ..Default::default()
+        });
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains(
+            r#"<w:hyperlink r:id="rId1" w:tooltip="Opens &quot;example&quot; &amp; more" w:history="1">"#
+        ));
+        assert_eq!(
+            crate::xml::parse(&xml).unwrap().paragraphs,
+            document.paragraphs
+        );
+    }
+
+    #[test]
+    fn test_estimate_len() {
+        // 10k runs, half of them formatted
+        let mut document = Document::from_text(&"Some text in a run\n".repeat(10_000));
+        for run in document.runs_mut().step_by(2) {
+            run.properties.bold = true;
+            run.properties.size = Some(24);
+        }
+
+        let xml = generate(&document).unwrap();
+        let estimate = estimate_len(&document);
+        // Not exact, but close enough to need at most a reallocation
+        assert!(estimate >= xml.len() / 2 && estimate <= xml.len() * 2);
+        assert!(xml.contains(r#"<w:r><w:rPr><w:b/><w:sz w:val="24"/><w:szCs w:val="24"/></w:rPr><w:t>Some text in a run</w:t></w:r>"#));
+    }
+
+    #[test]
+    fn test_explicit_false_keep_next() {
+        let mut document = Document::from_text("Heading\nBody");
+        document.paragraphs[0].properties.keep_next = Some(true);
+        document.paragraphs[0].properties.keep_lines = Some(true);
+        document.paragraphs[1].properties.keep_next = Some(false);
+
+        let xml = generate(&document).unwrap();
+        assert!(xml.contains("<w:pPr><w:keepNext/><w:keepLines/></w:pPr>"));
+        assert!(xml.contains(r#"<w:pPr><w:keepNext w:val="0"/></w:pPr>"#));
+        assert_eq!(crate::xml::parse(&xml).unwrap(), document);
+    }
+}
@@ -1,62 +1,406 @@
 use crate::elements::*;
 use crate::errors::RudocxError;
-use crate::rels::{bp, generate_doc_rels};
+use crate::rels::{
+    bp, generate_content_types_with_defaults, generate_doc_rels, generate_doc_rels_with_parts,
+    generate_package_rels, read_relationships_typed, Relationship, RelationshipKind, RelationshipManager,
+};
 use crate::xml::*;
 
+use std::collections::HashMap;
 use std::fs::File;
-use std::io::{BufReader, Read, Write};
+use std::io::{BufReader, Cursor, Read, Seek, SeekFrom, Write};
 use std::path::Path;
 use zip::write::FileOptions;
 use zip::{ZipArchive, ZipWriter};
 
+// Magic bytes of OLE compound files, which is how encrypted documents are stored instead of a zip package
+const CFB_SIGNATURE: [u8; 8] = [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+
 pub fn load<P: AsRef<Path>>(path: P) -> Result<Document, RudocxError> {
+    load_with(path, ParseOptions::default())
+}
+
+/// Same as [load], reading every part of the document with the given [ParseOptions].
+pub fn load_with<P: AsRef<Path>>(path: P, options: ParseOptions) -> Result<Document, RudocxError> {
     let file = File::open(path.as_ref()).map_err(RudocxError::IoError)?;
-    let reader = BufReader::new(file);
+    load_from(BufReader::new(file), options)
+}
+
+/// Same as [load], reading the document from the bytes of a `docx` file (e.g. the body of an upload).
+pub fn load_bytes(data: &[u8]) -> Result<Document, RudocxError> {
+    load_bytes_with(data, ParseOptions::default())
+}
+
+/// Same as [load_bytes], using the given [ParseOptions].
+pub fn load_bytes_with(data: &[u8], options: ParseOptions) -> Result<Document, RudocxError> {
+    load_from(Cursor::new(data), options)
+}
+
+fn load_from<R: Read + Seek>(mut reader: R, options: ParseOptions) -> Result<Document, RudocxError> {
+    check_not_encrypted(&mut reader)?;
     let mut archive = ZipArchive::new(reader).map_err(RudocxError::ZipError)?;
 
-    let mut document_file = archive
-        .by_name(bp::DOCUMENT_XML_PATH)
-        .map_err(|_| RudocxError::MissingPart(bp::DOCUMENT_XML_PATH.to_string()))?;
+    let xml_content = read_part(&mut archive, bp::DOCUMENT_XML_PATH)?;
+    let (mut document, references) = parse_with_references(&xml_content, options)?;
+    let relationships = read_part_relationships(&mut archive, bp::DOCUMENT_XML_PATH)?;
+    let mut paragraphs = std::mem::take(&mut document.paragraphs);
+    resolve_relationships(&mut archive, &relationships, &mut paragraphs, &mut document)?;
+    document.paragraphs = paragraphs;
+
+    for reference in references {
+        let relationship = relationships
+            .get(&reference.id)
+            .ok_or_else(|| RudocxError::MissingPart(reference.id.clone()))?;
+        let path = part_path(&relationship.target);
+        let part = parse_with(&read_part(&mut archive, &path)?, options)?;
+        document.has_tables |= part.has_tables;
+        document.has_tracked_changes |= part.has_tracked_changes;
+        let mut paragraphs = part.paragraphs;
+        let part_relationships = read_part_relationships(&mut archive, &path)?;
+        resolve_relationships(&mut archive, &part_relationships, &mut paragraphs, &mut document)?;
+        let section = &mut document.section_properties;
+        match reference.kind {
+            HeaderFooterKind::Header => section.set_header(reference.r#type, Some(Header::new(paragraphs))),
+            HeaderFooterKind::Footer => section.set_footer(reference.r#type, Some(Footer::new(paragraphs))),
+        }
+    }
+
+    // Comments, footnotes and styles are found through the relationship of their type, wherever the part is
+    let part_of = |kind: RelationshipKind| {
+        relationships
+            .values()
+            .find(|r| r.kind == kind)
+            .map(|r| part_path(&r.target))
+    };
+    if let Some(path) = part_of(RelationshipKind::Comments) {
+        let mut comments = parse_comments(&read_part(&mut archive, &path)?, options)?;
+        let part_relationships = read_part_relationships(&mut archive, &path)?;
+        let paragraphs = comments.iter_mut().flat_map(|c| &mut c.paragraphs);
+        resolve_relationships(&mut archive, &part_relationships, paragraphs, &mut document)?;
+        document.comments = comments;
+    }
+    if let Some(path) = part_of(RelationshipKind::Footnotes) {
+        let mut footnotes = parse_footnotes(&read_part(&mut archive, &path)?, options)?;
+        let part_relationships = read_part_relationships(&mut archive, &path)?;
+        let paragraphs = footnotes.iter_mut().flat_map(|f| &mut f.paragraphs);
+        resolve_relationships(&mut archive, &part_relationships, paragraphs, &mut document)?;
+        document.footnotes = footnotes;
+    }
+    if let Some(path) = part_of(RelationshipKind::Styles) {
+        let styles = parse_styles(&read_part(&mut archive, &path)?, options)?;
+        document.default_font = styles.default_font;
+        document.default_size = styles.default_size;
+        document.styles = styles.styles;
+    }
+
+    Ok(document)
+}
+
+// Relationships of a part, read from the `.rels` part next to it (e.g. word/_rels/header1.xml.rels). Parts without
+// relationships have none.
+fn read_part_relationships<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    path: &str,
+) -> Result<HashMap<String, Relationship>, RudocxError> {
+    let (folder, name) = path.rsplit_once('/').unwrap_or(("", path));
+    let rels_path = format!("{folder}/_rels/{name}.rels");
+    match archive.index_for_name(&rels_path) {
+        Some(_) => read_relationships_typed(&read_part(archive, &rels_path)?),
+        None => Ok(HashMap::new()),
+    }
+}
+
+// Add the relationships of the hyperlinks, drawings and pictures of a part to the relationship manager of the document, along
+// with the images the drawings show. Ids are only unique within a part, so the ones already taken by another target
+// (e.g. by the body for a header) are renumbered. Ids without relationship are kept as is, they only miss their target.
+fn resolve_relationships<'a, R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    relationships: &HashMap<String, Relationship>,
+    paragraphs: impl IntoIterator<Item = &'a mut Paragraph>,
+    document: &mut Document,
+) -> Result<(), RudocxError> {
+    let mut renumbered: HashMap<String, String> = HashMap::new();
+    for (id, kind) in paragraphs.into_iter().flat_map(Paragraph::relationship_ids_mut) {
+        if let Some(new_id) = renumbered.get(id.as_str()) {
+            id.clone_from(new_id);
+            continue;
+        }
+        let Some(Relationship { target, .. }) = relationships.get(id.as_str()) else {
+            continue;
+        };
+        let manager = &mut document.relationship_manager;
+        let new_id = match manager.get(id) {
+            Some(r) if r.target == *target && r.kind == kind => id.clone(),
+            Some(_) if kind == RelationshipKind::Image => manager.generate_image_rid(target),
+            Some(_) => manager.generate_rid(target),
+            None if kind == RelationshipKind::Image => {
+                manager.add_image_relationship(id.clone(), target.clone());
+                id.clone()
+            }
+            None => {
+                manager.add_relationship(id.clone(), target.clone());
+                id.clone()
+            }
+        };
+        if kind == RelationshipKind::Image && !document.images.iter().any(|i| i.id == new_id) {
+            let extension = target.rsplit_once('.').map(|(_, e)| e).unwrap_or_default();
+            document.images.push(Image {
+                id: new_id.clone(),
+                content_type: Image::content_type_of(extension).to_string(),
+                data: read_part_bytes(archive, &part_path(target))?,
+            });
+        }
+        renumbered.insert(id.clone(), new_id.clone());
+        *id = new_id;
+    }
+    Ok(())
+}
+
+// Relationships of the hyperlinks, drawings and pictures of a part other than the body. The document relationship manager holds
+// them all, but each part must list the ones it uses in its own `.rels` part.
+fn part_relationships<'a>(
+    document: &Document,
+    paragraphs: impl IntoIterator<Item = &'a Paragraph>,
+) -> RelationshipManager {
+    let mut relationships = RelationshipManager::new();
+    for id in paragraphs.into_iter().flat_map(Paragraph::relationship_ids) {
+        if let Some(relationship) = document.relationship_manager.get(id) {
+            relationships.add_relationship_typed(id.to_string(), relationship.clone());
+        }
+    }
+    relationships
+}
+
+// Fail early on encrypted documents, as ZipArchive would only report an invalid archive
+fn check_not_encrypted<R: Read + Seek>(reader: &mut R) -> Result<(), RudocxError> {
+    let mut signature = [0u8; CFB_SIGNATURE.len()];
+    let is_encrypted = reader.read_exact(&mut signature).is_ok() && signature == CFB_SIGNATURE;
+    reader.seek(SeekFrom::Start(0)).map_err(RudocxError::IoError)?;
+    match is_encrypted {
+        true => Err(RudocxError::Encrypted),
+        false => Ok(()),
+    }
+}
+
+// Helper function to read a whole part of the package as text
+fn read_part<R: Read + Seek>(archive: &mut ZipArchive<R>, name: &str) -> Result<String, RudocxError> {
+    let mut part = archive
+        .by_name(name)
+        .map_err(|_| RudocxError::MissingPart(name.to_string()))?;
 
     let mut xml_content = String::new();
-    document_file
-        .read_to_string(&mut xml_content)
+    part.read_to_string(&mut xml_content)
         .map_err(RudocxError::IoError)?;
+    Ok(xml_content)
+}
 
-    parse(&xml_content)
+// Helper function to read a whole binary part of the package (e.g. images)
+fn read_part_bytes<R: Read + Seek>(archive: &mut ZipArchive<R>, name: &str) -> Result<Vec<u8>, RudocxError> {
+    let mut part = archive
+        .by_name(name)
+        .map_err(|_| RudocxError::MissingPart(name.to_string()))?;
+
+    let mut content = Vec::new();
+    part.read_to_end(&mut content).map_err(RudocxError::IoError)?;
+    Ok(content)
 }
 
-// Helper function to parse the actual XML content
+// Relationship targets of word/document.xml are relative to the word/ folder unless absolute
+fn part_path(target: &str) -> String {
+    match target.strip_prefix('/') {
+        Some(absolute) => absolute.to_string(),
+        None => format!("word/{target}"),
+    }
+}
+
+/// Options to tweak how a document is written by [save_with].
+///
+/// ### Fields
+/// > - **minimal_parts:** `bool` - Write minimal `word/styles.xml`, `docProps/app.xml` and `docProps/core.xml` parts.
+/// >   They are optional in OOXML, but some consumers (e.g. LibreOffice and strict validators) reject packages without them.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SaveOptions {
+    pub minimal_parts: bool,
+}
 
 pub fn save<P: AsRef<Path>>(document: &Document, path: P) -> Result<(), RudocxError> {
+    save_with(document, path, &SaveOptions::default())
+}
+
+/// Same as [save], using the given [SaveOptions].
+pub fn save_with<P: AsRef<Path>>(document: &Document, path: P, save_options: &SaveOptions) -> Result<(), RudocxError> {
     let file = File::create(path.as_ref()).map_err(RudocxError::IoError)?;
-    let mut zip = ZipWriter::new(file);
+    save_to(document, file, save_options)?;
+    Ok(())
+}
+
+/// Same as [save], returning the bytes of the `docx` file instead of writing it to disk.
+pub fn save_bytes(document: &Document) -> Result<Vec<u8>, RudocxError> {
+    let cursor = save_to(document, Cursor::new(Vec::new()), &SaveOptions::default())?;
+    Ok(cursor.into_inner())
+}
+
+fn save_to<W: Write + Seek>(document: &Document, writer: W, save_options: &SaveOptions) -> Result<W, RudocxError> {
+    let mut zip = ZipWriter::new(writer);
     let options: FileOptions<'_, ()> = FileOptions::default();
 
+    let parts = header_footer_parts(document);
+    let part_names = parts
+        .iter()
+        .map(|p| format!("/word/{}", p.file_name))
+        .collect::<Vec<String>>();
+    let mut overrides = parts
+        .iter()
+        .zip(&part_names)
+        .map(|(p, name)| match p.kind {
+            HeaderFooterKind::Header => (name.as_str(), bp::HEADER_CONTENT_TYPE),
+            HeaderFooterKind::Footer => (name.as_str(), bp::FOOTER_CONTENT_TYPE),
+        })
+        .collect::<Vec<(&str, &str)>>();
+    let mut part_rels = parts
+        .iter()
+        .map(|p| match p.kind {
+            HeaderFooterKind::Header => (p.id.as_str(), bp::HEADER_REL_TYPE, p.file_name.as_str()),
+            HeaderFooterKind::Footer => (p.id.as_str(), bp::FOOTER_REL_TYPE, p.file_name.as_str()),
+        })
+        .collect::<Vec<(&str, &str, &str)>>();
+    let mut package_rels = Vec::new();
+
+    // Other parts go after the hyperlinks and the headers and footers, so their ids don't collide
+    let mut next_id = document.relationship_manager.last_id() as usize + parts.len();
+    let mut part_id = || {
+        next_id += 1;
+        format!("rId{next_id}")
+    };
+    let comments_id = (!document.comments.is_empty()).then(&mut part_id);
+    if let Some(id) = &comments_id {
+        overrides.push(("/word/comments.xml", bp::COMMENTS_CONTENT_TYPE));
+        part_rels.push((id.as_str(), bp::COMMENTS_REL_TYPE, "comments.xml"));
+    }
+    let footnotes_id = (!document.footnotes.is_empty()).then(&mut part_id);
+    if let Some(id) = &footnotes_id {
+        overrides.push(("/word/footnotes.xml", bp::FOOTNOTES_CONTENT_TYPE));
+        part_rels.push((id.as_str(), bp::FOOTNOTES_REL_TYPE, "footnotes.xml"));
+    }
+    let styles_id = part_id();
+    // The document defaults live in the styles, as does the style of the footnote marks
+    let write_styles = save_options.minimal_parts
+        || document.default_font.is_some()
+        || document.default_size.is_some()
+        || !document.styles.is_empty()
+        || !document.footnotes.is_empty();
+    if write_styles {
+        overrides.push(("/word/styles.xml", bp::STYLES_CONTENT_TYPE));
+        part_rels.push((styles_id.as_str(), bp::STYLES_REL_TYPE, "styles.xml"));
+    }
+    if save_options.minimal_parts {
+        overrides.extend([
+            ("/docProps/app.xml", bp::APP_CONTENT_TYPE),
+            ("/docProps/core.xml", bp::CORE_CONTENT_TYPE),
+        ]);
+        package_rels.extend([
+            ("rId2", bp::APP_REL_TYPE, bp::APP_XML_PATH),
+            ("rId3", bp::CORE_REL_TYPE, bp::CORE_XML_PATH),
+        ]);
+    }
+
     // Write boilerplate files
     zip.start_file("_rels/.rels", options)?;
-    zip.write_all(bp::RELS_XML_CONTENT.as_bytes())?;
+    zip.write_all(generate_package_rels(&mut String::with_capacity(1024), &package_rels)?.as_bytes())?;
 
+    // Image formats are registered once per extension
+    let mut defaults = Vec::new();
+    for image in &document.images {
+        let default = (image.extension(), image.content_type.as_str());
+        if !defaults.contains(&default) {
+            defaults.push(default);
+        }
+    }
     zip.start_file("[Content_Types].xml", options)?;
-    zip.write_all(bp::CONTENT_TYPES_XML_CONTENT.as_bytes())?;
+    zip.write_all(
+        generate_content_types_with_defaults(&mut String::with_capacity(1024), &defaults, &overrides)?.as_bytes(),
+    )?;
 
     // Ensure word/_rels directory exists implicitly via path
-    zip.start_file("word/_rels/document.xml.rels", options)?;
-    zip.write_all(generate_doc_rels(&mut String::with_capacity(4096), &document.relationship_manager).as_bytes())?;
+    zip.start_file(bp::DOCUMENT_RELS_PATH, options)?;
+    zip.write_all(
+        generate_doc_rels_with_parts(
+            &mut String::with_capacity(4096),
+            &document.relationship_manager,
+            &part_rels,
+        )?
+        .as_bytes(),
+    )?;
 
     // Generate and write word/document.xml
     let document_xml = generate(document)?;
     zip.start_file(bp::DOCUMENT_XML_PATH, options)?;
     zip.write_all(document_xml.as_bytes())?;
 
-    zip.finish().map_err(RudocxError::ZipError)?;
+    // Write the images where their relationships point to
+    for image in &document.images {
+        let target = document
+            .relationship_manager
+            .get(&image.id)
+            .map(|r| &r.target)
+            .ok_or_else(|| RudocxError::Unsupported(format!("image without relationship: {}", image.id)))?;
+        zip.start_file(part_path(target), options)?;
+        zip.write_all(&image.data)?;
+    }
 
-    Ok(())
+    // Generate and write headers and footers, with the relationships of their hyperlinks and drawings
+    for part in &parts {
+        zip.start_file(format!("word/{}", part.file_name), options)?;
+        zip.write_all(generate_header_footer(part)?.as_bytes())?;
+        let relationships = part_relationships(document, part.paragraphs);
+        if !relationships.get_relationships().is_empty() {
+            zip.start_file(format!("word/_rels/{}.rels", part.file_name), options)?;
+            zip.write_all(generate_doc_rels(&mut String::with_capacity(1024), &relationships)?.as_bytes())?;
+        }
+    }
+
+    if !document.comments.is_empty() {
+        zip.start_file(bp::COMMENTS_XML_PATH, options)?;
+        zip.write_all(generate_comments(&document.comments)?.as_bytes())?;
+        let relationships = part_relationships(document, document.comments.iter().flat_map(|c| &c.paragraphs));
+        if !relationships.get_relationships().is_empty() {
+            zip.start_file(bp::COMMENTS_RELS_PATH, options)?;
+            zip.write_all(generate_doc_rels(&mut String::with_capacity(1024), &relationships)?.as_bytes())?;
+        }
+    }
+
+    if !document.footnotes.is_empty() {
+        zip.start_file(bp::FOOTNOTES_XML_PATH, options)?;
+        zip.write_all(generate_footnotes(&document.footnotes)?.as_bytes())?;
+        let relationships = part_relationships(document, document.footnotes.iter().flat_map(|f| &f.paragraphs));
+        if !relationships.get_relationships().is_empty() {
+            zip.start_file(bp::FOOTNOTES_RELS_PATH, options)?;
+            zip.write_all(generate_doc_rels(&mut String::with_capacity(1024), &relationships)?.as_bytes())?;
+        }
+    }
+
+    if write_styles {
+        zip.start_file(bp::STYLES_XML_PATH, options)?;
+        zip.write_all(generate_styles(document)?.as_bytes())?;
+    }
+
+    if save_options.minimal_parts {
+        for (name, content) in [
+            (bp::APP_XML_PATH, bp::APP_XML_CONTENT),
+            (bp::CORE_XML_PATH, bp::CORE_XML_CONTENT),
+        ] {
+            zip.start_file(name, options)?;
+            zip.write_all(content.as_bytes())?;
+        }
+    }
+
+    zip.finish().map_err(RudocxError::ZipError)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::rels::read_relationships;
 
     #[test]
     fn test_save_simple_doc() {
@@ -68,6 +412,10 @@ mod tests {
                             properties: RunProperties::default(),
                             text: "Hello ".to_string(),
                             space_preserve: false,
+                            picture_id: None,
+                            comment_reference: None,
+                            footnote_reference: None,
+                            break_type: None,
                         }),
                         ParagraphChild::Run(Run {
                             properties: RunProperties {
@@ -82,9 +430,21 @@ mod tests {
                                 dstrike: false,
                                 valign: None,
                                 spacing: None,
+                                theme_color: None,
+                                theme_tint: None,
+                                theme_shade: None,
+                                position: None,
+                                no_proof: false,
+                                rtl: false,
+                                lang: None,
+                                style: None,
                             },
                             text: "World".to_string(),
                             space_preserve: false,
+                            picture_id: None,
+                            comment_reference: None,
+                            footnote_reference: None,
+                            break_type: None,
                         }),
                         ParagraphChild::Run(Run {
                             properties: RunProperties {
@@ -99,11 +459,24 @@ mod tests {
                                 dstrike: false,
                                 valign: None,
                                 spacing: None,
+                                theme_color: None,
+                                theme_tint: None,
+                                theme_shade: None,
+                                position: None,
+                                no_proof: false,
+                                rtl: false,
+                                lang: None,
+                                style: None,
                             },
                             text: " Red!".to_string(),
                             space_preserve: false,
+                            picture_id: None,
+                            comment_reference: None,
+                            footnote_reference: None,
+                            break_type: None,
                         }),
                     ],
+                    ..Default::default()
                 },
                 Paragraph {
                     children: vec![ParagraphChild::Run(Run {
@@ -119,13 +492,26 @@ mod tests {
                             dstrike: false,
                             valign: None,
                             spacing: None,
+                            theme_color: None,
+                            theme_tint: None,
+                            theme_shade: None,
+                            position: None,
+                            no_proof: false,
+                            rtl: false,
+                            lang: None,
+                            style: None,
                         },
                         text: "This is italic.".to_string(),
                         space_preserve: false,
+                        picture_id: None,
+                        comment_reference: None,
+                        footnote_reference: None,
+                        break_type: None,
                     })],
+                    ..Default::default()
                 },
             ],
-            relationship_manager: Default::default(),
+            ..Default::default()
         };
 
         // Create the hyperlink using the document's relationship manager
@@ -142,10 +528,18 @@ mod tests {
                     properties: RunProperties::default(),
                     text: " That was hyperlink.".to_string(),
                     space_preserve: false,
+                    picture_id: None,
+                    comment_reference: None,
+                    footnote_reference: None,
+                    break_type: None,
                 }),
             ],
+            ..Default::default()
         });
 
+        // Runs with surrounding whitespace are always written preserved, and read back as such
+        original_doc.fix_space_preserve();
+
         let temp_file_path = std::env::temp_dir().join("rudocx_test_save.docx");
 
         let save_result = save(&original_doc, &temp_file_path);
@@ -172,4 +566,624 @@ mod tests {
 
         let _ = std::fs::remove_file(&temp_file_path);
     }
+
+    #[test]
+    fn test_save_header_footer_round_trip() {
+        let mut document = Document::default();
+        document.paragraphs.push(Paragraph {
+            children: vec![ParagraphChild::Run(Run::from("Body text".to_string()))],
+            ..Default::default()
+        });
+        document.section_properties.default_header = Some(Header::new(vec![Paragraph {
+            children: vec![ParagraphChild::Run(Run::from("Quarterly report".to_string()))],
+            ..Default::default()
+        }]));
+        document.section_properties.default_footer = Some(Footer::new(vec![Paragraph {
+            children: vec![
+                ParagraphChild::Run(Run {
+                    space_preserve: true,
+                    ..Run::from("Page ".to_string())
+                }),
+                ParagraphChild::SimpleField(SimpleField::page_number()),
+            ],
+            ..Default::default()
+        }]));
+
+        let temp_file_path = std::env::temp_dir().join("rudocx_test_header_footer.docx");
+        save(&document, &temp_file_path).unwrap();
+
+        let file = File::open(&temp_file_path).unwrap();
+        let mut archive = ZipArchive::new(file).unwrap();
+        let content_types = read_part(&mut archive, "[Content_Types].xml").unwrap();
+        assert!(content_types.contains(r#"PartName="/word/header1.xml""#));
+        assert!(content_types.contains(r#"PartName="/word/footer1.xml""#));
+        let rels = read_part(&mut archive, bp::DOCUMENT_RELS_PATH).unwrap();
+        assert!(rels.contains(r#"Target="header1.xml""#));
+        assert!(rels.contains(r#"Target="footer1.xml""#));
+        let footer = read_part(&mut archive, "word/footer1.xml").unwrap();
+        assert!(footer.contains(r#"<w:fldSimple w:instr="PAGE">"#));
+
+        let loaded = load(&temp_file_path).unwrap();
+        assert_eq!(loaded.paragraphs, document.paragraphs);
+        assert_eq!(loaded.section_properties, document.section_properties);
+
+        let _ = std::fs::remove_file(&temp_file_path);
+    }
+
+    #[test]
+    fn test_save_minimal_parts() {
+        let mut document = Document::from_text("Minimal");
+        Hyperlink::new("https://example.com", &mut document.relationship_manager);
+
+        let temp_file_path = std::env::temp_dir().join("rudocx_test_minimal_parts.docx");
+        save_with(&document, &temp_file_path, &SaveOptions { minimal_parts: true }).unwrap();
+
+        let file = File::open(&temp_file_path).unwrap();
+        let mut archive = ZipArchive::new(file).unwrap();
+        let content_types = read_part(&mut archive, "[Content_Types].xml").unwrap();
+        for (part_name, content_type) in [
+            ("/word/styles.xml", bp::STYLES_CONTENT_TYPE),
+            ("/docProps/app.xml", bp::APP_CONTENT_TYPE),
+            ("/docProps/core.xml", bp::CORE_CONTENT_TYPE),
+        ] {
+            assert!(content_types.contains(&format!(
+                r#"<Override PartName="{part_name}" ContentType="{content_type}"/>"#
+            )));
+            assert!(read_part(&mut archive, &part_name[1..]).is_ok());
+        }
+
+        let package_rels = read_relationships(&read_part(&mut archive, "_rels/.rels").unwrap()).unwrap();
+        assert_eq!(package_rels.get("rId2").map(String::as_str), Some(bp::APP_XML_PATH));
+        assert_eq!(package_rels.get("rId3").map(String::as_str), Some(bp::CORE_XML_PATH));
+        let rels = read_relationships(&read_part(&mut archive, bp::DOCUMENT_RELS_PATH).unwrap()).unwrap();
+        assert_eq!(rels.len(), 2);
+        assert_eq!(rels.get("rId2").map(String::as_str), Some("styles.xml"));
+
+        assert_eq!(load(&temp_file_path).unwrap().paragraphs, document.paragraphs);
+
+        let _ = std::fs::remove_file(&temp_file_path);
+    }
+
+    #[test]
+    fn test_save_without_minimal_parts() {
+        let document = Document::from_text("Default");
+
+        let temp_file_path = std::env::temp_dir().join("rudocx_test_no_minimal_parts.docx");
+        save(&document, &temp_file_path).unwrap();
+
+        let file = File::open(&temp_file_path).unwrap();
+        let mut archive = ZipArchive::new(file).unwrap();
+        assert!(read_part(&mut archive, bp::STYLES_XML_PATH).is_err());
+        assert!(read_part(&mut archive, bp::CORE_XML_PATH).is_err());
+
+        let _ = std::fs::remove_file(&temp_file_path);
+    }
+
+    #[test]
+    fn test_load_encrypted() {
+        let temp_file_path = std::env::temp_dir().join("rudocx_test_encrypted.docx");
+        let mut header = CFB_SIGNATURE.to_vec();
+        header.resize(512, 0);
+        std::fs::write(&temp_file_path, header).unwrap();
+
+        match load(&temp_file_path) {
+            Err(RudocxError::Encrypted) => (),
+            Err(e) => panic!("Unexpected error: {e}"),
+            Ok(_) => panic!("Encrypted document was loaded"),
+        }
+
+        let _ = std::fs::remove_file(&temp_file_path);
+    }
+
+    #[test]
+    fn test_save_comments_round_trip() {
+        let mut document = Document::default();
+        let mut commented = Run::from("Needs review".to_string());
+        commented.properties.bold = true;
+        document.paragraphs.push(Paragraph {
+            children: vec![
+                ParagraphChild::CommentRangeStart(0),
+                ParagraphChild::Run(commented),
+                ParagraphChild::CommentRangeEnd(0),
+                ParagraphChild::Run(Run {
+                    comment_reference: Some(0),
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        });
+        let mut comment = Comment::new(0, "Reviewer", vec![Paragraph {
+            children: vec![ParagraphChild::Run(Run::from("Check the figures".to_string()))],
+            ..Default::default()
+        }]);
+        comment.date = Some(String::from("2024-01-31T10:00:00Z"));
+        document.comments.push(comment);
+        Hyperlink::new("https://example.com", &mut document.relationship_manager);
+
+        let temp_file_path = std::env::temp_dir().join("rudocx_test_comments.docx");
+        save(&document, &temp_file_path).unwrap();
+
+        let file = File::open(&temp_file_path).unwrap();
+        let mut archive = ZipArchive::new(file).unwrap();
+        let content_types = read_part(&mut archive, "[Content_Types].xml").unwrap();
+        assert!(content_types.contains(bp::COMMENTS_CONTENT_TYPE));
+        let rels = read_part(&mut archive, bp::DOCUMENT_RELS_PATH).unwrap();
+        assert!(rels.contains(r#"Id="rId2""#));
+        assert!(rels.contains(r#"Target="comments.xml""#));
+
+        let loaded = load(&temp_file_path).unwrap();
+        assert_eq!(loaded.comments.len(), 1);
+        assert_eq!(loaded.comments[0].author, "Reviewer");
+        assert_eq!(loaded.comments[0].date.as_deref(), Some("2024-01-31T10:00:00Z"));
+        assert_eq!(loaded.comments[0].paragraphs[0].runs().next().unwrap().text, "Check the figures");
+        assert_eq!(loaded.paragraphs, document.paragraphs);
+
+        let _ = std::fs::remove_file(&temp_file_path);
+    }
+
+    #[test]
+    fn test_save_footnotes_round_trip() {
+        let mut document = Document::default();
+        document.paragraphs.push(Paragraph {
+            children: vec![
+                ParagraphChild::Run(Run::from("Cited claim".to_string())),
+                ParagraphChild::Run(Run {
+                    footnote_reference: Some(1),
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        });
+        document.footnotes.push(Footnote::new(1, vec![Paragraph {
+            children: vec![ParagraphChild::Run(Run::from("Source, p. 42".to_string()))],
+            ..Default::default()
+        }]));
+
+        let temp_file_path = std::env::temp_dir().join("rudocx_test_footnotes.docx");
+        save(&document, &temp_file_path).unwrap();
+
+        let file = File::open(&temp_file_path).unwrap();
+        let mut archive = ZipArchive::new(file).unwrap();
+        let content_types = read_part(&mut archive, "[Content_Types].xml").unwrap();
+        assert!(content_types.contains(bp::FOOTNOTES_CONTENT_TYPE));
+        let rels = read_part(&mut archive, bp::DOCUMENT_RELS_PATH).unwrap();
+        assert!(rels.contains(r#"Target="footnotes.xml""#));
+        let footnotes = read_part(&mut archive, bp::FOOTNOTES_XML_PATH).unwrap();
+        assert!(footnotes.contains(r#"<w:footnote w:type="separator" w:id="-1">"#));
+        assert!(footnotes.contains(r#"<w:footnote w:type="continuationSeparator" w:id="0">"#));
+        assert!(footnotes.contains(
+            r#"<w:r><w:rPr><w:rStyle w:val="FootnoteReference"/></w:rPr><w:footnoteRef/></w:r>"#
+        ));
+        let body = read_part(&mut archive, bp::DOCUMENT_XML_PATH).unwrap();
+        assert!(body.contains(
+            r#"<w:r><w:rPr><w:rStyle w:val="FootnoteReference"/></w:rPr><w:footnoteReference w:id="1"/></w:r>"#
+        ));
+        let styles = read_part(&mut archive, bp::STYLES_XML_PATH).unwrap();
+        assert!(styles.contains(r#"w:styleId="FootnoteReference""#));
+
+        let loaded = load(&temp_file_path).unwrap();
+        assert_eq!(loaded.paragraphs, document.paragraphs);
+        assert_eq!(loaded.footnotes, document.footnotes);
+        assert!(loaded.styles.is_empty());
+
+        let _ = std::fs::remove_file(&temp_file_path);
+    }
+
+    // 1x1 transparent PNG
+    const PIXEL_PNG: [u8; 67] = [
+        0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x0D, 0x49, 0x48, 0x44, 0x52, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1F, 0x15, 0xC4, 0x89, 0x00, 0x00, 0x00,
+        0x0A, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9C, 0x63, 0x00, 0x01, 0x00, 0x00, 0x05, 0x00, 0x01, 0x0D, 0x0A, 0x2D,
+        0xB4, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4E, 0x44, 0xAE, 0x42, 0x60, 0x82,
+    ];
+
+    #[test]
+    fn test_save_image_round_trip() {
+        let mut document = Document::default();
+        let link = Hyperlink::new("https://example.com", &mut document.relationship_manager);
+        let drawing = document.add_image(PIXEL_PNG.to_vec(), "image/png", 914400, 914400);
+        document.paragraphs.push(Paragraph {
+            children: vec![
+                ParagraphChild::Hyperlink(link),
+                ParagraphChild::Drawing(drawing.clone()),
+                ParagraphChild::Run(Run::from("Caption".to_string())),
+            ],
+            ..Default::default()
+        });
+
+        let temp_file_path = std::env::temp_dir().join("rudocx_test_image.docx");
+        save(&document, &temp_file_path).unwrap();
+
+        let file = File::open(&temp_file_path).unwrap();
+        let mut archive = ZipArchive::new(file).unwrap();
+        let content_types = read_part(&mut archive, "[Content_Types].xml").unwrap();
+        assert!(content_types.contains(r#"<Default Extension="png" ContentType="image/png"/>"#));
+        let rels = read_part(&mut archive, bp::DOCUMENT_RELS_PATH).unwrap();
+        assert!(rels.contains(&format!(
+            r#"<Relationship Id="{}" Type="{}" Target="media/image1.png"/>"#,
+            drawing.image_id,
+            bp::IMAGE_REL_TYPE
+        )));
+        assert!(rels.contains(r#"TargetMode="External""#));
+        assert_eq!(read_part_bytes(&mut archive, "word/media/image1.png").unwrap(), PIXEL_PNG);
+        let xml = read_part(&mut archive, bp::DOCUMENT_XML_PATH).unwrap();
+        assert!(xml.contains(&format!(r#"<a:blip r:embed="{}"/>"#, drawing.image_id)));
+
+        let loaded = load(&temp_file_path).unwrap();
+        assert_eq!(loaded.paragraphs, document.paragraphs);
+        assert_eq!(loaded.images, document.images);
+        assert_eq!(
+            loaded.relationship_manager.get_images(),
+            document.relationship_manager.get_images()
+        );
+
+        let _ = std::fs::remove_file(&temp_file_path);
+    }
+
+    #[test]
+    fn test_save_bytes_round_trip() {
+        let mut document = Document::from_text("Plain");
+        document.paragraphs[0].children.push(ParagraphChild::Run(Run {
+            properties: RunProperties {
+                bold: true,
+                color: Some(HexColor::new("FF0000")),
+                ..Default::default()
+            },
+            text: String::from(" and bold"),
+            space_preserve: true,
+            ..Default::default()
+        }));
+        let link = Hyperlink::new("https://example.com", &mut document.relationship_manager);
+        document.paragraphs.push(Paragraph {
+            children: vec![ParagraphChild::Hyperlink(link)],
+            ..Default::default()
+        });
+
+        let bytes = save_bytes(&document).unwrap();
+        assert!(bytes.starts_with(b"PK"));
+        assert_eq!(load_bytes(&bytes).unwrap(), document);
+    }
+
+    #[test]
+    fn test_default_font_round_trip() {
+        let mut document = Document::from_text("Pinned font");
+        document.default_font = Some(String::from("Times New Roman"));
+        document.default_size = Some(24);
+
+        let bytes = save_bytes(&document).unwrap();
+        let mut archive = ZipArchive::new(Cursor::new(bytes.as_slice())).unwrap();
+        let styles = read_part(&mut archive, bp::STYLES_XML_PATH).unwrap();
+        assert!(styles.contains(
+            r#"<w:rPrDefault><w:rPr><w:sz w:val="24"/><w:szCs w:val="24"/><w:rFonts w:ascii="Times New Roman" w:hAnsi="Times New Roman" w:cs="Times New Roman"/></w:rPr></w:rPrDefault>"#
+        ));
+        assert!(read_part(&mut archive, "[Content_Types].xml").unwrap().contains("/word/styles.xml"));
+        assert!(read_part(&mut archive, bp::DOCUMENT_RELS_PATH).unwrap().contains("styles.xml"));
+        assert_eq!(load_bytes(&bytes).unwrap(), document);
+    }
+
+    #[test]
+    fn test_styles_round_trip() {
+        let mut document = Document::from_text("Introduction\nBody text");
+        let mut heading = Style::new("Heading1", "heading 1", StyleType::Paragraph);
+        heading.based_on = Some(String::from("Normal"));
+        heading.paragraph_properties.outline_level = Some(0);
+        heading.run_properties = RunProperties {
+            bold: true,
+            size: Some(32),
+            ..Default::default()
+        };
+        document.add_style(heading.clone());
+        document.paragraphs[0].properties.style = Some(String::from("Heading1"));
+
+        let bytes = save_bytes(&document).unwrap();
+        let loaded = load_bytes(&bytes).unwrap();
+        assert_eq!(loaded.paragraphs[0].properties.style.as_deref(), Some("Heading1"));
+        assert_eq!(loaded.style("Heading1"), Some(&heading));
+        assert_eq!(loaded, document);
+
+        // A defined Normal style stays the default paragraph style
+        let mut normal = Style::normal();
+        normal.run_properties.size = Some(24);
+        document.add_style(normal.clone());
+        let loaded = load_bytes(&save_bytes(&document).unwrap()).unwrap();
+        assert_eq!(loaded.style("Normal"), Some(&normal));
+        assert_eq!(loaded, document);
+    }
+
+    #[test]
+    fn test_hyperlink_query_string_round_trip() {
+        let mut document = Document::default();
+        let link = Hyperlink::new("https://example.com/?a=1&b=2", &mut document.relationship_manager);
+        document.paragraphs.push(Paragraph {
+            children: vec![ParagraphChild::Hyperlink(link)],
+            ..Default::default()
+        });
+
+        let loaded = load_bytes(&save_bytes(&document).unwrap()).unwrap();
+        assert_eq!(loaded, document);
+        assert_eq!(
+            loaded.relationship_manager.get_links().get("rId1").map(String::as_str),
+            Some("https://example.com/?a=1&b=2")
+        );
+    }
+
+    // Package made of the given parts, for the documents the writer can't produce
+    fn package(parts: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        let options: FileOptions<'_, ()> = FileOptions::default();
+        for (name, content) in parts {
+            zip.start_file(*name, options).unwrap();
+            zip.write_all(content).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    fn rels(relationships: &[(&str, &str, &str)]) -> Vec<u8> {
+        let mut xml = String::from(
+            r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
+        );
+        for (id, kind, target) in relationships {
+            xml.push_str(&format!(r#"<Relationship Id="{id}" Type="{kind}" Target="{target}"/>"#));
+        }
+        xml.push_str("</Relationships>");
+        xml.into_bytes()
+    }
+
+    const NAMESPACES: &str = r#"xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"
+        xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"
+        xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing"
+        xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"
+        xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart"
+        xmlns:v="urn:schemas-microsoft-com:vml""#;
+
+    #[test]
+    fn test_load_chart_drawing() {
+        let body = format!(
+            r#"<w:document {NAMESPACES}><w:body><w:p><w:r><w:rPr><w:b/></w:rPr>
+                <w:t xml:space="preserve">Before </w:t>
+                <w:drawing><wp:inline><wp:extent cx="5486400" cy="3200400"/><a:graphic>
+                    <a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/chart">
+                        <c:chart r:id="rId5"/>
+                    </a:graphicData>
+                </a:graphic></wp:inline></w:drawing>
+                <w:t>after</w:t>
+            </w:r></w:p></w:body></w:document>"#
+        );
+        let chart_rels = rels(&[(
+            "rId5",
+            "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart",
+            "charts/chart1.xml",
+        )]);
+        let bytes = package(&[
+            (bp::DOCUMENT_XML_PATH, body.as_bytes()),
+            (bp::DOCUMENT_RELS_PATH, &chart_rels),
+        ]);
+
+        let loaded = load_bytes(&bytes).unwrap();
+        let mut run = Run::from("Before after".to_string());
+        run.properties.bold = true;
+        run.space_preserve = true;
+        assert_eq!(loaded.paragraphs[0].children, vec![ParagraphChild::Run(run)]);
+        assert!(loaded.images.is_empty());
+    }
+
+    #[test]
+    fn test_load_header_image() {
+        let body = format!(
+            r#"<w:document {NAMESPACES}><w:body>
+                <w:p><w:hyperlink r:id="rId1"><w:r><w:t>Link</w:t></w:r></w:hyperlink></w:p>
+                <w:sectPr><w:headerReference w:type="default" r:id="rId2"/></w:sectPr>
+            </w:body></w:document>"#
+        );
+        let header = format!(
+            r#"<w:hdr {NAMESPACES}><w:p><w:r><w:t>Logo</w:t>
+                <w:drawing><wp:inline><wp:extent cx="914400" cy="914400"/>
+                    <a:graphic><a:graphicData><a:blip r:embed="rId1"/></a:graphicData></a:graphic>
+                </wp:inline></w:drawing>
+                <w:t>Company</w:t>
+            </w:r></w:p></w:hdr>"#
+        );
+        let document_rels = rels(&[
+            ("rId1", bp::HYPERLINK_REL_TYPE, "https://example.com"),
+            ("rId2", bp::HEADER_REL_TYPE, "header1.xml"),
+        ]);
+        let header_rels = rels(&[("rId1", bp::IMAGE_REL_TYPE, "media/logo.png")]);
+        let bytes = package(&[
+            (bp::DOCUMENT_XML_PATH, body.as_bytes()),
+            (bp::DOCUMENT_RELS_PATH, &document_rels),
+            ("word/header1.xml", header.as_bytes()),
+            ("word/_rels/header1.xml.rels", &header_rels),
+            ("word/media/logo.png", &PIXEL_PNG),
+        ]);
+
+        let loaded = load_bytes(&bytes).unwrap();
+        let header = loaded.section_properties.header(&HeaderFooterType::Default).unwrap();
+        let ParagraphChild::Drawing(drawing) = &header.paragraphs[0].children[1] else {
+            panic!("Drawing was not read: {:?}", header.paragraphs[0].children);
+        };
+        assert_ne!(drawing.image_id, "rId1");
+        assert_eq!(
+            loaded.relationship_manager.get(&drawing.image_id).map(|r| r.target.as_str()),
+            Some("media/logo.png")
+        );
+        assert_eq!(loaded.images[0].data, PIXEL_PNG);
+        assert_eq!(
+            loaded.relationship_manager.get("rId1").map(|r| r.target.as_str()),
+            Some("https://example.com")
+        );
+        assert_eq!(
+            header.paragraphs[0].children[2],
+            ParagraphChild::Run(Run::from("Company".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_save_header_hyperlink_round_trip() {
+        let mut document = Document::from_text("Body text");
+        let link = Hyperlink::new_with_text("https://example.com", "Home", &mut document.relationship_manager);
+        document.section_properties.default_header = Some(Header::new(vec![Paragraph {
+            children: vec![ParagraphChild::Hyperlink(link.clone())],
+            ..Default::default()
+        }]));
+
+        let bytes = save_bytes(&document).unwrap();
+        let mut archive = ZipArchive::new(Cursor::new(&bytes)).unwrap();
+        let header_rels = read_relationships(&read_part(&mut archive, "word/_rels/header1.xml.rels").unwrap()).unwrap();
+        assert_eq!(
+            header_rels.get(link.id.as_deref().unwrap()).map(String::as_str),
+            Some("https://example.com")
+        );
+        assert!(archive.index_for_name("word/_rels/footer1.xml.rels").is_none());
+
+        let loaded = load_bytes(&bytes).unwrap();
+        let header = loaded.section_properties.header(&HeaderFooterType::Default).unwrap();
+        let ParagraphChild::Hyperlink(loaded_link) = &header.paragraphs[0].children[0] else {
+            panic!("Hyperlink was not read: {:?}", header.paragraphs[0].children);
+        };
+        assert_eq!(
+            loaded_link.href(&loaded.relationship_manager).as_deref(),
+            Some("https://example.com")
+        );
+    }
+
+    #[test]
+    fn test_save_comment_hyperlink_round_trip() {
+        let mut document = Document::from_text("Body text");
+        let link = Hyperlink::new_with_text("https://example.com", "Source", &mut document.relationship_manager);
+        document.comments.push(Comment::new(0, "Reviewer", vec![Paragraph {
+            children: vec![ParagraphChild::Hyperlink(link.clone())],
+            ..Default::default()
+        }]));
+
+        let bytes = save_bytes(&document).unwrap();
+        let mut archive = ZipArchive::new(Cursor::new(&bytes)).unwrap();
+        let comments_rels = read_relationships(&read_part(&mut archive, bp::COMMENTS_RELS_PATH).unwrap()).unwrap();
+        assert_eq!(
+            comments_rels.get(link.id.as_deref().unwrap()).map(String::as_str),
+            Some("https://example.com")
+        );
+
+        let loaded = load_bytes(&bytes).unwrap();
+        let ParagraphChild::Hyperlink(loaded_link) = &loaded.comments[0].paragraphs[0].children[0] else {
+            panic!("Hyperlink was not read: {:?}", loaded.comments[0].paragraphs[0].children);
+        };
+        assert_eq!(
+            loaded_link.href(&loaded.relationship_manager).as_deref(),
+            Some("https://example.com")
+        );
+    }
+
+    #[test]
+    fn test_save_footnote_hyperlink_round_trip() {
+        let mut document = Document::from_text("Body text");
+        let link = Hyperlink::new_with_text("https://example.com", "Source", &mut document.relationship_manager);
+        document.paragraphs[0].children.push(ParagraphChild::Run(Run {
+            footnote_reference: Some(1),
+            ..Default::default()
+        }));
+        document.footnotes.push(Footnote::new(1, vec![Paragraph {
+            children: vec![ParagraphChild::Hyperlink(link.clone())],
+            ..Default::default()
+        }]));
+
+        let bytes = save_bytes(&document).unwrap();
+        let mut archive = ZipArchive::new(Cursor::new(&bytes)).unwrap();
+        let footnotes_rels = read_relationships(&read_part(&mut archive, bp::FOOTNOTES_RELS_PATH).unwrap()).unwrap();
+        assert_eq!(
+            footnotes_rels.get(link.id.as_deref().unwrap()).map(String::as_str),
+            Some("https://example.com")
+        );
+
+        let loaded = load_bytes(&bytes).unwrap();
+        let ParagraphChild::Hyperlink(loaded_link) = &loaded.footnotes[0].paragraphs[0].children[0] else {
+            panic!("Hyperlink was not read: {:?}", loaded.footnotes[0].paragraphs[0].children);
+        };
+        assert_eq!(
+            loaded_link.href(&loaded.relationship_manager).as_deref(),
+            Some("https://example.com")
+        );
+    }
+
+    #[test]
+    fn test_legacy_picture_round_trip() {
+        let body = format!(
+            r#"<w:document {NAMESPACES}><w:body><w:p><w:r>
+                <w:pict><v:shape style="width:72pt;height:72pt"><v:imagedata r:id="rId3"/></v:shape></w:pict>
+            </w:r></w:p></w:body></w:document>"#
+        );
+        let document_rels = rels(&[("rId3", bp::IMAGE_REL_TYPE, "media/image1.png")]);
+        let bytes = package(&[
+            (bp::DOCUMENT_XML_PATH, body.as_bytes()),
+            (bp::DOCUMENT_RELS_PATH, &document_rels),
+            ("word/media/image1.png", &PIXEL_PNG),
+        ]);
+
+        let loaded = load_bytes(&bytes).unwrap();
+        let run = loaded.runs().next().unwrap();
+        assert_eq!(run.picture_id.as_deref(), Some("rId3"));
+        assert_eq!(loaded.images[0].data, PIXEL_PNG);
+
+        let mut archive = ZipArchive::new(Cursor::new(save_bytes(&loaded).unwrap())).unwrap();
+        let document_xml = read_part(&mut archive, bp::DOCUMENT_XML_PATH).unwrap();
+        assert!(document_xml.contains(r#"<v:imagedata r:id="rId3"/>"#));
+        assert_eq!(read_part_bytes(&mut archive, "word/media/image1.png").unwrap(), PIXEL_PNG);
+
+        let reloaded = load_bytes(&save_bytes(&loaded).unwrap()).unwrap();
+        assert_eq!(reloaded.paragraphs, loaded.paragraphs);
+        assert_eq!(reloaded.images, loaded.images);
+    }
+
+    #[test]
+    fn test_load_with_options_reads_every_part() {
+        let body = format!(
+            r#"<w:document {NAMESPACES}><w:body>
+                <w:p><w:r><w:t>Body</w:t></w:r></w:p>
+                <w:sectPr><w:headerReference w:type="default" r:id="rId1"/></w:sectPr>
+            </w:body></w:document>"#
+        );
+        let header = format!(
+            r#"<w:hdr {NAMESPACES}><w:p><w:pPr><w:outlineLvl w:val="one"/></w:pPr><w:r><w:t>Header</w:t></w:r></w:p></w:hdr>"#
+        );
+        let document_rels = rels(&[("rId1", bp::HEADER_REL_TYPE, "header1.xml")]);
+        let bytes = package(&[
+            (bp::DOCUMENT_XML_PATH, body.as_bytes()),
+            (bp::DOCUMENT_RELS_PATH, &document_rels),
+            ("word/header1.xml", header.as_bytes()),
+        ]);
+
+        assert!(load_bytes(&bytes).is_ok());
+        let strict = ParseOptions {
+            strict: true,
+            ..Default::default()
+        };
+        assert!(load_bytes_with(&bytes, strict).is_err());
+    }
+
+    #[test]
+    fn test_load_parts_from_relationships() {
+        let body = format!(r#"<w:document {NAMESPACES}><w:body><w:p><w:r><w:t>Body</w:t></w:r></w:p></w:body></w:document>"#);
+        let comments = format!(
+            r#"<w:comments {NAMESPACES}><w:comment w:id="0" w:author="Ann"><w:p><w:r><w:t>Note</w:t></w:r></w:p></w:comment></w:comments>"#
+        );
+        let styles = format!(
+            r#"<w:styles {NAMESPACES}><w:style w:type="paragraph" w:styleId="Quote"><w:name w:val="Quote"/></w:style></w:styles>"#
+        );
+        let document_rels = rels(&[
+            ("rId1", bp::COMMENTS_REL_TYPE, "/word/notes/comments1.xml"),
+            ("rId2", bp::STYLES_REL_TYPE, "styles2.xml"),
+        ]);
+        let bytes = package(&[
+            (bp::DOCUMENT_XML_PATH, body.as_bytes()),
+            (bp::DOCUMENT_RELS_PATH, &document_rels),
+            ("word/notes/comments1.xml", comments.as_bytes()),
+            ("word/styles2.xml", styles.as_bytes()),
+            // Not referenced by a relationship, so not part of the document
+            (bp::FOOTNOTES_XML_PATH, b"<w:footnotes/>"),
+        ]);
+
+        let loaded = load_bytes(&bytes).unwrap();
+        assert_eq!(loaded.comments[0].author, "Ann");
+        assert_eq!(loaded.styles[0].id, "Quote");
+        assert!(loaded.footnotes.is_empty());
+    }
 }